@@ -0,0 +1,96 @@
+use crate::context::Context;
+use crate::module::zero_width;
+use crate::modules::kubernetes;
+
+/// The badge shown by iTerm2, made of the user variables set by starship
+const BADGE_FORMAT: &str =
+    "\\(user.starship_repo) \\(user.starship_branch)\n\\(user.starship_kube_context)";
+
+/// Get the escape sequences setting the terminal's user variables to the
+/// current repository, branch and kubernetes context, and iTerm2's badge to
+/// show them.
+///
+/// User variables are supported by iTerm2, kitty and WezTerm, which let them
+/// be shown in the tab title, the status bar or the badge.
+pub fn escapes(context: &Context) -> String {
+    let shell = context.get_env("STARSHIP_SHELL").unwrap_or_default();
+
+    let repo = context.get_repo().ok();
+    let repo_name = repo
+        .and_then(|repo| repo.root.as_ref()?.file_name())
+        .map(|name| name.to_string_lossy().into_owned());
+    let branch = repo.and_then(|repo| repo.branch.clone());
+    let kube_context = kubernetes::get_current_context(context).map(|(name, _)| name);
+
+    let vars = [
+        ("starship_repo", repo_name),
+        ("starship_branch", branch),
+        ("starship_kube_context", kube_context),
+    ];
+
+    let mut escapes: String = vars
+        .iter()
+        .map(|(name, value)| {
+            let value = value.as_deref().unwrap_or_default();
+            zero_width(&set_user_var(name, value), &shell)
+        })
+        .collect();
+    escapes.push_str(&zero_width(&set_badge_format(BADGE_FORMAT), &shell));
+
+    escapes
+}
+
+fn set_user_var(name: &str, value: &str) -> String {
+    format!("\u{1b}]1337;SetUserVar={}={}\u{7}", name, base64(value))
+}
+
+fn set_badge_format(format: &str) -> String {
+    format!("\u{1b}]1337;SetBadgeFormat={}\u{7}", base64(format))
+}
+
+/// Encode text as base64, as expected by the iTerm2 escape sequences
+fn base64(text: &str) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::new();
+    for chunk in text.as_bytes().chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let group = u32::from(bytes[0]) << 16 | u32::from(bytes[1]) << 8 | u32::from(bytes[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0x3f;
+                encoded.push(char::from(ALPHABET[index as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(""), "");
+        assert_eq!(base64("f"), "Zg==");
+        assert_eq!(base64("fo"), "Zm8=");
+        assert_eq!(base64("foo"), "Zm9v");
+        assert_eq!(base64("master"), "bWFzdGVy");
+    }
+
+    #[test]
+    fn test_set_user_var() {
+        assert_eq!(
+            set_user_var("starship_branch", "master"),
+            "\u{1b}]1337;SetUserVar=starship_branch=bWFzdGVy\u{7}"
+        );
+    }
+}
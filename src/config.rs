@@ -3,10 +3,12 @@ use crate::utils;
 use ansi_term::{Color, Style};
 
 use std::clone::Clone;
+use std::collections::HashMap;
 use std::marker::Sized;
 
 use dirs::home_dir;
 use std::env;
+use std::io::{self, Read};
 use toml::Value;
 
 /// Root config of a module.
@@ -92,6 +94,16 @@ impl<'a> ModuleConfig<'a> for u64 {
     }
 }
 
+impl<'a> ModuleConfig<'a> for usize {
+    fn from_config(config: &Value) -> Option<Self> {
+        match config {
+            Value::Integer(value) if *value >= 0 => Some(*value as usize),
+            Value::String(value) => value.parse::<usize>().ok(),
+            _ => None,
+        }
+    }
+}
+
 impl<'a> ModuleConfig<'a> for f64 {
     fn from_config(config: &Value) -> Option<Self> {
         config.as_float()
@@ -111,6 +123,19 @@ where
     }
 }
 
+impl<'a, T> ModuleConfig<'a> for HashMap<String, T>
+where
+    T: ModuleConfig<'a>,
+{
+    fn from_config(config: &'a Value) -> Option<Self> {
+        config
+            .as_table()?
+            .iter()
+            .map(|(key, value)| Some((key.clone(), T::from_config(value)?)))
+            .collect()
+    }
+}
+
 impl<'a, T> ModuleConfig<'a> for Option<T>
 where
     T: ModuleConfig<'a> + Sized,
@@ -139,6 +164,29 @@ impl StarshipConfig {
         }
     }
 
+    /// Initialize the Config struct from a configuration read from stdin
+    pub fn from_stdin() -> Self {
+        let mut toml_content = String::new();
+        if let Err(e) = io::stdin().read_to_string(&mut toml_content) {
+            log::warn!("Unable to read config from stdin: \n{}", &e);
+        }
+
+        let config = match toml::from_str(&toml_content) {
+            Ok(config) => {
+                log::debug!("Config parsed from stdin: \n{:?}", &config);
+                config
+            }
+            Err(e) => {
+                log::warn!("Unable to parse config from stdin: \n{}", &e);
+                Value::Table(toml::value::Table::new())
+            }
+        };
+
+        StarshipConfig {
+            config: Some(config),
+        }
+    }
+
     /// Create a config from a starship configuration file
     fn config_from_file() -> Option<Value> {
         let file_path = if let Ok(path) = env::var("STARSHIP_CONFIG") {
@@ -0,0 +1,23 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct AzureConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub format: &'a str,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for AzureConfig<'a> {
+    fn new() -> Self {
+        AzureConfig {
+            symbol: SegmentConfig::new("🔷 "),
+            format: "$name",
+            style: Color::Blue.bold(),
+            disabled: true,
+        }
+    }
+}
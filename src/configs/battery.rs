@@ -10,7 +10,7 @@ pub struct BatteryConfig<'a> {
     pub discharging_symbol: SegmentConfig<'a>,
     pub unknown_symbol: Option<SegmentConfig<'a>>,
     pub empty_symbol: Option<SegmentConfig<'a>>,
-    pub display: Vec<BatteryDisplayConfig>,
+    pub display: Vec<BatteryDisplayConfig<'a>>,
     pub disabled: bool,
     pub percentage: SegmentConfig<'a>,
 }
@@ -26,6 +26,8 @@ impl<'a> RootModuleConfig<'a> for BatteryConfig<'a> {
             display: vec![BatteryDisplayConfig {
                 threshold: 10,
                 style: Color::Red.bold(),
+                charging_symbol: None,
+                discharging_symbol: None,
             }],
             disabled: false,
             percentage: SegmentConfig::default(),
@@ -33,8 +35,28 @@ impl<'a> RootModuleConfig<'a> for BatteryConfig<'a> {
     }
 }
 
-#[derive(Clone, ModuleConfig)]
-pub struct BatteryDisplayConfig {
+#[derive(Clone)]
+pub struct BatteryDisplayConfig<'a> {
     pub threshold: i64,
     pub style: Style,
+    pub charging_symbol: Option<SegmentConfig<'a>>,
+    pub discharging_symbol: Option<SegmentConfig<'a>>,
+}
+
+impl<'a> ModuleConfig<'a> for BatteryDisplayConfig<'a> {
+    fn from_config(config: &'a toml::Value) -> Option<Self> {
+        let config = config.as_table()?;
+
+        // The symbols are optional, those of the module are used by default
+        Some(BatteryDisplayConfig {
+            threshold: i64::from_config(config.get("threshold")?)?,
+            style: Style::from_config(config.get("style")?)?,
+            charging_symbol: config
+                .get("charging_symbol")
+                .and_then(SegmentConfig::from_config),
+            discharging_symbol: config
+                .get("discharging_symbol")
+                .and_then(SegmentConfig::from_config),
+        })
+    }
 }
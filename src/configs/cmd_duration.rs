@@ -6,8 +6,10 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct CmdDurationConfig<'a> {
     pub min_time: i64,
-    pub prefix: &'a str,
+    pub prefix: Option<&'a str>,
     pub style: Style,
+    pub show_notifications: bool,
+    pub min_time_to_notify: i64,
     pub disabled: bool,
 }
 
@@ -15,8 +17,10 @@ impl<'a> RootModuleConfig<'a> for CmdDurationConfig<'a> {
     fn new() -> Self {
         CmdDurationConfig {
             min_time: 2,
-            prefix: "took ",
+            prefix: None,
             style: Color::Yellow.bold(),
+            show_notifications: false,
+            min_time_to_notify: 45,
             disabled: false,
         }
     }
@@ -5,6 +5,8 @@ use starship_module_config_derive::ModuleConfig;
 
 #[derive(Clone, ModuleConfig)]
 pub struct CondaConfig<'a> {
+    pub truncation_length: usize,
+    pub ignore_base: bool,
     pub symbol: SegmentConfig<'a>,
     pub environment: SegmentConfig<'a>,
     pub style: Style,
@@ -14,6 +16,8 @@ pub struct CondaConfig<'a> {
 impl<'a> RootModuleConfig<'a> for CondaConfig<'a> {
     fn new() -> Self {
         CondaConfig {
+            truncation_length: 1,
+            ignore_base: true,
             symbol: SegmentConfig {
                 value: "C ",
                 style: None,
@@ -0,0 +1,23 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct ContainerConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub name: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for ContainerConfig<'a> {
+    fn new() -> Self {
+        ContainerConfig {
+            symbol: SegmentConfig::new("⬢ "),
+            name: SegmentConfig::default(),
+            style: Color::Red.bold().dimmed(),
+            disabled: false,
+        }
+    }
+}
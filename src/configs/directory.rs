@@ -1,25 +1,32 @@
-use crate::config::{ModuleConfig, RootModuleConfig};
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
 
 use ansi_term::{Color, Style};
 use starship_module_config_derive::ModuleConfig;
+use std::collections::HashMap;
 
 #[derive(Clone, ModuleConfig)]
-pub struct DirectoryConfig {
+pub struct DirectoryConfig<'a> {
     pub truncation_length: i64,
     pub truncate_to_repo: bool,
     pub fish_style_pwd_dir_length: i64,
     pub use_logical_path: bool,
+    pub substitutions: HashMap<String, &'a str>,
+    pub read_only: SegmentConfig<'a>,
+    pub read_only_style: Style,
     pub style: Style,
     pub disabled: bool,
 }
 
-impl<'a> RootModuleConfig<'a> for DirectoryConfig {
+impl<'a> RootModuleConfig<'a> for DirectoryConfig<'a> {
     fn new() -> Self {
         DirectoryConfig {
             truncation_length: 3,
             truncate_to_repo: true,
             fish_style_pwd_dir_length: 0,
             use_logical_path: true,
+            substitutions: HashMap::new(),
+            read_only: SegmentConfig::new(" 🔒"),
+            read_only_style: Color::Red.normal(),
             style: Color::Cyan.bold(),
             disabled: false,
         }
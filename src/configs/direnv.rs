@@ -0,0 +1,29 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct DirenvConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub loaded_msg: &'a str,
+    pub allowed_msg: &'a str,
+    pub not_allowed_msg: &'a str,
+    pub denied_msg: &'a str,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for DirenvConfig<'a> {
+    fn new() -> Self {
+        DirenvConfig {
+            symbol: SegmentConfig::new("direnv "),
+            loaded_msg: "loaded",
+            allowed_msg: "allowed",
+            not_allowed_msg: "not allowed",
+            denied_msg: "denied",
+            style: Color::Yellow.bold(),
+            disabled: true,
+        }
+    }
+}
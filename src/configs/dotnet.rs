@@ -9,6 +9,7 @@ pub struct DotnetConfig<'a> {
     pub version: SegmentConfig<'a>,
     pub style: Style,
     pub heuristic: bool,
+    pub show_target_framework: bool,
     pub disabled: bool,
 }
 
@@ -19,6 +20,7 @@ impl<'a> RootModuleConfig<'a> for DotnetConfig<'a> {
             version: SegmentConfig::default(),
             style: Color::Blue.bold(),
             heuristic: true,
+            show_target_framework: true,
             disabled: false,
         }
     }
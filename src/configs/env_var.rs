@@ -8,6 +8,7 @@ pub struct EnvVarConfig<'a> {
     pub symbol: Option<SegmentConfig<'a>>,
     pub variable: Option<&'a str>,
     pub default: Option<&'a str>,
+    pub format: &'a str,
     pub prefix: &'a str,
     pub suffix: &'a str,
     pub style: Style,
@@ -20,6 +21,7 @@ impl<'a> RootModuleConfig<'a> for EnvVarConfig<'a> {
             symbol: None,
             variable: None,
             default: None,
+            format: "$value",
             prefix: "",
             suffix: "",
             style: Color::Black.bold().dimmed(),
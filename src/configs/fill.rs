@@ -0,0 +1,21 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::Style;
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct FillConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for FillConfig<'a> {
+    fn new() -> Self {
+        FillConfig {
+            symbol: SegmentConfig::new(" "),
+            style: Style::default(),
+            disabled: false,
+        }
+    }
+}
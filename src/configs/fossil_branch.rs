@@ -0,0 +1,27 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct FossilBranchConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub truncation_length: i64,
+    pub truncation_symbol: &'a str,
+    pub branch_name: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for FossilBranchConfig<'a> {
+    fn new() -> Self {
+        FossilBranchConfig {
+            symbol: SegmentConfig::new(" "),
+            truncation_length: i64::MAX,
+            truncation_symbol: "…",
+            branch_name: SegmentConfig::default(),
+            style: Color::Purple.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+use std::collections::HashMap;
+
+#[derive(Clone, ModuleConfig)]
+pub struct GcloudConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub account: SegmentConfig<'a>,
+    pub project: SegmentConfig<'a>,
+    pub region: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+    pub region_aliases: HashMap<String, &'a str>,
+}
+
+impl<'a> RootModuleConfig<'a> for GcloudConfig<'a> {
+    fn new() -> Self {
+        GcloudConfig {
+            symbol: SegmentConfig::new("☁️  "),
+            account: SegmentConfig::default(),
+            project: SegmentConfig::default(),
+            region: SegmentConfig::default(),
+            style: Color::Blue.bold(),
+            disabled: false,
+            region_aliases: HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct GitMetricsConfig<'a> {
+    pub added_style: Style,
+    pub deleted_style: Style,
+    pub separator: &'a str,
+    pub only_nonzero_diffs: bool,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for GitMetricsConfig<'a> {
+    fn new() -> Self {
+        GitMetricsConfig {
+            added_style: Color::Green.bold(),
+            deleted_style: Color::Red.bold(),
+            separator: "/",
+            only_nonzero_diffs: true,
+            disabled: true,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct GitRemoteConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub remote_name: SegmentConfig<'a>,
+    pub remote_branch: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for GitRemoteConfig<'a> {
+    fn new() -> Self {
+        GitRemoteConfig {
+            symbol: SegmentConfig::new("⇢ "),
+            remote_name: SegmentConfig::default(),
+            remote_branch: SegmentConfig::default(),
+            style: Color::Blue.bold(),
+            disabled: true,
+        }
+    }
+}
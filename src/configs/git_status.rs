@@ -6,8 +6,11 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct GitStatusConfig<'a> {
     pub stashed: SegmentConfig<'a>,
+    pub stashed_count: CountConfig,
     pub ahead: SegmentConfig<'a>,
+    pub ahead_count: CountConfig,
     pub behind: SegmentConfig<'a>,
+    pub behind_count: CountConfig,
     pub diverged: SegmentConfig<'a>,
     pub show_sync_count: bool,
     pub conflicted: SegmentConfig<'a>,
@@ -22,6 +25,10 @@ pub struct GitStatusConfig<'a> {
     pub staged_count: CountConfig,
     pub untracked: SegmentConfig<'a>,
     pub untracked_count: CountConfig,
+    pub untracked_files: Option<UntrackedFiles>,
+    pub backend: GitBackend,
+    pub status_timeout: u64,
+    pub timed_out: SegmentConfig<'a>,
     pub prefix: &'a str,
     pub suffix: &'a str,
     pub style: Style,
@@ -32,8 +39,11 @@ impl<'a> RootModuleConfig<'a> for GitStatusConfig<'a> {
     fn new() -> Self {
         GitStatusConfig {
             stashed: SegmentConfig::new("$"),
+            stashed_count: CountConfig::default(),
             ahead: SegmentConfig::new("⇡"),
+            ahead_count: CountConfig::default(),
             behind: SegmentConfig::new("⇣"),
+            behind_count: CountConfig::default(),
             diverged: SegmentConfig::new("⇕"),
             conflicted: SegmentConfig::new("="),
             show_sync_count: false,
@@ -48,6 +58,10 @@ impl<'a> RootModuleConfig<'a> for GitStatusConfig<'a> {
             staged_count: CountConfig::default(),
             untracked: SegmentConfig::new("?"),
             untracked_count: CountConfig::default(),
+            untracked_files: None,
+            backend: GitBackend::Libgit2,
+            status_timeout: 0,
+            timed_out: SegmentConfig::new("~"),
             prefix: "[",
             suffix: "] ",
             style: Color::Red.bold(),
@@ -61,3 +75,50 @@ pub struct CountConfig {
     pub enabled: bool,
     pub style: Option<Style>,
 }
+
+/// Which untracked files are looked for, like `status.showUntrackedFiles` in git
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UntrackedFiles {
+    /// Untracked files, including those in untracked directories
+    All,
+    /// Untracked files, without looking into untracked directories
+    Normal,
+    /// No untracked files, which is much faster in large repositories
+    None,
+}
+
+impl<'a> ModuleConfig<'a> for UntrackedFiles {
+    fn from_config(config: &toml::Value) -> Option<Self> {
+        match config.as_str()? {
+            "all" => Some(UntrackedFiles::All),
+            "normal" => Some(UntrackedFiles::Normal),
+            "none" | "no" => Some(UntrackedFiles::None),
+            _ => None,
+        }
+    }
+}
+
+/// Which library the status of the working tree is read with
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GitBackend {
+    Libgit2,
+    /// Faster in large repositories
+    #[cfg(feature = "gitoxide")]
+    Gitoxide,
+}
+
+impl<'a> ModuleConfig<'a> for GitBackend {
+    fn from_config(config: &toml::Value) -> Option<Self> {
+        match config.as_str()? {
+            "libgit2" => Some(GitBackend::Libgit2),
+            #[cfg(feature = "gitoxide")]
+            "gitoxide" => Some(GitBackend::Gitoxide),
+            #[cfg(not(feature = "gitoxide"))]
+            "gitoxide" => {
+                log::debug!("starship is built without the `gitoxide` feature, using libgit2");
+                None
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct GodotConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub version: SegmentConfig<'a>,
+    pub project_name: SegmentConfig<'a>,
+    pub show_project_name: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for GodotConfig<'a> {
+    fn new() -> Self {
+        GodotConfig {
+            symbol: SegmentConfig::new("🤖 "),
+            version: SegmentConfig::default(),
+            project_name: SegmentConfig::default(),
+            show_project_name: false,
+            style: Color::Blue.bold(),
+            disabled: false,
+        }
+    }
+}
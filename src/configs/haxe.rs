@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct HaxeConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub version: SegmentConfig<'a>,
+    pub use_haxerc: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for HaxeConfig<'a> {
+    fn new() -> Self {
+        HaxeConfig {
+            symbol: SegmentConfig::new("⌘ "),
+            version: SegmentConfig::default(),
+            use_haxerc: true,
+            style: Color::Yellow.bold(),
+            disabled: false,
+        }
+    }
+}
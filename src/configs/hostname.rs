@@ -10,6 +10,7 @@ pub struct HostnameConfig<'a> {
     pub suffix: &'a str,
     pub trim_at: &'a str,
     pub style: Style,
+    pub ssh_style: Option<Style>,
     pub disabled: bool,
 }
 
@@ -21,6 +22,7 @@ impl<'a> RootModuleConfig<'a> for HostnameConfig<'a> {
             suffix: "",
             trim_at: ".",
             style: Color::Green.bold().dimmed(),
+            ssh_style: None,
             disabled: false,
         }
     }
@@ -0,0 +1,23 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct JupyterConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub kernel: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for JupyterConfig<'a> {
+    fn new() -> Self {
+        JupyterConfig {
+            symbol: SegmentConfig::new("🪐 "),
+            kernel: SegmentConfig::default(),
+            style: Color::Yellow.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct KotlinConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub version: SegmentConfig<'a>,
+    pub kotlin_binary: &'a str,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for KotlinConfig<'a> {
+    fn new() -> Self {
+        KotlinConfig {
+            symbol: SegmentConfig::new("🅺 "),
+            version: SegmentConfig::default(),
+            kotlin_binary: "kotlin",
+            style: Color::Blue.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct LocalipConfig<'a> {
+    pub ssh_only: bool,
+    pub ipv6: bool,
+    pub separator: SegmentConfig<'a>,
+    pub cache_duration: u64,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for LocalipConfig<'a> {
+    fn new() -> Self {
+        LocalipConfig {
+            ssh_only: true,
+            ipv6: false,
+            separator: SegmentConfig::new(" "),
+            cache_duration: 60,
+            style: Color::Yellow.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct LuaConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub version: SegmentConfig<'a>,
+    pub lua_binary: &'a str,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for LuaConfig<'a> {
+    fn new() -> Self {
+        LuaConfig {
+            symbol: SegmentConfig::new("🌙 "),
+            version: SegmentConfig::default(),
+            lua_binary: "lua",
+            style: Color::Blue.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -1,28 +1,88 @@
 pub mod aws;
+pub mod azure;
 pub mod battery;
+pub mod bazel;
+pub mod buf;
+pub mod bun;
 pub mod character;
+pub mod cmake;
 pub mod cmd_duration;
+pub mod cobol;
 pub mod conda;
+pub mod container;
+pub mod crystal;
+pub mod dart;
+pub mod deno;
 pub mod directory;
+pub mod direnv;
 pub mod dotnet;
+pub mod elm;
 pub mod env_var;
+pub mod erlang;
+pub mod fill;
+pub mod fossil_branch;
+pub mod gcloud;
 pub mod git_branch;
+pub mod git_commit;
+pub mod git_metrics;
+pub mod git_remote;
 pub mod git_state;
 pub mod git_status;
+pub mod gleam;
 pub mod go;
+pub mod godot;
+pub mod gradle;
+pub mod haskell;
+pub mod haxe;
+pub mod helm;
+pub mod hg_branch;
 pub mod hostname;
 pub mod java;
 pub mod jobs;
+pub mod julia;
+pub mod jupyter;
+pub mod kotlin;
 pub mod kubernetes;
+pub mod latex;
+pub mod localip;
+pub mod lua;
 pub mod memory_usage;
+pub mod meson;
+pub mod nim;
 pub mod nix_shell;
 pub mod nodejs;
+pub mod nomad;
+pub mod ocaml;
+pub mod odin;
+pub mod openstack;
+pub mod os;
 pub mod package;
+pub mod php;
+pub mod pixi;
+pub mod pulumi;
+pub mod purescript;
 pub mod python;
+pub mod quarto;
+pub mod raku;
+pub mod rlang;
 pub mod ruby;
 pub mod rust;
+pub mod scala;
+pub mod shell;
+pub mod singularity;
+pub mod solidity;
+pub mod spack;
 mod starship_root;
+pub mod status;
+pub mod sudo;
+pub mod svn;
+pub mod terraform;
 pub mod time;
+pub mod typst;
+pub mod unity;
+pub mod unreal;
 pub mod username;
+pub mod vagrant;
+pub mod vlang;
 
 pub use starship_root::*;
@@ -8,6 +8,8 @@ pub struct NixShellConfig<'a> {
     pub use_name: bool,
     pub impure_msg: SegmentConfig<'a>,
     pub pure_msg: SegmentConfig<'a>,
+    pub unknown_msg: SegmentConfig<'a>,
+    pub heuristic: bool,
     pub style: Style,
     pub disabled: bool,
 }
@@ -18,6 +20,8 @@ impl<'a> RootModuleConfig<'a> for NixShellConfig<'a> {
             use_name: false,
             impure_msg: SegmentConfig::new("impure"),
             pure_msg: SegmentConfig::new("pure"),
+            unknown_msg: SegmentConfig::new("nix"),
+            heuristic: false,
             style: Color::Red.bold(),
             disabled: false,
         }
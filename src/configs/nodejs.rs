@@ -7,6 +7,7 @@ use starship_module_config_derive::ModuleConfig;
 pub struct NodejsConfig<'a> {
     pub symbol: SegmentConfig<'a>,
     pub style: Style,
+    pub not_capable_style: Style,
     pub disabled: bool,
 }
 
@@ -15,6 +16,7 @@ impl<'a> RootModuleConfig<'a> for NodejsConfig<'a> {
         NodejsConfig {
             symbol: SegmentConfig::new("⬢ "),
             style: Color::Green.bold(),
+            not_capable_style: Color::Red.bold(),
             disabled: false,
         }
     }
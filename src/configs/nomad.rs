@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct NomadConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub namespace: SegmentConfig<'a>,
+    pub region: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for NomadConfig<'a> {
+    fn new() -> Self {
+        NomadConfig {
+            symbol: SegmentConfig::new("🅽 "),
+            namespace: SegmentConfig::default(),
+            region: SegmentConfig::default(),
+            style: Color::Green.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct OdinConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub version: SegmentConfig<'a>,
+    pub show_commit: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for OdinConfig<'a> {
+    fn new() -> Self {
+        OdinConfig {
+            symbol: SegmentConfig::new("Ø "),
+            version: SegmentConfig::default(),
+            show_commit: false,
+            style: Color::Blue.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct OpenStackConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub cloud: SegmentConfig<'a>,
+    pub project: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for OpenStackConfig<'a> {
+    fn new() -> Self {
+        OpenStackConfig {
+            symbol: SegmentConfig::new("☁️  "),
+            cloud: SegmentConfig::default(),
+            project: SegmentConfig::default(),
+            style: Color::Yellow.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::config::{ModuleConfig, RootModuleConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+use std::collections::HashMap;
+
+#[derive(Clone, ModuleConfig)]
+pub struct OsConfig<'a> {
+    pub symbols: HashMap<String, &'a str>,
+    pub show_name: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for OsConfig<'a> {
+    fn new() -> Self {
+        OsConfig {
+            symbols: HashMap::new(),
+            show_name: false,
+            style: Color::White.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -6,6 +6,7 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct PackageConfig<'a> {
     pub symbol: SegmentConfig<'a>,
+    pub display_private: bool,
     pub style: Style,
     pub disabled: bool,
 }
@@ -14,6 +15,7 @@ impl<'a> RootModuleConfig<'a> for PackageConfig<'a> {
     fn new() -> Self {
         PackageConfig {
             symbol: SegmentConfig::new("📦 "),
+            display_private: false,
             style: Color::Red.bold(),
             disabled: false,
         }
@@ -0,0 +1,27 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct PixiConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub version: SegmentConfig<'a>,
+    pub environment: SegmentConfig<'a>,
+    pub show_default_environment: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for PixiConfig<'a> {
+    fn new() -> Self {
+        PixiConfig {
+            symbol: SegmentConfig::new("🧚 "),
+            version: SegmentConfig::default(),
+            environment: SegmentConfig::default(),
+            show_default_environment: false,
+            style: Color::Yellow.bold(),
+            disabled: false,
+        }
+    }
+}
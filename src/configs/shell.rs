@@ -0,0 +1,39 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct ShellConfig<'a> {
+    pub bash_indicator: SegmentConfig<'a>,
+    pub fish_indicator: SegmentConfig<'a>,
+    pub zsh_indicator: SegmentConfig<'a>,
+    pub elvish_indicator: SegmentConfig<'a>,
+    pub xonsh_indicator: SegmentConfig<'a>,
+    pub tcsh_indicator: SegmentConfig<'a>,
+    pub powershell_indicator: SegmentConfig<'a>,
+    pub cmd_indicator: SegmentConfig<'a>,
+    pub sh_indicator: SegmentConfig<'a>,
+    pub unknown_indicator: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for ShellConfig<'a> {
+    fn new() -> Self {
+        ShellConfig {
+            bash_indicator: SegmentConfig::new("bsh"),
+            fish_indicator: SegmentConfig::new("fsh"),
+            zsh_indicator: SegmentConfig::new("zsh"),
+            elvish_indicator: SegmentConfig::new("esh"),
+            xonsh_indicator: SegmentConfig::new("xsh"),
+            tcsh_indicator: SegmentConfig::new("tsh"),
+            powershell_indicator: SegmentConfig::new("psh"),
+            cmd_indicator: SegmentConfig::new("cmd"),
+            sh_indicator: SegmentConfig::new("sh"),
+            unknown_indicator: SegmentConfig::new(""),
+            style: Color::White.bold(),
+            disabled: true,
+        }
+    }
+}
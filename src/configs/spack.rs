@@ -0,0 +1,23 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct SpackConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub environment: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for SpackConfig<'a> {
+    fn new() -> Self {
+        SpackConfig {
+            symbol: SegmentConfig::new("🅢 "),
+            environment: SegmentConfig::default(),
+            style: Color::Blue.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -7,6 +7,12 @@ pub struct StarshipRootConfig<'a> {
     pub add_newline: bool,
     pub prompt_order: Vec<&'a str>,
     pub scan_timeout: u64,
+    pub locale: Option<&'a str>,
+    pub screen_reader: bool,
+    pub cache_prompt: bool,
+    pub responsive_width: usize,
+    pub hyperlinks: bool,
+    pub badge: bool,
 }
 
 impl<'a> RootModuleConfig<'a> for StarshipRootConfig<'a> {
@@ -17,28 +23,85 @@ impl<'a> RootModuleConfig<'a> for StarshipRootConfig<'a> {
             // NOTE: If this const value is changed then Default prompt order subheading inside
             // prompt heading of config docs needs to be updated according to changes made here.
             prompt_order: vec![
+                "os",
                 "username",
                 "hostname",
+                "localip",
+                "sudo",
                 "kubernetes",
                 "directory",
                 "git_branch",
+                "git_remote",
+                "git_commit",
                 "git_state",
                 "git_status",
+                "git_metrics",
+                "hg_branch",
+                "fossil_branch",
+                "svn",
                 "package",
                 // ↓ Toolchain version modules ↓
                 // (Let's keep these sorted alphabetically)
+                "bazel",
+                "buf",
+                "bun",
+                "cmake",
+                "cobol",
+                "crystal",
+                "dart",
+                "deno",
                 "dotnet",
+                "elm",
+                "erlang",
+                "gleam",
+                "godot",
                 "golang",
+                "gradle",
+                "haskell",
+                "haxe",
+                "helm",
                 "java",
+                "julia",
+                "jupyter",
+                "kotlin",
+                "latex",
+                "lua",
+                "meson",
+                "nim",
                 "nodejs",
+                "ocaml",
+                "odin",
+                "php",
+                "pulumi",
+                "purescript",
                 "python",
+                "quarto",
+                "raku",
+                "rlang",
                 "ruby",
                 "rust",
+                "scala",
+                "solidity",
+                "terraform",
+                "typst",
+                "unity",
+                "unreal",
+                "vagrant",
+                "vlang",
                 // ↑ Toolchain version modules ↑
                 "nix_shell",
+                "direnv",
+                "singularity",
+                "container",
                 "conda",
+                "pixi",
+                "spack",
                 "memory_usage",
                 "aws",
+                "gcloud",
+                "azure",
+                "openstack",
+                "nomad",
                 "env_var",
                 "cmd_duration",
                 "line_break",
@@ -46,9 +109,17 @@ impl<'a> RootModuleConfig<'a> for StarshipRootConfig<'a> {
                 #[cfg(feature = "battery")]
                 "battery",
                 "time",
+                "status",
+                "shell",
                 "character",
             ],
             scan_timeout: 30,
+            locale: None,
+            screen_reader: false,
+            cache_prompt: false,
+            responsive_width: 80,
+            hyperlinks: false,
+            badge: false,
         }
     }
 }
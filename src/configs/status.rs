@@ -0,0 +1,37 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct StatusConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub not_executable_symbol: SegmentConfig<'a>,
+    pub not_found_symbol: SegmentConfig<'a>,
+    pub sigint_symbol: SegmentConfig<'a>,
+    pub signal_symbol: SegmentConfig<'a>,
+    pub map_symbol: bool,
+    pub recognize_signal_code: bool,
+    pub pipestatus: bool,
+    pub pipestatus_separator: SegmentConfig<'a>,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for StatusConfig<'a> {
+    fn new() -> Self {
+        StatusConfig {
+            symbol: SegmentConfig::new("✖ "),
+            not_executable_symbol: SegmentConfig::new("🚫 "),
+            not_found_symbol: SegmentConfig::new("🔍 "),
+            sigint_symbol: SegmentConfig::new("🧱 "),
+            signal_symbol: SegmentConfig::new("⚡ "),
+            map_symbol: true,
+            recognize_signal_code: true,
+            pipestatus: false,
+            pipestatus_separator: SegmentConfig::new("|"),
+            style: Color::Red.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct SudoConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub timeout: u64,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for SudoConfig<'a> {
+    fn new() -> Self {
+        SudoConfig {
+            symbol: SegmentConfig::new("🧙"),
+            timeout: 100,
+            style: Color::Blue.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct SvnConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub branch_name: SegmentConfig<'a>,
+    pub revision: SegmentConfig<'a>,
+    pub show_revision: bool,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for SvnConfig<'a> {
+    fn new() -> Self {
+        SvnConfig {
+            symbol: SegmentConfig::new(" "),
+            branch_name: SegmentConfig::default(),
+            revision: SegmentConfig::default(),
+            show_revision: true,
+            style: Color::Purple.bold(),
+            disabled: true,
+        }
+    }
+}
@@ -6,6 +6,7 @@ use starship_module_config_derive::ModuleConfig;
 #[derive(Clone, ModuleConfig)]
 pub struct TimeConfig<'a> {
     pub use_12hr: bool,
+    pub time_format: Option<&'a str>,
     pub format: Option<&'a str>,
     pub style: Style,
     pub disabled: bool,
@@ -16,6 +17,7 @@ impl<'a> RootModuleConfig<'a> for TimeConfig<'a> {
     fn new() -> Self {
         TimeConfig {
             use_12hr: false,
+            time_format: None,
             format: None,
             style: Color::Yellow.bold(),
             disabled: true,
@@ -0,0 +1,25 @@
+use crate::config::{ModuleConfig, RootModuleConfig, SegmentConfig};
+
+use ansi_term::{Color, Style};
+use starship_module_config_derive::ModuleConfig;
+
+#[derive(Clone, ModuleConfig)]
+pub struct UnrealConfig<'a> {
+    pub symbol: SegmentConfig<'a>,
+    pub version: SegmentConfig<'a>,
+    pub source_build: &'a str,
+    pub style: Style,
+    pub disabled: bool,
+}
+
+impl<'a> RootModuleConfig<'a> for UnrealConfig<'a> {
+    fn new() -> Self {
+        UnrealConfig {
+            symbol: SegmentConfig::new("🕹 "),
+            version: SegmentConfig::default(),
+            source_build: "source",
+            style: Color::Blue.bold(),
+            disabled: false,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use crate::config::StarshipConfig;
-use crate::module::Module;
+use crate::locale::{self, Locale};
+use crate::module::{Module, Priority};
+use crate::profile;
+use crate::utils::{self, CommandOutput};
 
 use clap::ArgMatches;
 use git2::{Repository, RepositoryState};
@@ -10,6 +13,8 @@ use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::string::String;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
 use std::time::{Duration, SystemTime};
 
 /// Context contains data or common methods that may be used by multiple modules.
@@ -25,9 +30,19 @@ pub struct Context<'a> {
     /// A vector containing the full paths of all the files in `current_dir`.
     dir_files: OnceCell<Vec<PathBuf>>,
 
+    /// Whether building `dir_files` was cut short by `scan_timeout`.
+    dir_scan_timed_out: AtomicBool,
+
     /// Properties to provide to modules.
     pub properties: HashMap<&'a str, String>,
 
+    /// The environment variables visible to modules.
+    pub env: Env,
+
+    /// Stubbed outputs of external commands, keyed by the full command line.
+    /// Commands without a stub are executed as usual.
+    pub cmd: HashMap<String, Option<CommandOutput>>,
+
     /// Private field to store Git information for modules who need it
     repo: OnceCell<Repo>,
 }
@@ -55,7 +70,13 @@ impl<'a> Context<'a> {
     where
         T: Into<PathBuf>,
     {
-        let config = StarshipConfig::initialize();
+        let config = profile::record("config", "load config", || {
+            if arguments.is_present("stdin_config") {
+                StarshipConfig::from_stdin()
+            } else {
+                StarshipConfig::initialize()
+            }
+        });
 
         // Unwrap the clap arguments into a simple hashtable
         // we only care about single arguments at this point, there isn't a
@@ -67,6 +88,29 @@ impl<'a> Context<'a> {
             .map(|(a, b)| (*a, b.vals.first().cloned().unwrap().into_string().unwrap()))
             .collect();
 
+        let mut context = Context::new_with_config(config, properties, dir);
+
+        // Variables given with `--env KEY=VALUE` override the real environment
+        for pair in arguments.values_of("env").into_iter().flatten() {
+            let mut parts = pair.splitn(2, '=');
+            if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+                context.env.insert(key, value);
+            }
+        }
+
+        context
+    }
+
+    /// Create a new instance of Context for the provided directory, configuration
+    /// and properties, without reading the configuration file or the command line.
+    pub fn new_with_config<T>(
+        config: StarshipConfig,
+        properties: HashMap<&'a str, String>,
+        dir: T,
+    ) -> Context<'a>
+    where
+        T: Into<PathBuf>,
+    {
         // TODO: Currently gets the physical directory. Get the logical directory.
         let current_dir = Context::expand_tilde(dir.into());
 
@@ -75,6 +119,9 @@ impl<'a> Context<'a> {
             properties,
             current_dir,
             dir_files: OnceCell::new(),
+            dir_scan_timed_out: AtomicBool::new(false),
+            env: Env::default(),
+            cmd: HashMap::new(),
             repo: OnceCell::new(),
         }
     }
@@ -92,7 +139,66 @@ impl<'a> Context<'a> {
     pub fn new_module(&self, name: &str) -> Module {
         let config = self.config.get_module_config(name);
 
-        Module::new(name, config)
+        let mut module = Module::new(name, config);
+        module.get_prefix().set_value(self.locale().via);
+        module
+    }
+
+    /// Get the locale used for the words shown around modules
+    ///
+    /// The `locale` option of the config takes precedence over the language
+    /// set in the environment (`$LC_ALL`, `$LC_MESSAGES` or `$LANG`).
+    pub fn locale(&self) -> &'static Locale {
+        let language = self
+            .config
+            .get_root_config()
+            .locale
+            .map(String::from)
+            .or_else(|| {
+                ["LC_ALL", "LC_MESSAGES", "LANG"]
+                    .iter()
+                    .filter_map(|key| self.get_env(key))
+                    .find(|value| !value.is_empty())
+            })
+            .unwrap_or_default();
+
+        locale::get(&language)
+    }
+
+    /// Whether segments should be rendered as hyperlinks, when a module has
+    /// something to link them to
+    pub fn hyperlinks(&self) -> bool {
+        self.config.get_root_config().hyperlinks
+    }
+
+    /// Get the value of an environment variable, as seen by the modules
+    pub fn get_env<K: AsRef<str>>(&self, key: K) -> Option<String> {
+        self.env.get(key.as_ref())
+    }
+
+    /// Execute a command and return its output, or its stubbed output if one was provided
+    pub fn exec_cmd(&self, cmd: &str, args: &[&str]) -> Option<CommandOutput> {
+        let command_line = format!("{} {}", cmd, args.join(" "));
+        if let Some(output) = self.cmd.get(command_line.trim_end()) {
+            log::trace!("Using stubbed output for '{}'", command_line.trim_end());
+            return output.clone();
+        }
+        utils::exec_cmd(cmd, args)
+    }
+
+    /// Execute a command like `exec_cmd`, giving up if it takes longer than `timeout`
+    pub fn exec_cmd_with_timeout(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        timeout: Duration,
+    ) -> Option<CommandOutput> {
+        let command_line = format!("{} {}", cmd, args.join(" "));
+        if let Some(output) = self.cmd.get(command_line.trim_end()) {
+            log::trace!("Using stubbed output for '{}'", command_line.trim_end());
+            return output.clone();
+        }
+        utils::exec_cmd_with_timeout(cmd, args, timeout)
     }
 
     /// Check if `disabled` option of the module is true in configuration file.
@@ -105,6 +211,32 @@ impl<'a> Context<'a> {
         disabled == Some(true)
     }
 
+    /// The priority of a module, from the `priority` option of its config or
+    /// its default priority
+    pub fn get_module_priority(&self, name: &str) -> Priority {
+        let priority = self
+            .config
+            .get_module_config(name)
+            .and_then(|table| table.as_table()?.get("priority")?.as_str());
+
+        match priority {
+            Some(value) => Priority::from_config(value).unwrap_or_else(|| {
+                log::warn!("Unknown priority \"{}\" for module \"{}\"", value, name);
+                Priority::default_for(name)
+            }),
+            None => Priority::default_for(name),
+        }
+    }
+
+    /// The width of the terminal in columns, from `--terminal-width` or `$COLUMNS`
+    pub fn terminal_width(&self) -> Option<usize> {
+        self.properties
+            .get("terminal_width")
+            .cloned()
+            .or_else(|| self.get_env("COLUMNS"))
+            .and_then(|width| width.trim().parse().ok())
+    }
+
     // returns a new ScanDir struct with reference to current dir_files of context
     // see ScanDir for methods
     pub fn try_begin_scan(&'a self) -> Option<ScanDir<'a>> {
@@ -133,6 +265,7 @@ impl<'a> Context<'a> {
                     branch,
                     root,
                     state,
+                    repository: repository.map(Mutex::new),
                 })
             })
     }
@@ -143,21 +276,97 @@ impl<'a> Context<'a> {
 
         self.dir_files
             .get_or_try_init(|| -> Result<Vec<PathBuf>, std::io::Error> {
-                let dir_files = fs::read_dir(&self.current_dir)?
-                    .take_while(|_item| {
-                        SystemTime::now().duration_since(start_time).unwrap() < scan_timeout
+                let dir_files = profile::record("scan", "scan current dir", || {
+                    fs::read_dir(&self.current_dir).map(|entries| {
+                        entries
+                            .take_while(|_item| {
+                                let in_time = SystemTime::now().duration_since(start_time).unwrap()
+                                    < scan_timeout;
+                                if !in_time {
+                                    self.dir_scan_timed_out.store(true, Ordering::Relaxed);
+                                }
+                                in_time
+                            })
+                            .filter_map(Result::ok)
+                            .map(|entry| entry.path())
+                            .collect::<Vec<PathBuf>>()
                     })
-                    .filter_map(Result::ok)
-                    .map(|entry| entry.path())
-                    .collect::<Vec<PathBuf>>();
+                })?;
 
                 log::trace!(
                     "Building a vector of directory files took {:?}",
                     SystemTime::now().duration_since(start_time).unwrap()
                 );
+                if self.dir_scan_timed_out() {
+                    log::debug!(
+                        "Scanning the current directory timed out after {:?}, only {} entries were read",
+                        scan_timeout,
+                        dir_files.len()
+                    );
+                }
                 Ok(dir_files)
             })
     }
+
+    /// Whether scanning the files of `current_dir` exceeded `scan_timeout`.
+    pub fn dir_scan_timed_out(&self) -> bool {
+        self.dir_scan_timed_out.load(Ordering::Relaxed)
+    }
+}
+
+/// The environment variables visible to modules.
+///
+/// Variables set with `insert` take precedence over the environment of the
+/// starship process. An isolated `Env` doesn't fall back to the process
+/// environment at all, which makes module output deterministic in tests.
+#[derive(Default)]
+pub struct Env {
+    vars: HashMap<String, String>,
+    isolated: bool,
+}
+
+impl Env {
+    /// Create an environment which only contains explicitly inserted variables
+    // Only used by the test harness, which the binary doesn't build
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn isolated() -> Self {
+        Env {
+            vars: HashMap::new(),
+            isolated: true,
+        }
+    }
+
+    /// Set a variable, overriding the value from the process environment
+    pub fn insert<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.vars.insert(key.into(), value.into());
+    }
+
+    /// Get the value of a variable
+    pub fn get(&self, key: &str) -> Option<String> {
+        match self.vars.get(key) {
+            Some(value) => Some(value.to_owned()),
+            None if self.isolated => None,
+            None => env::var(key).ok(),
+        }
+    }
+
+    /// Get every variable, sorted by name
+    pub fn all(&self) -> Vec<(String, String)> {
+        let mut vars: HashMap<String, String> = if self.isolated {
+            HashMap::new()
+        } else {
+            env::vars().collect()
+        };
+        vars.extend(self.vars.clone());
+
+        let mut vars: Vec<_> = vars.into_iter().collect();
+        vars.sort();
+        vars
+    }
 }
 
 pub struct Repo {
@@ -171,6 +380,19 @@ pub struct Repo {
 
     /// State
     pub state: Option<RepositoryState>,
+
+    /// The repository opened by `get_repo`, shared by the git modules
+    repository: Option<Mutex<Repository>>,
+}
+
+impl Repo {
+    /// Get the repository, opening it only once for all git modules.
+    ///
+    /// `git2::Repository` can't be used from several threads at once, so the
+    /// modules take turns using it.
+    pub fn open(&self) -> Option<MutexGuard<'_, Repository>> {
+        self.repository.as_ref()?.lock().ok()
+    }
 }
 
 // A struct of Criteria which will be used to verify current PathBuf is
@@ -201,13 +423,24 @@ impl<'a> ScanDir<'a> {
     /// based on the current Pathbuf check to see
     /// if any of this criteria match or exist and returning a boolean
     pub fn is_match(&self) -> bool {
-        self.dir_files.iter().any(|path| {
+        let is_match = self.dir_files.iter().any(|path| {
             if path.is_dir() {
                 path_has_name(path, self.folders)
             } else {
                 path_has_name(path, self.files) || has_extension(path, self.extensions)
             }
-        })
+        });
+
+        if !is_match {
+            log::trace!(
+                "No detection files matched (files: {:?}, extensions: {:?}, folders: {:?})",
+                self.files,
+                self.extensions,
+                self.folders
+            );
+        }
+
+        is_match
     }
 }
 
@@ -308,6 +541,15 @@ mod tests {
         assert_eq!(failing_dir_criteria.is_match(), false);
     }
 
+    #[test]
+    fn test_env_overrides_and_isolation() {
+        let mut env = Env::isolated();
+        env.insert("STARSHIP_TEST_VAR", "overridden");
+
+        assert_eq!(env.get("STARSHIP_TEST_VAR"), Some("overridden".to_string()));
+        assert_eq!(env.get("PATH"), None);
+    }
+
     #[test]
     fn test_criteria_scan_passes() {
         let passing_criteria = ScanDir {
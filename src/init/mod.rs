@@ -94,6 +94,50 @@ fi"#,
             );
             Some(script)
         }
+        Some("sh") | Some("dash") | Some("ash") => {
+            // POSIX shells have no process substitution, so the whole script
+            // is evaluated at once. Quoting it keeps its lines apart.
+            let script = format!("eval \"$(\"{}\" init sh --print-full-init)\"", starship);
+            Some(script)
+        }
+        Some("elvish") => {
+            // Elvish has no process substitution, but `eval` keeps the lines
+            // of the script apart
+            let script = format!(
+                "eval (\"{}\" init elvish --print-full-init | slurp)",
+                starship
+            );
+            Some(script)
+        }
+        Some("xonsh") => {
+            // xonsh runs Python code, which `execx` evaluates as a whole
+            let script = format!("execx($(\"{}\" init xonsh --print-full-init))", starship);
+            Some(script)
+        }
+        Some("tcsh") => {
+            /*
+             * tcsh has no process substitution, and replaces the newlines
+             * of a command substitution with spaces, so the whole script is
+             * evaluated as a single line. Every command of the script ends
+             * with a semicolon for that reason, and it has no comments.
+             *
+             * The command status is read at the start of the `precmd` alias,
+             * and the command duration is measured from the `postcmd` alias,
+             * which tcsh runs before every command.
+             */
+            let script = format!("eval `\"{}\" init tcsh --print-full-init`", starship);
+            Some(script)
+        }
+        Some("cmd") => {
+            // cmd.exe has no way to change its prompt from a script, so the
+            // prompt is drawn by Clink (https://chrisant996.github.io/clink),
+            // which loads Lua scripts and runs the script of this stub.
+            let script = format!(
+                "load(io.popen([[\"{}\" init cmd --print-full-init]]):read(\"*a\"))()",
+                starship
+            );
+            Some(script)
+        }
         Some("powershell") => {
             // Explanation of syntax:
             // &: Explicitly tells powershell to execute path with starship executable.
@@ -122,7 +166,7 @@ fi"#,
         Some(shell_basename) => {
             println!(
                 "printf \"\\n{0} is not yet supported by starship.\\n\
-                 For the time being, we support bash, zsh, and fish.\\n\
+                 For the time being, we support bash, zsh, fish, elvish, xonsh, tcsh, powershell, cmd and POSIX sh.\\n\
                  Please open an issue in the starship repo if you would like to \
                  see support for {0}:\\nhttps://github.com/starship/starship/issues/new\"\\n\\n",
                 shell_basename
@@ -145,7 +189,12 @@ pub fn init_main(shell_name: &str) -> io::Result<()> {
         "bash" => Some(BASH_INIT),
         "zsh" => Some(ZSH_INIT),
         "fish" => Some(FISH_INIT),
+        "elvish" => Some(ELVISH_INIT),
+        "xonsh" => Some(XONSH_INIT),
+        "tcsh" => Some(TCSH_INIT),
         "powershell" => Some(PWSH_INIT),
+        "cmd" => Some(CMD_INIT),
+        "sh" => Some(SH_INIT),
         _ => {
             println!(
                 "printf \"Shell name detection failed on phase two init.\\n\
@@ -185,4 +234,14 @@ const ZSH_INIT: &str = include_str!("starship.zsh");
 
 const FISH_INIT: &str = include_str!("starship.fish");
 
+const ELVISH_INIT: &str = include_str!("starship.elv");
+
+const XONSH_INIT: &str = include_str!("starship.xsh");
+
+const TCSH_INIT: &str = include_str!("starship.tcsh");
+
 const PWSH_INIT: &str = include_str!("starship.ps1");
+
+const CMD_INIT: &str = include_str!("starship.lua");
+
+const SH_INIT: &str = include_str!("starship.sh");
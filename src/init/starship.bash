@@ -24,7 +24,8 @@ starship_preexec() {
 # Will be run before the prompt is drawn
 starship_precmd() {
     # Save the status, because commands in this pipeline will change $?
-    STATUS=$?
+    # PIPESTATUS has to be saved in the same command, before it changes too
+    STATUS=$? STARSHIP_PIPE_STATUS=(${PIPESTATUS[@]})
 
     # Run the bash precmd function, if it's set. If not set, evaluates to no-op
     "${starship_precmd_user_func-:}"
@@ -33,10 +34,10 @@ starship_precmd() {
     if [[ $STARSHIP_START_TIME ]]; then
         STARSHIP_END_TIME=$(date +%s)
         STARSHIP_DURATION=$((STARSHIP_END_TIME - STARSHIP_START_TIME))
-        PS1="$(::STARSHIP:: prompt --status=$STATUS --jobs="$(jobs -p | wc -l)" --cmd-duration=$STARSHIP_DURATION)"
+        PS1="$(::STARSHIP:: prompt --status=$STATUS --pipestatus="${STARSHIP_PIPE_STATUS[*]}" --jobs="$(jobs -p | wc -l)" --terminal-width="$COLUMNS" --cmd-duration=$STARSHIP_DURATION --cmd-start=$STARSHIP_START_TIME)"
         unset STARSHIP_START_TIME
     else
-        PS1="$(::STARSHIP:: prompt --status=$STATUS --jobs="$(jobs -p | wc -l)")"
+        PS1="$(::STARSHIP:: prompt --status=$STATUS --pipestatus="${STARSHIP_PIPE_STATUS[*]}" --jobs="$(jobs -p | wc -l)" --terminal-width="$COLUMNS")"
     fi
     PREEXEC_READY=true;  # Signal that we can safely restart the timer
 }
@@ -66,4 +67,7 @@ fi
 
 # Set up the start time and STARSHIP_SHELL, which controls shell-specific sequences
 STARSHIP_START_TIME=$(date +%s)
-export STARSHIP_SHELL="bash"
\ No newline at end of file
+export STARSHIP_SHELL="bash"
+
+# Identify this shell session, so that its prompt can be cached
+export STARSHIP_SESSION_KEY="$RANDOM$RANDOM$RANDOM"
\ No newline at end of file
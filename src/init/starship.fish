@@ -1,3 +1,9 @@
+# Remember when each command starts, to tell apart the commands which end
+# with the same status and duration
+function starship_preexec --on-event fish_preexec
+    set -g STARSHIP_START_TIME (date +%s)
+end
+
 function fish_prompt
     switch "$fish_key_bindings"
         case fish_hybrid_key_bindings fish_vi_key_bindings
@@ -5,11 +11,13 @@ function fish_prompt
         case '*'
             set keymap insert
     end
+    # `set` keeps $status, so $pipestatus can be saved first
+    set -l exit_pipestatus $pipestatus
     set -l exit_code $status
     # Account for changes in variable name between v2.7 and v3.0
     set -l CMD_DURATION "$CMD_DURATION$cmd_duration"
     set -l starship_duration (math --scale=0 "$CMD_DURATION / 1000")
-    ::STARSHIP:: prompt --status=$exit_code --keymap=$keymap --cmd-duration=$starship_duration --jobs=(count (jobs -p))
+    ::STARSHIP:: prompt --status=$exit_code --pipestatus="$exit_pipestatus" --keymap=$keymap --cmd-duration=$starship_duration --cmd-start=$STARSHIP_START_TIME --jobs=(count (jobs -p)) --terminal-width=$COLUMNS
 end
 
 # disable virtualenv prompt, it breaks starship
@@ -17,3 +25,6 @@ set VIRTUAL_ENV_DISABLE_PROMPT 1
 
 function fish_mode_prompt; end
 export STARSHIP_SHELL="fish"
+
+# Identify this shell session, so that its prompt can be cached
+set -gx STARSHIP_SESSION_KEY (random)(random)(random)
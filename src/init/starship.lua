@@ -0,0 +1,52 @@
+-- Clink runs this script in cmd.exe, where starship is drawn by a prompt
+-- filter. The path is a long string, so that its backslashes aren't escapes.
+local starship = [[::STARSHIP::]]
+
+-- Clink tells when a command line is accepted and when the next one begins,
+-- which gives the duration of the command. Empty lines don't run a command.
+local start_time = nil
+local cmd_start = nil
+local cmd_duration = nil
+
+clink.onendedit(function (line)
+    if line:match("%S") then
+        start_time = os.time()
+    else
+        start_time = nil
+    end
+end)
+
+clink.onbeginedit(function ()
+    if start_time then
+        cmd_start = start_time
+        cmd_duration = os.time() - start_time
+    else
+        cmd_start = nil
+        cmd_duration = nil
+    end
+end)
+
+local starship_prompt = clink.promptfilter(1)
+
+function starship_prompt:filter(prompt)
+    local command = starship.." prompt"..
+        " --status="..os.geterrorlevel()..
+        " --terminal-width="..console.getwidth()
+    if cmd_duration then
+        command = command.." --cmd-duration="..cmd_duration.." --cmd-start="..cmd_start
+    end
+
+    local output = io.popen(command)
+    local text = output:read("*a")
+    output:close()
+
+    -- Stop the filters with a lower priority from changing the prompt
+    return text, false
+end
+
+-- Set up STARSHIP_SHELL, which controls shell-specific sequences
+os.setenv("STARSHIP_SHELL", "cmd")
+
+-- Identify this shell session, so that its prompt can be cached
+math.randomseed(os.time())
+os.setenv("STARSHIP_SESSION_KEY", tostring(math.random(1000000000))..tostring(math.random(1000000000)))
@@ -6,13 +6,15 @@ function global:prompt {
     $out = $null
     # @ makes sure the result is an array even if single or no values are returned
     $jobs = @(Get-Job | Where-Object { $_.State -eq 'Running' }).Count
+    $width = $Host.UI.RawUI.WindowSize.Width
 
     if ($lastCmd = Get-History -Count 1) {
         $duration = [math]::Round(($lastCmd.EndExecutionTime - $lastCmd.StartExecutionTime).TotalSeconds)
+        $start = ([DateTimeOffset]$lastCmd.StartExecutionTime).ToUnixTimeSeconds()
         # & ensures the path is interpreted as something to execute
-        $out = @(&::STARSHIP:: prompt --status=$lastexitcode --jobs=$jobs --cmd-duration=$duration)
+        $out = @(&::STARSHIP:: prompt --status=$lastexitcode --jobs=$jobs --terminal-width=$width --cmd-duration=$duration --cmd-start=$start)
     } else {
-        $out = @(&::STARSHIP:: prompt --status=$lastexitcode --jobs=$jobs)
+        $out = @(&::STARSHIP:: prompt --status=$lastexitcode --jobs=$jobs --terminal-width=$width)
     }
 
     # Convert stdout (array of lines) to expected return type string
@@ -21,3 +23,6 @@ function global:prompt {
 }
 
 $ENV:STARSHIP_SHELL = "powershell"
+
+# Identify this shell session, so that its prompt can be cached
+$ENV:STARSHIP_SESSION_KEY = "$(Get-Random)$(Get-Random)"
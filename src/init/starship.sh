@@ -0,0 +1,39 @@
+# POSIX shells (dash, ash, BusyBox...) have neither PROMPT_COMMAND nor hooks
+# running before commands, so the prompt is rendered by a command substitution
+# in PS1, which these shells expand every time they draw the prompt.
+
+# A command substitution runs in a subshell and can't set variables for the
+# next prompt, so the time the last prompt was drawn is kept in a file. The
+# command duration is measured from that time: it includes the time spent
+# typing the command, unlike in shells which can tell when a command starts.
+STARSHIP_TIME_FILE="${TMPDIR:-/tmp}/starship_time_$$"
+
+starship_prompt() {
+    # Save the status, because the commands below will change $?
+    STATUS=$?
+    STARSHIP_END_TIME=$(date +%s)
+    # These shells rarely export COLUMNS, so tput gives the width instead
+    STARSHIP_COLUMNS="${COLUMNS:-$(tput cols 2>/dev/null)}"
+
+    if [ -r "$STARSHIP_TIME_FILE" ]; then
+        read -r STARSHIP_START_TIME < "$STARSHIP_TIME_FILE"
+        STARSHIP_DURATION=$((STARSHIP_END_TIME - STARSHIP_START_TIME))
+        ::STARSHIP:: prompt --status="$STATUS" --terminal-width="$STARSHIP_COLUMNS" --cmd-duration="$STARSHIP_DURATION" --cmd-start="$STARSHIP_START_TIME"
+    else
+        ::STARSHIP:: prompt --status="$STATUS" --terminal-width="$STARSHIP_COLUMNS"
+    fi
+
+    echo "$STARSHIP_END_TIME" > "$STARSHIP_TIME_FILE"
+}
+
+# Remove the file keeping the time of the last prompt when the shell exits
+trap 'rm -f "$STARSHIP_TIME_FILE"' EXIT
+
+# The single quotes make sure starship runs for every prompt, not just once now
+PS1='$(starship_prompt)'
+
+# Set up STARSHIP_SHELL, which controls shell-specific sequences
+export STARSHIP_SHELL="sh"
+
+# Identify this shell session, so that its prompt can be cached
+export STARSHIP_SESSION_KEY="$$$(date +%s)"
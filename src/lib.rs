@@ -1,9 +1,16 @@
-// Lib is present to allow for benchmarking
+// Lib is present to allow for benchmarking and for testing modules
+mod badge;
 pub mod config;
 pub mod configs;
 pub mod context;
+pub mod locale;
 pub mod module;
 pub mod modules;
+mod notification;
 pub mod print;
+mod profile;
+mod prompt_cache;
 pub mod segment;
+#[cfg(any(test, feature = "test-harness"))]
+pub mod test;
 mod utils;
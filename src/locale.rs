@@ -0,0 +1,136 @@
+/// The human-readable words starship puts around the information shown by modules
+#[derive(Debug, PartialEq)]
+pub struct Locale {
+    /// The default module prefix, e.g. "via 🦀 v1.40.0"
+    pub via: &'static str,
+    /// Prefix for the hostname and the git branch
+    pub on: &'static str,
+    /// Prefix for the current directory
+    pub in_dir: &'static str,
+    /// Prefix for the package version
+    pub is: &'static str,
+    /// Prefix for environment variables
+    pub with: &'static str,
+    /// Prefix for the command duration
+    pub took: &'static str,
+    /// Prefix for the current time
+    pub at: &'static str,
+    /// Unit suffixes for days, hours, minutes and seconds in durations
+    pub duration_units: [&'static str; 4],
+}
+
+pub const EN: Locale = Locale {
+    via: "via ",
+    on: "on ",
+    in_dir: "in ",
+    is: "is ",
+    with: "with ",
+    took: "took ",
+    at: "at ",
+    duration_units: ["d", "h", "m", "s"],
+};
+
+pub const DE: Locale = Locale {
+    via: "mit ",
+    on: "auf ",
+    in_dir: "in ",
+    is: "ist ",
+    with: "mit ",
+    took: "dauerte ",
+    at: "um ",
+    duration_units: ["T", "h", "m", "s"],
+};
+
+pub const ES: Locale = Locale {
+    via: "vía ",
+    on: "en ",
+    in_dir: "en ",
+    is: "es ",
+    with: "con ",
+    took: "tardó ",
+    at: "a las ",
+    duration_units: ["d", "h", "m", "s"],
+};
+
+pub const FR: Locale = Locale {
+    via: "via ",
+    on: "sur ",
+    in_dir: "dans ",
+    is: "est ",
+    with: "avec ",
+    took: "a pris ",
+    at: "à ",
+    duration_units: ["j", "h", "m", "s"],
+};
+
+/// Get the locale for a language, given as a language code (e.g. `"de"`) or as
+/// the value of `$LANG` (e.g. `"de_DE.UTF-8"`). Falls back to English.
+pub fn get(language: &str) -> &'static Locale {
+    let code = language
+        .split(&['_', '-', '.', '@'][..])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    match code.as_str() {
+        "de" => &DE,
+        "es" => &ES,
+        "fr" => &FR,
+        _ => &EN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_get_locale() {
+        assert_eq!(get("de"), &DE);
+        assert_eq!(get("de_DE.UTF-8"), &DE);
+        assert_eq!(get("fr-CA"), &FR);
+        assert_eq!(get("ES"), &ES);
+        assert_eq!(get("C"), &EN);
+        assert_eq!(get(""), &EN);
+    }
+
+    #[test]
+    fn locale_from_lang() {
+        let actual = ModuleRenderer::new("env_var")
+            .env("LANG", "fr_FR.UTF-8")
+            .env("ASTRONAUT", "Thomas")
+            .config(toml::toml! {
+                [env_var]
+                variable = "ASTRONAUT"
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "avec {} ",
+            Color::Black.bold().dimmed().paint("Thomas")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn locale_from_config_overrides_lang() {
+        let actual = ModuleRenderer::new("env_var")
+            .env("LANG", "fr_FR.UTF-8")
+            .env("ASTRONAUT", "Alexander")
+            .config(toml::toml! {
+                locale = "de"
+
+                [env_var]
+                variable = "ASTRONAUT"
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "mit {} ",
+            Color::Black.bold().dimmed().paint("Alexander")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,14 +1,22 @@
 #[macro_use]
 extern crate clap;
 
+mod badge;
 mod config;
 mod configs;
 mod context;
 mod init;
+mod locale;
 mod module;
 mod modules;
+mod notification;
 mod print;
+mod profile;
+mod prompt_cache;
 mod segment;
+#[cfg(test)]
+mod test;
+mod update;
 mod utils;
 
 use crate::module::ALL_MODULES;
@@ -24,6 +32,14 @@ fn main() {
         .help("The status code of the previously run command")
         .takes_value(true);
 
+    let pipestatus_arg = Arg::with_name("pipestatus")
+        .long("pipestatus")
+        .value_name("PIPESTATUS")
+        .help(
+            "The status codes of the commands of the previously run pipeline, separated by spaces",
+        )
+        .takes_value(true);
+
     let path_arg = Arg::with_name("path")
         .short("p")
         .long("path")
@@ -34,7 +50,7 @@ fn main() {
     let shell_arg = Arg::with_name("shell")
         .value_name("SHELL")
         .help(
-            "The name of the currently running shell\nCurrently supported options: bash, zsh, fish, powershell",
+            "The name of the currently running shell\nCurrently supported options: bash, zsh, fish, elvish, xonsh, tcsh, powershell, cmd, sh",
         )
         .required(true);
 
@@ -45,6 +61,12 @@ fn main() {
         .help("The execution duration of the last command, in seconds")
         .takes_value(true);
 
+    let cmd_start_arg = Arg::with_name("cmd_start")
+        .long("cmd-start")
+        .value_name("CMD_START")
+        .help("The time the last command started, in seconds since the Unix epoch")
+        .takes_value(true);
+
     let keymap_arg = Arg::with_name("keymap")
         .short("k")
         .long("keymap")
@@ -60,6 +82,45 @@ fn main() {
         .help("The number of currently running jobs")
         .takes_value(true);
 
+    let terminal_width_arg = Arg::with_name("terminal_width")
+        .short("w")
+        .long("terminal-width")
+        .value_name("WIDTH")
+        .help("The width of the terminal, in columns")
+        .takes_value(true);
+
+    let env_arg = Arg::with_name("env")
+        .short("e")
+        .long("env")
+        .value_name("KEY=VALUE")
+        .help("Set an environment variable for the modules, overriding the real environment")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .validator(|value| {
+            if value.contains('=') {
+                Ok(())
+            } else {
+                Err(String::from(
+                    "expected an environment variable as KEY=VALUE",
+                ))
+            }
+        });
+
+    let stdin_config_arg = Arg::with_name("stdin_config")
+        .long("stdin-config")
+        .help("Read the configuration from stdin instead of the configuration file");
+
+    let tmux_arg = Arg::with_name("tmux")
+        .long("tmux")
+        .help("Render with tmux style syntax instead of ANSI escapes, for the tmux status line");
+
+    let profile_output_arg = Arg::with_name("profile_output")
+        .long("profile-output")
+        .value_name("FILE")
+        .help("Write a Chrome tracing profile of the prompt rendering to FILE")
+        .takes_value(true);
+
     let init_scripts_arg = Arg::with_name("print_full_init")
         .long("print-full-init")
         .help("Print the main initialization script (as opposed to the init stub)");
@@ -82,10 +143,17 @@ fn main() {
             SubCommand::with_name("prompt")
                 .about("Prints the full starship prompt")
                 .arg(&status_code_arg)
+                .arg(&pipestatus_arg)
                 .arg(&path_arg)
                 .arg(&cmd_duration_arg)
+                .arg(&cmd_start_arg)
                 .arg(&keymap_arg)
-                .arg(&jobs_arg),
+                .arg(&jobs_arg)
+                .arg(&terminal_width_arg)
+                .arg(&env_arg)
+                .arg(&stdin_config_arg)
+                .arg(&tmux_arg)
+                .arg(&profile_output_arg),
         )
         .subcommand(
             SubCommand::with_name("module")
@@ -103,10 +171,23 @@ fn main() {
                         .help("List out all supported modules"),
                 )
                 .arg(&status_code_arg)
+                .arg(&pipestatus_arg)
                 .arg(&path_arg)
                 .arg(&cmd_duration_arg)
                 .arg(&keymap_arg)
-                .arg(&jobs_arg),
+                .arg(&jobs_arg)
+                .arg(&env_arg)
+                .arg(&stdin_config_arg)
+                .arg(&tmux_arg),
+        )
+        .subcommand(
+            SubCommand::with_name("update")
+                .about("Updates starship to the latest release")
+                .arg(
+                    Arg::with_name("check")
+                        .long("check")
+                        .help("Only check whether a newer release is available"),
+                ),
         )
         .get_matches();
 
@@ -132,6 +213,12 @@ fn main() {
                 print::module(module_name, sub_m.clone());
             }
         }
+        ("update", Some(sub_m)) => {
+            if let Err(e) = update::update(sub_m.is_present("check")) {
+                eprintln!("Unable to update starship: {}", e);
+                std::process::exit(1);
+            }
+        }
         _ => {}
     }
 }
@@ -1,42 +1,140 @@
 use crate::config::SegmentConfig;
 use crate::segment::Segment;
-use ansi_term::Style;
 use ansi_term::{ANSIString, ANSIStrings};
+use ansi_term::{Color, Style};
 use std::fmt;
+use unicode_width::UnicodeWidthChar;
 
 // List of all modules
 // Keep these ordered alphabetically.
 // Default ordering is handled in configs/mod.rs
 pub const ALL_MODULES: &[&str] = &[
     "aws",
+    "azure",
     #[cfg(feature = "battery")]
     "battery",
+    "bazel",
+    "buf",
+    "bun",
     "character",
+    "cmake",
     "cmd_duration",
+    "cobol",
     "conda",
+    "container",
+    "crystal",
+    "dart",
+    "deno",
     "directory",
+    "direnv",
     "dotnet",
+    "elm",
     "env_var",
+    "erlang",
+    "fill",
+    "fossil_branch",
+    "gcloud",
     "git_branch",
+    "git_commit",
+    "git_metrics",
+    "git_remote",
     "git_state",
     "git_status",
+    "gleam",
+    "godot",
     "golang",
+    "gradle",
+    "haskell",
+    "haxe",
+    "helm",
+    "hg_branch",
     "hostname",
     "java",
     "jobs",
+    "julia",
+    "jupyter",
+    "kotlin",
     "kubernetes",
+    "latex",
     "line_break",
+    "localip",
+    "lua",
     "memory_usage",
+    "meson",
+    "nim",
     "nix_shell",
     "nodejs",
+    "nomad",
+    "ocaml",
+    "odin",
+    "openstack",
+    "os",
     "package",
+    "php",
+    "pixi",
+    "pulumi",
+    "purescript",
     "python",
+    "quarto",
+    "raku",
+    "rlang",
     "ruby",
     "rust",
+    "scala",
+    "shell",
+    "singularity",
+    "solidity",
+    "spack",
+    "status",
+    "sudo",
+    "svn",
+    "terraform",
     "time",
+    "typst",
+    "unity",
+    "unreal",
     "username",
+    "vagrant",
+    "vlang",
 ];
 
+/// Modules which are left out of the prompt on narrow terminals, unless their
+/// config sets a different `priority`
+pub const LOW_PRIORITY_MODULES: &[&str] = &[
+    #[cfg(feature = "battery")]
+    "battery",
+    "memory_usage",
+    "time",
+];
+
+/// How important it is to show a module when space is short
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Priority {
+    /// Left out of the prompt on terminals narrower than `responsive_width`
+    Low,
+    /// Always shown
+    Normal,
+}
+
+impl Priority {
+    /// The default priority of the module with the given name
+    pub fn default_for(name: &str) -> Self {
+        if LOW_PRIORITY_MODULES.contains(&name) {
+            Priority::Low
+        } else {
+            Priority::Normal
+        }
+    }
+
+    pub fn from_config(value: &str) -> Option<Self> {
+        match value {
+            "low" => Some(Priority::Low),
+            "normal" => Some(Priority::Normal),
+            _ => None,
+        }
+    }
+}
+
 /// A module is a collection of segments showing data for a single integration
 /// (e.g. The git module shows the current git branch and status)
 pub struct Module<'a> {
@@ -123,21 +221,166 @@ impl<'a> Module<'a> {
             .map(Segment::ansi_string)
             .collect::<Vec<ANSIString>>();
 
-        let mut ansi_strings = match shell.as_str() {
-            "bash" => ansi_strings_modified(ansi_strings, shell),
-            "zsh" => ansi_strings_modified(ansi_strings, shell),
+        let ansi_strings = match shell.as_str() {
+            "bash" => ansi_strings_modified(ansi_strings, shell.clone()),
+            "zsh" => ansi_strings_modified(ansi_strings, shell.clone()),
+            "tcsh" => ansi_strings_modified(ansi_strings, shell.clone()),
             _ => ansi_strings,
         };
 
+        let mut ansi_strings = ansi_strings
+            .into_iter()
+            .zip(&self.segments)
+            .map(|(ansi_string, segment)| match segment.get_link() {
+                Some(url) => ANSIString::from(hyperlink(&ansi_string.to_string(), url, &shell)),
+                None => ansi_string,
+            })
+            .collect::<Vec<ANSIString>>();
+
         ansi_strings.insert(0, self.prefix.ansi_string());
         ansi_strings.push(self.suffix.ansi_string());
 
+        let escape: Option<fn(&str) -> String> = match shell.as_str() {
+            "xonsh" => Some(xonsh_escaped),
+            "tcsh" => Some(tcsh_escaped),
+            _ => None,
+        };
+        if let Some(escape) = escape {
+            ansi_strings = ansi_strings
+                .iter()
+                .map(|ansi_string| ANSIString::from(escape(&ansi_string.to_string())))
+                .collect();
+        }
+
         ansi_strings
     }
 
     pub fn to_string_without_prefix(&self) -> String {
         ANSIStrings(&self.ansi_strings()[1..]).to_string()
     }
+
+    /// Repeat the value of the module's first segment until it is `width`
+    /// columns wide, padding with spaces if the value doesn't fit exactly.
+    pub fn stretch_to(&mut self, width: usize) {
+        let segment = match self.segments.first_mut() {
+            Some(segment) => segment,
+            None => return,
+        };
+
+        let pattern: Vec<char> = segment
+            .get_value()
+            .chars()
+            .filter(|c| c.width().unwrap_or(0) > 0)
+            .collect();
+        let pattern = if pattern.is_empty() {
+            vec![' ']
+        } else {
+            pattern
+        };
+
+        let mut value = String::new();
+        let mut value_width = 0;
+        for c in pattern.iter().cycle() {
+            let char_width = c.width().unwrap_or(0);
+            if value_width + char_width > width {
+                break;
+            }
+            value.push(*c);
+            value_width += char_width;
+        }
+        value.push_str(&" ".repeat(width - value_width));
+
+        segment.set_value(value);
+    }
+
+    /// Renders the module for the tmux status line, styled with tmux's
+    /// `#[fg=...]` syntax instead of ANSI escape sequences.
+    pub fn to_tmux_string(&self, with_prefix: bool) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        if with_prefix {
+            parts.push(tmux_styled(&self.prefix.value, self.prefix.style));
+        }
+        parts.extend(
+            self.segments
+                .iter()
+                .map(|segment| tmux_styled(segment.get_value(), segment.get_style())),
+        );
+        parts.push(tmux_styled(&self.suffix.value, self.suffix.style));
+
+        parts.concat()
+    }
+
+    /// Renders the module as plain words for screen readers: without any
+    /// escape sequences, with symbols replaced by what they stand for and
+    /// with decorative separators left out.
+    pub fn to_screen_reader_string(&self, with_prefix: bool) -> String {
+        match self._name.as_str() {
+            "line_break" => return String::from("\n"),
+            "fill" => return String::new(),
+            _ => {}
+        }
+
+        let mut words: Vec<String> = Vec::new();
+        if with_prefix {
+            words.push(spoken_affix(&self.prefix.value));
+        }
+        words.extend(self.segments.iter().filter_map(|segment| {
+            spoken_segment(&self._name, segment.get_name(), segment.get_value())
+        }));
+        words.push(spoken_affix(&self.suffix.value));
+
+        words
+            .iter()
+            .map(|word| word.trim())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+}
+
+/// Whether a value is made of symbols only (e.g. "⇡", "[" or "🦀 ")
+fn is_symbolic(value: &str) -> bool {
+    !value.chars().any(char::is_alphanumeric)
+}
+
+/// Affixes are either words (e.g. "via ") or decorations (e.g. "[")
+fn spoken_affix(value: &str) -> String {
+    if is_symbolic(value) {
+        String::new()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Get the words a screen reader should read for a segment, if any
+fn spoken_segment(module_name: &str, segment_name: &str, value: &str) -> Option<String> {
+    if value.trim().is_empty() {
+        return None;
+    }
+
+    if module_name == "character" {
+        return match segment_name {
+            "error_symbol" => Some(String::from("error >")),
+            "vicmd_symbol" => Some(String::from("normal >")),
+            _ => Some(String::from(">")),
+        };
+    }
+
+    let is_symbol = segment_name == "symbol" || segment_name.ends_with("_symbol");
+    if !is_symbol && !is_symbolic(value) {
+        return Some(value.to_string());
+    }
+
+    // Symbols are replaced by the name of what they stand for, e.g. the
+    // "symbol" of the rust module by "rust" and a git status "⇡" by "ahead"
+    let name = match segment_name {
+        "symbol" => module_name,
+        "separator" | "prefix" | "suffix" => return None,
+        "progress_divider" => "of",
+        name => name.trim_end_matches("_symbol"),
+    };
+
+    Some(name.replace('_', " "))
 }
 
 impl<'a> fmt::Display for Module<'a> {
@@ -165,7 +408,7 @@ fn ansi_strings_modified(ansi_strings: Vec<ANSIString>, shell: String) -> Vec<AN
                         escaped = true;
                         match shell.as_str() {
                             "bash" => String::from("\u{5c}\u{5b}\u{1b}"), // => \[ESC
-                            "zsh" => String::from("\u{25}\u{7b}\u{1b}"),  // => %{ESC
+                            "zsh" | "tcsh" => String::from("\u{25}\u{7b}\u{1b}"), // => %{ESC
                             _ => x.to_string(),
                         }
                     }
@@ -173,8 +416,8 @@ fn ansi_strings_modified(ansi_strings: Vec<ANSIString>, shell: String) -> Vec<AN
                         if escaped {
                             escaped = false;
                             match shell.as_str() {
-                                "bash" => String::from("m\u{5c}\u{5d}"), // => m\]
-                                "zsh" => String::from("m\u{25}\u{7d}"),  // => m%}
+                                "bash" => String::from("m\u{5c}\u{5d}"),         // => m\]
+                                "zsh" | "tcsh" => String::from("m\u{25}\u{7d}"), // => m%}
                                 _ => x.to_string(),
                             }
                         } else {
@@ -189,6 +432,98 @@ fn ansi_strings_modified(ansi_strings: Vec<ANSIString>, shell: String) -> Vec<AN
         .collect::<Vec<ANSIString>>()
 }
 
+/// Style text with tmux's `#[...]` syntax, escaping the `#` characters tmux
+/// would interpret
+fn tmux_styled(text: &str, style: Style) -> String {
+    let text = text.replace('#', "##");
+    if text.is_empty() || style == Style::default() {
+        return text;
+    }
+
+    format!("#[{}]{}#[default]", tmux_attributes(style).join(","), text)
+}
+
+fn tmux_attributes(style: Style) -> Vec<String> {
+    let mut attributes = Vec::new();
+    if let Some(color) = style.foreground {
+        attributes.push(format!("fg={}", tmux_color(color)));
+    }
+    if let Some(color) = style.background {
+        attributes.push(format!("bg={}", tmux_color(color)));
+    }
+
+    let flags = [
+        (style.is_bold, "bold"),
+        (style.is_dimmed, "dim"),
+        (style.is_italic, "italics"),
+        (style.is_underline, "underscore"),
+        (style.is_blink, "blink"),
+        (style.is_reverse, "reverse"),
+        (style.is_hidden, "hidden"),
+        (style.is_strikethrough, "strikethrough"),
+    ];
+    attributes.extend(
+        flags
+            .iter()
+            .filter(|(is_set, _)| *is_set)
+            .map(|(_, name)| name.to_string()),
+    );
+
+    attributes
+}
+
+fn tmux_color(color: Color) -> String {
+    match color {
+        Color::Black => String::from("black"),
+        Color::Red => String::from("red"),
+        Color::Green => String::from("green"),
+        Color::Yellow => String::from("yellow"),
+        Color::Blue => String::from("blue"),
+        Color::Purple => String::from("magenta"),
+        Color::Cyan => String::from("cyan"),
+        Color::White => String::from("white"),
+        Color::Fixed(number) => format!("colour{}", number),
+        Color::RGB(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+    }
+}
+
+/// Wrap text in an OSC 8 escape sequence, making it a link to `url` in the
+/// terminals supporting it. Like styles, the escape sequences are marked as
+/// zero-width for bash and zsh.
+///
+/// The sequences end with ST (`ESC \`), except in bash where its backslash
+/// would escape the `\]` closing the zero-width sequence, so BEL is used.
+fn hyperlink(text: &str, url: &str, shell: &str) -> String {
+    let terminator = if shell == "bash" { "\u{7}" } else { "\u{1b}\\" };
+    format!(
+        "{}{}{}",
+        zero_width(&format!("\u{1b}]8;;{}{}", url, terminator), shell),
+        text,
+        zero_width(&format!("\u{1b}]8;;{}", terminator), shell)
+    )
+}
+
+/// Escape the braces xonsh would interpret, as it formats the prompt as a
+/// template where e.g. `{cwd}` is a field
+fn xonsh_escaped(text: &str) -> String {
+    text.replace('{', "{{").replace('}', "}}")
+}
+
+/// Escape the `!` characters tcsh would replace with the history event number
+fn tcsh_escaped(text: &str) -> String {
+    text.replace('!', "\\!")
+}
+
+/// Mark an escape sequence as taking no space, for the shells which need to
+/// know it to place the cursor
+pub fn zero_width(escape: &str, shell: &str) -> String {
+    match shell {
+        "bash" => format!("\\[{}\\]", escape),
+        "zsh" | "tcsh" => format!("%{{{}%}}", escape),
+        _ => escape.to_string(),
+    }
+}
+
 /// Module affixes are to be used for the prefix or suffix of a module.
 pub struct Affix {
     /// The affix's name, to be used in configuration and logging.
@@ -254,6 +589,62 @@ impl fmt::Display for Affix {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tmux_string() {
+        let mut module = Module::new("rust", None);
+        module.create_segment(
+            "symbol",
+            &SegmentConfig {
+                value: "🦀 ",
+                style: Some(Color::Red.bold()),
+            },
+        );
+        module.create_segment(
+            "version",
+            &SegmentConfig {
+                value: "#1",
+                style: Some(Color::Fixed(208).on(Color::RGB(0, 16, 255))),
+            },
+        );
+
+        assert_eq!(
+            module.to_tmux_string(true),
+            "via #[fg=red,bold]🦀 #[default]#[fg=colour208,bg=#0010ff]##1#[default] "
+        );
+        assert_eq!(
+            module.to_tmux_string(false),
+            "#[fg=red,bold]🦀 #[default]#[fg=colour208,bg=#0010ff]##1#[default] "
+        );
+    }
+
+    #[test]
+    fn test_hyperlink() {
+        assert_eq!(
+            hyperlink("main", "https://example.com", ""),
+            "\u{1b}]8;;https://example.com\u{1b}\\main\u{1b}]8;;\u{1b}\\"
+        );
+        assert_eq!(
+            hyperlink("main", "https://example.com", "zsh"),
+            "%{\u{1b}]8;;https://example.com\u{1b}\\%}main%{\u{1b}]8;;\u{1b}\\%}"
+        );
+        assert_eq!(
+            hyperlink("main", "https://example.com", "bash"),
+            "\\[\u{1b}]8;;https://example.com\u{7}\\]main\\[\u{1b}]8;;\u{7}\\]"
+        );
+    }
+
+    #[test]
+    fn test_xonsh_escaped() {
+        assert_eq!(xonsh_escaped("feature/{x}"), "feature/{{x}}");
+        assert_eq!(xonsh_escaped("main"), "main");
+    }
+
+    #[test]
+    fn test_tcsh_escaped() {
+        assert_eq!(tcsh_escaped("[!]"), "[\\!]");
+        assert_eq!(tcsh_escaped("main"), "main");
+    }
+
     #[test]
     fn test_module_is_empty_with_no_segments() {
         let name = "unit_test";
@@ -283,4 +674,44 @@ mod tests {
 
         assert!(module.is_empty());
     }
+
+    #[test]
+    fn test_screen_reader_string() {
+        let mut module = Module::new("rust", None);
+        module.create_segment("symbol", &SegmentConfig::new("🦀 "));
+        module.create_segment("version", &SegmentConfig::new("v1.40.0"));
+
+        assert_eq!(module.to_screen_reader_string(true), "via rust v1.40.0");
+        assert_eq!(module.to_screen_reader_string(false), "rust v1.40.0");
+    }
+
+    #[test]
+    fn test_screen_reader_string_without_decorations() {
+        let mut module = Module::new("git_status", None);
+        module.get_prefix().set_value("[");
+        module.get_suffix().set_value("] ");
+        module.create_segment("ahead", &SegmentConfig::new("⇡"));
+        module.create_segment("modified", &SegmentConfig::new("!"));
+        module.create_segment("modified_count", &SegmentConfig::new("3"));
+
+        assert_eq!(module.to_screen_reader_string(true), "ahead modified 3");
+    }
+
+    #[test]
+    fn test_spoken_segment() {
+        assert_eq!(
+            spoken_segment("battery", "charging_symbol", "⚡️"),
+            Some("charging".to_string())
+        );
+        assert_eq!(
+            spoken_segment("git_state", "progress_divider", "/"),
+            Some("of".to_string())
+        );
+        assert_eq!(spoken_segment("memory_usage", "separator", " | "), None);
+        assert_eq!(
+            spoken_segment("character", "error_symbol", "✖"),
+            Some("error >".to_string())
+        );
+        assert_eq!(spoken_segment("time", "time", " "), None);
+    }
 }
@@ -1,4 +1,3 @@
-use std::env;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
@@ -13,9 +12,9 @@ use crate::configs::aws::{AwsConfig, AwsItems};
 type Profile = String;
 type Region = String;
 
-fn get_aws_region_from_config(aws_profile: Option<&str>) -> Option<Region> {
-    let config_location = env::var("AWS_CONFIG_FILE")
-        .ok()
+fn get_aws_region_from_config(context: &Context, aws_profile: Option<&str>) -> Option<Region> {
+    let config_location = context
+        .get_env("AWS_CONFIG_FILE")
         .and_then(|path| PathBuf::from_str(&path).ok())
         .or_else(|| {
             let mut home = home_dir()?;
@@ -47,49 +46,66 @@ fn get_aws_region_from_config(aws_profile: Option<&str>) -> Option<Region> {
     Some(region.to_string())
 }
 
-fn get_aws_profile_and_region() -> (Option<Profile>, Option<Region>) {
+/// The active profile, set by the AWS CLI or by aws-vault when running a
+/// shell with `aws-vault exec`
+fn get_aws_profile(context: &Context) -> Option<Profile> {
+    context
+        .get_env("AWS_PROFILE")
+        .or_else(|| context.get_env("AWS_VAULT"))
+}
+
+fn get_aws_profile_and_region(context: &Context) -> (Option<Profile>, Option<Region>) {
     match (
-        env::var("AWS_PROFILE").ok(),
-        env::var("AWS_REGION").ok(),
-        env::var("AWS_DEFAULT_REGION").ok(),
+        get_aws_profile(context),
+        context.get_env("AWS_REGION"),
+        context.get_env("AWS_DEFAULT_REGION"),
     ) {
         (Some(p), Some(_), Some(dr)) => (Some(p), Some(dr)),
         (Some(p), Some(r), None) => (Some(p), Some(r)),
         (None, Some(r), None) => (None, Some(r)),
         (Some(p), None, Some(dr)) => (Some(p), Some(dr)),
-        (Some(ref p), None, None) => (Some(p.to_owned()), get_aws_region_from_config(Some(p))),
+        (Some(ref p), None, None) => (
+            Some(p.to_owned()),
+            get_aws_region_from_config(context, Some(p)),
+        ),
         (None, None, Some(dr)) => (None, Some(dr)),
         (None, Some(_), Some(dr)) => (None, Some(dr)),
-        (None, None, None) => (None, get_aws_region_from_config(None)),
+        (None, None, None) => (None, get_aws_region_from_config(context, None)),
     }
 }
 
-fn get_aws_region() -> Option<Region> {
+fn get_aws_region(context: &Context) -> Option<Region> {
     match (
-        env::var("AWS_REGION").ok(),
-        env::var("AWS_DEFAULT_REGION").ok(),
+        context.get_env("AWS_REGION"),
+        context.get_env("AWS_DEFAULT_REGION"),
     ) {
         (Some(r), None) => Some(r),
         (None, Some(dr)) => Some(dr),
         (Some(_), Some(dr)) => Some(dr),
-        (None, None) => get_aws_region_from_config(None),
+        (None, None) => get_aws_region_from_config(context, None),
     }
 }
 
-pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    const AWS_PREFIX: &str = "on ";
+fn alias_region(region: Region, config: &AwsConfig) -> Region {
+    match config.region_aliases.get(&region) {
+        Some(alias) => (*alias).to_string(),
+        None => region,
+    }
+}
 
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("aws");
     let config: AwsConfig = AwsConfig::try_load(module.config);
 
     module.set_style(config.style);
 
-    module.get_prefix().set_value(AWS_PREFIX);
+    module.get_prefix().set_value(context.locale().on);
 
     module.create_segment("symbol", &config.symbol);
     match config.displayed_items {
         AwsItems::All => {
-            let (aws_profile, aws_region) = get_aws_profile_and_region();
+            let (aws_profile, aws_region) = get_aws_profile_and_region(context);
+            let aws_region = aws_region.map(|region| alias_region(region, &config));
 
             let aws_segment = match (&aws_profile, &aws_region) {
                 (None, None) => return None,
@@ -100,12 +116,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             module.create_segment("all", &config.region.with_value(&aws_segment));
         }
         AwsItems::Profile => {
-            let aws_profile = env::var("AWS_PROFILE").ok()?;
+            let aws_profile = get_aws_profile(context)?;
 
             module.create_segment("profile", &config.profile.with_value(&aws_profile));
         }
         AwsItems::Region => {
-            let aws_region = get_aws_region()?;
+            let aws_region = alias_region(get_aws_region(context)?, &config);
 
             module.create_segment("region", &config.region.with_value(&aws_region));
         }
@@ -113,3 +129,81 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     Some(module)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn profile_from_aws_vault() {
+        let actual = ModuleRenderer::new("aws")
+            .env("AWS_VAULT", "astronauts-vault")
+            .env("AWS_REGION", "ap-northeast-2")
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow
+                .bold()
+                .paint("☁️  astronauts-vault(ap-northeast-2)")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn aws_profile_takes_precedence_over_aws_vault() {
+        let actual = ModuleRenderer::new("aws")
+            .env("AWS_PROFILE", "astronauts")
+            .env("AWS_VAULT", "astronauts-vault")
+            .config(toml::toml! {
+                [aws]
+                displayed_items = "profile"
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow.bold().paint("☁️  astronauts")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn region_aliased() {
+        let actual = ModuleRenderer::new("aws")
+            .env("AWS_PROFILE", "astronauts")
+            .env("AWS_REGION", "ap-southeast-2")
+            .config(toml::toml! {
+                [aws.region_aliases]
+                ap-southeast-2 = "au"
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow.bold().paint("☁️  astronauts(au)")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn region_config_file_for_profile() {
+        let renderer = ModuleRenderer::new("aws").file(
+            "config",
+            "[default]\nregion = us-east-1\n\n[profile astronauts]\nregion = us-east-2\n",
+        );
+        let config_path = renderer.root_path().join("config");
+
+        let actual = renderer
+            .env("AWS_CONFIG_FILE", config_path.to_string_lossy())
+            .env("AWS_VAULT", "astronauts")
+            .config(toml::toml! {
+                [aws.region_aliases]
+                us-east-2 = "ohio"
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow.bold().paint("☁️  astronauts(ohio)")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
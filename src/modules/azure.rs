@@ -0,0 +1,142 @@
+use std::path::PathBuf;
+
+use serde_json as json;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::azure::AzureConfig;
+use crate::utils;
+
+/// The subscription selected with `az account set`
+#[derive(Debug, PartialEq)]
+struct Subscription {
+    name: String,
+    user: String,
+}
+
+/// Find the default subscription in the contents of `azureProfile.json`
+fn parse_subscription(contents: &str) -> Option<Subscription> {
+    // The Azure CLI writes the profile with a byte order mark, which serde_json rejects
+    let profile: json::Value = json::from_str(contents.trim_start_matches('\u{feff}')).ok()?;
+
+    let subscription = profile["subscriptions"]
+        .as_array()?
+        .iter()
+        .find(|subscription| subscription["isDefault"].as_bool() == Some(true))?;
+
+    Some(Subscription {
+        name: subscription["name"].as_str()?.to_string(),
+        user: subscription["user"]["name"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+/// The directory of the Azure CLI configuration, `~/.azure` unless overridden
+/// by `$AZURE_CONFIG_DIR`
+fn get_config_dir(context: &Context) -> Option<PathBuf> {
+    context
+        .get_env("AZURE_CONFIG_DIR")
+        .map(PathBuf::from)
+        .or_else(|| Some(dirs::home_dir()?.join(".azure")))
+}
+
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("azure");
+    let config: AzureConfig = AzureConfig::try_load(module.config);
+
+    if config.disabled {
+        log::debug!("Module \"azure\" is disabled by default, set `disabled = false` to enable it");
+        return None;
+    };
+
+    let profile = utils::read_file(get_config_dir(context)?.join("azureProfile.json")).ok()?;
+    let subscription = parse_subscription(&profile)?;
+
+    let value = config
+        .format
+        .replace("$name", &subscription.name)
+        .replace("$user", &subscription.user);
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("subscription", &SegmentConfig::new(&value));
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const PROFILE: &str = "\u{feff}{
+  \"installationId\": \"3deacd2a-b9db-77e1-aa42-23e2f8dfffc3\",
+  \"subscriptions\": [
+    {
+      \"id\": \"f568c543-d12e-de0b-3d85-69843598b565\",
+      \"name\": \"Development\",
+      \"state\": \"Enabled\",
+      \"user\": { \"name\": \"user@example.com\", \"type\": \"user\" },
+      \"isDefault\": false
+    },
+    {
+      \"id\": \"d4442d26-ea6d-46c4-07cb-4f70b8ae5465\",
+      \"name\": \"Production\",
+      \"state\": \"Enabled\",
+      \"user\": { \"name\": \"user@example.com\", \"type\": \"user\" },
+      \"isDefault\": true
+    }
+  ]
+}";
+
+    #[test]
+    fn parse_default_subscription() {
+        let expected = Some(Subscription {
+            name: "Production".to_string(),
+            user: "user@example.com".to_string(),
+        });
+        assert_eq!(expected, parse_subscription(PROFILE));
+    }
+
+    #[test]
+    fn parse_without_subscriptions() {
+        assert_eq!(None, parse_subscription("{\"subscriptions\": []}"));
+        assert_eq!(None, parse_subscription(""));
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let renderer = ModuleRenderer::new("azure").file("azureProfile.json", PROFILE);
+        let config_dir = renderer.root_path().to_path_buf();
+
+        let actual = renderer
+            .env("AZURE_CONFIG_DIR", config_dir.to_string_lossy())
+            .collect();
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn subscription_with_format() {
+        let renderer = ModuleRenderer::new("azure").file("azureProfile.json", PROFILE);
+        let config_dir = renderer.root_path().to_path_buf();
+
+        let actual = renderer
+            .env("AZURE_CONFIG_DIR", config_dir.to_string_lossy())
+            .config(toml::toml! {
+                [azure]
+                disabled = false
+                format = "$name ($user)"
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Blue.bold().paint("🔷 Production (user@example.com)")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
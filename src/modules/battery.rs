@@ -1,13 +1,14 @@
 use super::{Context, Module, RootModuleConfig};
-use crate::configs::battery::BatteryConfig;
+use crate::configs::battery::{BatteryConfig, BatteryDisplayConfig};
 
 /// Creates a module for the battery percentage and charging state
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     // TODO: Update when v1.0 printing refactor is implemented to only
     // print escapes in a prompt context.
-    let shell = std::env::var("STARSHIP_SHELL").unwrap_or_default();
+    let shell = context.get_env("STARSHIP_SHELL").unwrap_or_default();
     let percentage_char = match shell.as_str() {
-        "zsh" => "%%", // % is an escape in zsh, see PROMPT in `man zshmisc`
+        "zsh" => "%%",  // % is an escape in zsh, see PROMPT in `man zshmisc`
+        "tcsh" => "%%", // % is an escape in tcsh too, see prompt in `man tcsh`
         "powershell" => "`%",
         _ => "%",
     };
@@ -19,10 +20,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let battery_config: BatteryConfig = BatteryConfig::try_load(module.config);
 
     // Parse config under `display`
-    let display_styles = &battery_config.display;
-    let display_style = display_styles
-        .iter()
-        .find(|display_style| percentage <= display_style.threshold as f32);
+    let display_style = get_display_style(&battery_config.display, percentage);
 
     if let Some(display_style) = display_style {
         // Set style based on percentage
@@ -34,10 +32,18 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
                 module.create_segment("full_symbol", &battery_config.full_symbol);
             }
             battery::State::Charging => {
-                module.create_segment("charging_symbol", &battery_config.charging_symbol);
+                let charging_symbol = display_style
+                    .charging_symbol
+                    .as_ref()
+                    .unwrap_or(&battery_config.charging_symbol);
+                module.create_segment("charging_symbol", charging_symbol);
             }
             battery::State::Discharging => {
-                module.create_segment("discharging_symbol", &battery_config.discharging_symbol);
+                let discharging_symbol = display_style
+                    .discharging_symbol
+                    .as_ref()
+                    .unwrap_or(&battery_config.discharging_symbol);
+                module.create_segment("discharging_symbol", discharging_symbol);
             }
             battery::State::Unknown => {
                 log::debug!("Unknown detected");
@@ -73,7 +79,23 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 }
 
+/// Get the display config for a percentage, the one with the lowest threshold
+/// above it whatever the order of the thresholds in the config
+fn get_display_style<'a, 'b>(
+    display_styles: &'b [BatteryDisplayConfig<'a>],
+    percentage: f32,
+) -> Option<&'b BatteryDisplayConfig<'a>> {
+    display_styles
+        .iter()
+        .filter(|display_style| percentage <= display_style.threshold as f32)
+        .min_by_key(|display_style| display_style.threshold)
+}
+
 fn get_battery_status() -> Option<BatteryStatus> {
+    get_system_power_status().or_else(get_battery_manager_status)
+}
+
+fn get_battery_manager_status() -> Option<BatteryStatus> {
     let battery_manager = battery::Manager::new().ok()?;
     match battery_manager.batteries().ok()?.next() {
         Some(Ok(battery)) => {
@@ -96,7 +118,128 @@ fn get_battery_status() -> Option<BatteryStatus> {
     }
 }
 
+/// Get the status of the battery from `GetSystemPowerStatus`, which gives the
+/// same charge as the taskbar of Windows
+#[cfg(windows)]
+fn get_system_power_status() -> Option<BatteryStatus> {
+    use winapi::um::winbase::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    let mut power_status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+    if unsafe { GetSystemPowerStatus(&mut power_status) } == 0 {
+        log::debug!("Unable to get the system power status");
+        return None;
+    }
+    log::debug!(
+        "System power status: AC line {}, battery flag {}, battery life {}%",
+        power_status.ACLineStatus,
+        power_status.BatteryFlag,
+        power_status.BatteryLifePercent
+    );
+
+    from_power_status(
+        power_status.ACLineStatus,
+        power_status.BatteryFlag,
+        power_status.BatteryLifePercent,
+    )
+}
+
+#[cfg(not(windows))]
+fn get_system_power_status() -> Option<BatteryStatus> {
+    None
+}
+
+/// Convert the fields of a `SYSTEM_POWER_STATUS` to a battery status, see
+/// https://docs.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-system_power_status
+#[cfg(any(windows, test))]
+fn from_power_status(
+    ac_line_status: u8,
+    battery_flag: u8,
+    battery_life_percent: u8,
+) -> Option<BatteryStatus> {
+    const AC_ONLINE: u8 = 1;
+    const BATTERY_CHARGING: u8 = 8;
+    const NO_SYSTEM_BATTERY: u8 = 128;
+    const UNKNOWN_STATUS: u8 = 255;
+
+    if battery_flag == UNKNOWN_STATUS
+        || battery_flag & NO_SYSTEM_BATTERY != 0
+        || battery_life_percent == UNKNOWN_STATUS
+    {
+        return None;
+    }
+
+    let state = if battery_flag & BATTERY_CHARGING != 0 {
+        battery::State::Charging
+    } else if ac_line_status == AC_ONLINE && battery_life_percent == 100 {
+        battery::State::Full
+    } else if ac_line_status == AC_ONLINE || ac_line_status == UNKNOWN_STATUS {
+        battery::State::Unknown
+    } else {
+        battery::State::Discharging
+    };
+
+    Some(BatteryStatus {
+        percentage: f32::from(battery_life_percent),
+        state,
+    })
+}
+
 struct BatteryStatus {
     percentage: f32,
     state: battery::State,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ModuleConfig;
+
+    #[test]
+    fn test_get_display_style() {
+        let config = toml::toml! {
+            [[display]]
+            threshold = 30
+            style = "bold yellow"
+
+            [[display]]
+            threshold = 10
+            style = "bold red"
+            discharging_symbol = "💀"
+        };
+        let display_styles = Vec::<BatteryDisplayConfig>::from_config(&config["display"]).unwrap();
+
+        let display_style = get_display_style(&display_styles, 5.0).unwrap();
+        assert_eq!(display_style.threshold, 10);
+        assert_eq!(
+            display_style
+                .discharging_symbol
+                .as_ref()
+                .map(|symbol| symbol.value),
+            Some("💀")
+        );
+        assert!(display_style.charging_symbol.is_none());
+
+        let display_style = get_display_style(&display_styles, 20.0).unwrap();
+        assert_eq!(display_style.threshold, 30);
+        assert!(display_style.discharging_symbol.is_none());
+
+        assert!(get_display_style(&display_styles, 50.0).is_none());
+    }
+
+    #[test]
+    fn test_from_power_status() {
+        let status = from_power_status(0, 2, 25).unwrap();
+        assert_eq!(status.percentage, 25.0);
+        assert_eq!(status.state, battery::State::Discharging);
+
+        let status = from_power_status(1, 8, 60).unwrap();
+        assert_eq!(status.state, battery::State::Charging);
+
+        let status = from_power_status(1, 1, 100).unwrap();
+        assert_eq!(status.state, battery::State::Full);
+
+        // Desktops without a battery
+        assert!(from_power_status(1, 128, 255).is_none());
+        assert!(from_power_status(255, 255, 255).is_none());
+    }
+}
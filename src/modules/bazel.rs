@@ -0,0 +1,107 @@
+use std::path::{Path, PathBuf};
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::bazel::BazelConfig;
+use crate::utils;
+
+/// Creates a module with the Bazel version of the current workspace
+///
+/// Will display the Bazel version pinned with a `.bazelversion` file if the
+/// current directory or one of its parents contains a `WORKSPACE`,
+/// `WORKSPACE.bazel` or `MODULE.bazel` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let workspace_root = find_workspace_root(&context.current_dir)?;
+
+    // The version is the one Bazelisk would run, which doesn't need to run Bazel
+    let bazel_version = match context.get_env("USE_BAZEL_VERSION") {
+        Some(version) => version,
+        None => utils::read_file(workspace_root.join(".bazelversion")).ok()?,
+    };
+    let formatted_version = format_bazel_version(&bazel_version)?;
+
+    let mut module = context.new_module("bazel");
+    let config = BazelConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+/// Bazel commands run in the workspace of the current directory or its parents
+fn find_workspace_root(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .find(|dir| {
+            ["WORKSPACE", "WORKSPACE.bazel", "MODULE.bazel"]
+                .iter()
+                .any(|file| dir.join(file).is_file())
+        })
+        .map(Path::to_path_buf)
+}
+
+/// Format a version of `.bazelversion`, which can also be a label like `latest`
+fn format_bazel_version(bazel_version: &str) -> Option<String> {
+    let version = bazel_version.lines().next()?.trim();
+    if version.is_empty() {
+        None
+    } else if version.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(format!("v{}", version))
+    } else {
+        Some(version.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_bazel_version() {
+        assert_eq!(format_bazel_version("6.0.0\n"), Some("v6.0.0".to_string()));
+        assert_eq!(format_bazel_version("latest\n"), Some("latest".to_string()));
+        assert_eq!(format_bazel_version("\n"), None);
+    }
+
+    #[test]
+    fn folder_without_workspace() {
+        let actual = ModuleRenderer::new("bazel")
+            .file(".bazelversion", "6.0.0\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn workspace_without_bazelversion() {
+        let actual = ModuleRenderer::new("bazel").file("WORKSPACE", "").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn workspace_with_bazelversion() {
+        let actual = ModuleRenderer::new("bazel")
+            .file("MODULE.bazel", "module(name = \"rocket\")\n")
+            .file(".bazelversion", "6.0.0\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("🍃 v6.0.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn workspace_with_bazelisk_variable() {
+        let actual = ModuleRenderer::new("bazel")
+            .file("WORKSPACE.bazel", "")
+            .file(".bazelversion", "6.0.0\n")
+            .env("USE_BAZEL_VERSION", "5.4.0")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("🍃 v5.4.0")));
+        assert_eq!(expected, actual);
+    }
+}
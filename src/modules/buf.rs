@@ -0,0 +1,89 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::buf::BufConfig;
+
+/// Creates a module with the current buf CLI version
+///
+/// Will display the buf version if any of the following criteria are met:
+///     - Current directory contains a `buf.yaml` file
+///     - Current directory contains a `buf.gen.yaml` file
+///     - Current directory contains a `buf.work.yaml` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_buf_project = context
+        .try_begin_scan()?
+        .set_files(&["buf.yaml", "buf.gen.yaml", "buf.work.yaml"])
+        .is_match();
+
+    if !is_buf_project {
+        return None;
+    }
+
+    let buf_version = context.exec_cmd("buf", &["--version"])?.stdout;
+    let formatted_version = format_buf_version(&buf_version)?;
+
+    let mut module = context.new_module("buf");
+    let config = BufConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_buf_version(buf_stdout: &str) -> Option<String> {
+    // buf --version output looks like this:
+    // 1.28.1
+    let version = buf_stdout.trim();
+    if !version.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_buf_version() {
+        assert_eq!(format_buf_version("1.28.1\n"), Some("v1.28.1".to_string()));
+        assert_eq!(format_buf_version(""), None);
+        assert_eq!(format_buf_version("unknown command"), None);
+    }
+
+    #[test]
+    fn folder_without_buf_config() {
+        let actual = ModuleRenderer::new("buf")
+            .file("service.proto", "")
+            .cmd("buf --version", "1.28.1\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_buf_yaml() {
+        let actual = ModuleRenderer::new("buf")
+            .file("buf.yaml", "version: v1\n")
+            .cmd("buf --version", "1.28.1\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🐃 v1.28.1")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_buf_work_yaml() {
+        let actual = ModuleRenderer::new("buf")
+            .file("buf.work.yaml", "version: v1\n")
+            .cmd("buf --version", "1.28.1\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🐃 v1.28.1")));
+        assert_eq!(expected, actual);
+    }
+}
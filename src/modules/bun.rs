@@ -0,0 +1,86 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::bun::BunConfig;
+
+/// Creates a module with the current Bun version
+///
+/// Will display the Bun version if any of the following criteria are met:
+///     - Current directory contains a `bun.lockb` or `bun.lock` file
+///     - Current directory contains a `bunfig.toml` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_bun_project = context
+        .try_begin_scan()?
+        .set_files(&["bun.lockb", "bun.lock", "bunfig.toml"])
+        .is_match();
+
+    if !is_bun_project {
+        return None;
+    }
+
+    let bun_version = context.exec_cmd("bun", &["--version"])?.stdout;
+    let formatted_version = format_bun_version(&bun_version)?;
+
+    let mut module = context.new_module("bun");
+    let config = BunConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_bun_version(bun_stdout: &str) -> Option<String> {
+    // bun --version output looks like this:
+    // 1.0.0
+    let version = bun_stdout.trim();
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_bun_version() {
+        assert_eq!(format_bun_version("1.0.0\n"), Some("v1.0.0".to_string()));
+        assert_eq!(format_bun_version(""), None);
+    }
+
+    #[test]
+    fn folder_without_bun_files() {
+        let actual = ModuleRenderer::new("bun")
+            .file("package.json", "{}")
+            .cmd("bun --version", "1.0.0\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_bun_lockfile() {
+        let actual = ModuleRenderer::new("bun")
+            .file("bun.lockb", "")
+            .cmd("bun --version", "1.0.0\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("🍞 v1.0.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_bunfig_and_no_bun() {
+        let actual = ModuleRenderer::new("bun")
+            .file("bunfig.toml", "")
+            .cmd_output("bun --version", None)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+}
@@ -25,10 +25,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let props = &context.properties;
     let exit_code_default = std::string::String::from("0");
     let exit_code = props.get("status_code").unwrap_or(&exit_code_default);
-    let shell = std::env::var("STARSHIP_SHELL").unwrap_or_default();
+    let shell = context.get_env("STARSHIP_SHELL").unwrap_or_default();
     let keymap_default = std::string::String::from("viins");
     let keymap = props.get("keymap").unwrap_or(&keymap_default);
     let exit_success = exit_code == "0";
+    // Screen readers can't tell the success and failure styles apart
+    let screen_reader = context.config.get_root_config().screen_reader;
 
     // Match shell "keymap" names to normalized vi modes
     // NOTE: in vi mode, fish reports normal mode as "default".
@@ -48,7 +50,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     /* If an error symbol is set in the config, use symbols to indicate
     success/failure, in addition to color */
-    if config.use_symbol_for_status && !exit_success {
+    if (config.use_symbol_for_status || screen_reader) && !exit_success {
         module.create_segment("error_symbol", &config.error_symbol)
     } else {
         match mode {
@@ -0,0 +1,82 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::cmake::CMakeConfig;
+
+/// Creates a module with the current CMake version
+///
+/// Will display the CMake version if the current directory contains a
+/// `CMakeLists.txt` or `CMakeCache.txt` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_cmake_project = context
+        .try_begin_scan()?
+        .set_files(&["CMakeLists.txt", "CMakeCache.txt"])
+        .is_match();
+
+    if !is_cmake_project {
+        return None;
+    }
+
+    let cmake_version = context.exec_cmd("cmake", &["--version"])?.stdout;
+    let formatted_version = format_cmake_version(&cmake_version)?;
+
+    let mut module = context.new_module("cmake");
+    let config = CMakeConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_cmake_version(cmake_stdout: &str) -> Option<String> {
+    // cmake --version output looks like this:
+    // cmake version 3.17.3
+    //
+    // CMake suite maintained and supported by Kitware (kitware.com/cmake).
+    let version = cmake_stdout
+        .lines()
+        .next()?
+        .strip_prefix("cmake version ")?
+        .trim();
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const CMAKE_VERSION: &str = "cmake version 3.17.3\n\nCMake suite maintained and supported by Kitware (kitware.com/cmake).\n";
+
+    #[test]
+    fn test_format_cmake_version() {
+        assert_eq!(
+            format_cmake_version(CMAKE_VERSION),
+            Some("v3.17.3".to_string())
+        );
+        assert_eq!(format_cmake_version("not cmake"), None);
+    }
+
+    #[test]
+    fn folder_without_cmake_files() {
+        let actual = ModuleRenderer::new("cmake")
+            .cmd("cmake --version", CMAKE_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_cmake_lists() {
+        let actual = ModuleRenderer::new("cmake")
+            .file("CMakeLists.txt", "cmake_minimum_required(VERSION 3.10)\n")
+            .cmd("cmake --version", CMAKE_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("△ v3.17.3")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -36,7 +36,10 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     };
 
     module.set_style(module_color);
-    let cmd_duration_stacked = &format!("{}{}", config.prefix, render_time(elapsed));
+    let locale = context.locale();
+    let prefix = config.prefix.unwrap_or(locale.took);
+    let cmd_duration_stacked =
+        &format!("{}{}", prefix, render_time(elapsed, &locale.duration_units));
     module.create_segment("cmd_duration", &SegmentConfig::new(&cmd_duration_stacked));
     module.get_prefix().set_value("");
 
@@ -44,18 +47,16 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 }
 
 // Render the time into a nice human-readable string
-fn render_time(raw_seconds: u64) -> String {
+pub fn render_time(raw_seconds: u64, suffixes: &[&str; 4]) -> String {
     // Calculate a simple breakdown into days/hours/minutes/seconds
     let (seconds, raw_minutes) = (raw_seconds % 60, raw_seconds / 60);
     let (minutes, raw_hours) = (raw_minutes % 60, raw_minutes / 60);
     let (hours, days) = (raw_hours % 24, raw_hours / 24);
 
     let components = [days, hours, minutes, seconds];
-    let suffixes = ["d", "h", "m", "s"];
-
     let rendered_components: Vec<String> = components
         .iter()
-        .zip(&suffixes)
+        .zip(suffixes)
         .map(render_time_component)
         .collect();
     rendered_components.join("")
@@ -72,21 +73,33 @@ fn render_time_component((component, suffix): (&u64, &&str)) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::locale;
 
     #[test]
     fn test_10s() {
-        assert_eq!(render_time(10 as u64), "10s")
+        assert_eq!(render_time(10_u64, &locale::EN.duration_units), "10s")
     }
+
     #[test]
     fn test_90s() {
-        assert_eq!(render_time(90 as u64), "1m30s")
+        assert_eq!(render_time(90_u64, &locale::EN.duration_units), "1m30s")
     }
+
     #[test]
     fn test_10110s() {
-        assert_eq!(render_time(10110 as u64), "2h48m30s")
+        assert_eq!(
+            render_time(10110_u64, &locale::EN.duration_units),
+            "2h48m30s"
+        )
     }
+
     #[test]
     fn test_1d() {
-        assert_eq!(render_time(86400 as u64), "1d")
+        assert_eq!(render_time(86400_u64, &locale::EN.duration_units), "1d")
+    }
+
+    #[test]
+    fn test_localized_units() {
+        assert_eq!(render_time(90000, &locale::FR.duration_units), "1j1h")
     }
 }
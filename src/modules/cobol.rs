@@ -0,0 +1,99 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::cobol::CobolConfig;
+
+/// Creates a module with the current GnuCOBOL version
+///
+/// Will display the COBOL version if any of the following criteria are met:
+///     - Current directory contains a file with the `.cbl` or `.cob` extension
+///       (in lower or upper case, as is usual for mainframe sources)
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_cobol_project = context
+        .try_begin_scan()?
+        .set_extensions(&["cbl", "cob", "CBL", "COB"])
+        .is_match();
+
+    if !is_cobol_project {
+        return None;
+    }
+
+    let cobc_version = context.exec_cmd("cobc", &["-version"])?.stdout;
+    let formatted_version = format_cobc_version(&cobc_version)?;
+
+    let mut module = context.new_module("cobol");
+    let config = CobolConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_cobc_version(cobc_stdout: &str) -> Option<String> {
+    // cobc -version output looks like this:
+    // cobc (GnuCOBOL) 3.1.2.0
+    // Built     Dec 24 2020 19:08:58
+    // ...
+    let version = cobc_stdout
+        .lines()
+        .next()?
+        .strip_prefix("cobc (GnuCOBOL) ")?
+        .trim();
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const COBC_VERSION: &str = "\
+cobc (GnuCOBOL) 3.1.2.0
+Built     Dec 24 2020 19:08:58
+Packaged  Dec 23 2020 12:04:58 UTC
+";
+
+    #[test]
+    fn test_format_cobc_version() {
+        assert_eq!(
+            format_cobc_version(COBC_VERSION),
+            Some("v3.1.2.0".to_string())
+        );
+        assert_eq!(format_cobc_version("cobc: unknown option"), None);
+        assert_eq!(format_cobc_version(""), None);
+    }
+
+    #[test]
+    fn folder_without_cobol_files() {
+        let actual = ModuleRenderer::new("cobol")
+            .cmd("cobc -version", COBC_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_cbl_file() {
+        let actual = ModuleRenderer::new("cobol")
+            .file("payroll.cbl", "")
+            .cmd("cobc -version", COBC_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("⚙️ v3.1.2.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_upper_case_cob_file() {
+        let actual = ModuleRenderer::new("cobol")
+            .file("PAYROLL.COB", "")
+            .cmd("cobc -version", COBC_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("⚙️ v3.1.2.0")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,5 +1,3 @@
-use std::env;
-
 use super::{Context, Module};
 
 use crate::config::RootModuleConfig;
@@ -7,10 +5,11 @@ use crate::configs::conda::CondaConfig;
 
 /// Creates a module with the current Conda environment
 ///
-/// Will display the Conda environment iff `$CONDA_DEFAULT_ENV` is set.
+/// Will display the Conda environment iff `$CONDA_DEFAULT_ENV` is set, and it
+/// isn't the `base` environment when `ignore_base` is true.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     // Reference implementation: https://github.com/denysdovhan/spaceship-prompt/blob/master/sections/conda.zsh
-    let conda_env = env::var("CONDA_DEFAULT_ENV").unwrap_or_else(|_| "".into());
+    let conda_env = context.get_env("CONDA_DEFAULT_ENV").unwrap_or_default();
     if conda_env.trim().is_empty() {
         return None;
     }
@@ -18,6 +17,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("conda");
     let config = CondaConfig::try_load(module.config);
 
+    if config.ignore_base && conda_env == "base" {
+        return None;
+    }
+
+    // Environments activated by path are shown by their last directories
+    let conda_env = truncate_env(&conda_env, config.truncation_length);
+
     module.set_style(config.style);
 
     module.create_segment("symbol", &config.symbol);
@@ -25,3 +31,94 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     Some(module)
 }
+
+/// Keep the last `length` path components of an environment, or all of them
+/// if `length` is 0
+fn truncate_env(env: &str, length: usize) -> String {
+    let components = env
+        .split(&['/', '\\'][..])
+        .filter(|component| !component.is_empty())
+        .collect::<Vec<_>>();
+    if length == 0 || components.len() <= length {
+        return env.to_string();
+    }
+
+    components[components.len() - length..].join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::truncate_env;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn not_in_env() {
+        let actual = ModuleRenderer::new("conda").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn env_set() {
+        let actual = ModuleRenderer::new("conda")
+            .env("CONDA_DEFAULT_ENV", "astronauts")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green.bold().paint("C astronauts")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn ignore_base() {
+        let actual = ModuleRenderer::new("conda")
+            .env("CONDA_DEFAULT_ENV", "base")
+            .collect();
+        assert_eq!(None, actual);
+
+        let actual = ModuleRenderer::new("conda")
+            .env("CONDA_DEFAULT_ENV", "base")
+            .config(toml::toml! {
+                [conda]
+                ignore_base = false
+            })
+            .collect();
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("C base")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn truncated_env_path() {
+        let actual = ModuleRenderer::new("conda")
+            .env(
+                "CONDA_DEFAULT_ENV",
+                "/home/astronaut/rockets/envs/astronauts",
+            )
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green.bold().paint("C astronauts")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_truncate_env() {
+        assert_eq!(truncate_env("astronauts", 1), "astronauts");
+        assert_eq!(
+            truncate_env("/opt/rockets/envs/astronauts", 2),
+            "envs/astronauts"
+        );
+        assert_eq!(
+            truncate_env("/opt/rockets/envs/astronauts", 0),
+            "/opt/rockets/envs/astronauts"
+        );
+        assert_eq!(
+            truncate_env("C:\\Users\\astronaut\\envs\\rocket", 1),
+            "rocket"
+        );
+    }
+}
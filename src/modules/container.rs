@@ -0,0 +1,133 @@
+use std::path::Path;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::container::ContainerConfig;
+use crate::utils;
+
+/// Creates a module showing if the shell runs inside a container
+///
+/// Will display the container if any of the following criteria are met:
+///     - `/run/.containerenv` exists, created by Podman, which names the container
+///     - `/.dockerenv` exists, created by Docker
+///     - The `$container` environment variable is set, as done by LXC and systemd-nspawn
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let name = get_container_name(context, Path::new("/"))?;
+
+    let mut module = context.new_module("container");
+    let config = ContainerConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("name", &config.name.with_value(&format!("[{}]", name)));
+
+    Some(module)
+}
+
+/// Get the name of the container the shell runs in, looking for the files
+/// created by container engines under `root`
+fn get_container_name(context: &Context, root: &Path) -> Option<String> {
+    let containerenv = root.join("run").join(".containerenv");
+    if containerenv.exists() {
+        let name = utils::read_file(&containerenv)
+            .ok()
+            .and_then(|contents| parse_containerenv(&contents));
+        return Some(name.unwrap_or_else(|| "podman".to_string()));
+    }
+
+    if root.join(".dockerenv").exists() {
+        return Some("Docker".to_string());
+    }
+
+    context
+        .get_env("container")
+        .filter(|container| !container.is_empty())
+}
+
+/// Get the container name, or else its image, from the contents of
+/// `/run/.containerenv`, e.g. `name="toolbox"` and `image="fedora-toolbox:31"`
+fn parse_containerenv(contents: &str) -> Option<String> {
+    let value = |key: &str| {
+        contents
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .find(|(name, _)| name.trim() == key)
+            .map(|(_, value)| value.trim().trim_matches('"').to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    value("name").or_else(|| value("image"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StarshipConfig;
+    use crate::context::Env;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::io;
+
+    fn container_name(root: &Path, env: Option<&str>) -> Option<String> {
+        let config = StarshipConfig { config: None };
+        let mut context = Context::new_with_config(config, HashMap::new(), root);
+        context.env = Env::isolated();
+        if let Some(env) = env {
+            context.env.insert("container", env);
+        }
+        get_container_name(&context, root)
+    }
+
+    #[test]
+    fn test_parse_containerenv() {
+        let contents = "engine=\"podman-1.6.4\"\nname=\"toolbox\"\nimage=\"fedora-toolbox:31\"\n";
+        assert_eq!(parse_containerenv(contents), Some("toolbox".to_string()));
+        assert_eq!(
+            parse_containerenv("image=\"fedora-toolbox:31\"\n"),
+            Some("fedora-toolbox:31".to_string())
+        );
+        assert_eq!(parse_containerenv(""), None);
+    }
+
+    #[test]
+    fn not_in_container() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        assert_eq!(container_name(root.path(), None), None);
+        root.close()
+    }
+
+    #[test]
+    fn in_podman_container() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        fs::create_dir(root.path().join("run"))?;
+        fs::write(root.path().join("run/.containerenv"), "name=\"toolbox\"\n")?;
+        assert_eq!(
+            container_name(root.path(), None),
+            Some("toolbox".to_string())
+        );
+        root.close()
+    }
+
+    #[test]
+    fn in_docker_container() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        fs::write(root.path().join(".dockerenv"), "")?;
+        assert_eq!(
+            container_name(root.path(), None),
+            Some("Docker".to_string())
+        );
+        root.close()
+    }
+
+    #[test]
+    fn in_lxc_container() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        assert_eq!(
+            container_name(root.path(), Some("lxc")),
+            Some("lxc".to_string())
+        );
+        root.close()
+    }
+}
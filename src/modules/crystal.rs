@@ -0,0 +1,96 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::crystal::CrystalConfig;
+
+/// Creates a module with the current Crystal version
+///
+/// Will display the Crystal version if any of the following criteria are met:
+///     - Current directory contains a `shard.yml` file
+///     - Current directory contains a file with the `.cr` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_crystal_project = context
+        .try_begin_scan()?
+        .set_files(&["shard.yml"])
+        .set_extensions(&["cr"])
+        .is_match();
+
+    if !is_crystal_project {
+        return None;
+    }
+
+    let crystal_version = context.exec_cmd("crystal", &["--version"])?.stdout;
+    let formatted_version = format_crystal_version(&crystal_version)?;
+
+    let mut module = context.new_module("crystal");
+    let config = CrystalConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_crystal_version(crystal_stdout: &str) -> Option<String> {
+    // crystal --version output looks like this:
+    // Crystal 1.0.0 [dd40a2442] (2021-03-22)
+    //
+    // LLVM: 10.0.0
+    // Default target: x86_64-unknown-linux-gnu
+    let version = crystal_stdout
+        .trim()
+        .strip_prefix("Crystal ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const CRYSTAL_VERSION: &str = "Crystal 1.0.0 [dd40a2442] (2021-03-22)\n\nLLVM: 10.0.0\nDefault target: x86_64-unknown-linux-gnu\n";
+
+    #[test]
+    fn test_format_crystal_version() {
+        assert_eq!(
+            format_crystal_version(CRYSTAL_VERSION),
+            Some("v1.0.0".to_string())
+        );
+        assert_eq!(format_crystal_version("not crystal"), None);
+    }
+
+    #[test]
+    fn folder_without_crystal_files() {
+        let actual = ModuleRenderer::new("crystal")
+            .cmd("crystal --version", CRYSTAL_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_shard_yml() {
+        let actual = ModuleRenderer::new("crystal")
+            .file("shard.yml", "name: rocket\n")
+            .cmd("crystal --version", CRYSTAL_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("🔮 v1.0.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_cr_file() {
+        let actual = ModuleRenderer::new("crystal")
+            .file("main.cr", "")
+            .cmd("crystal --version", CRYSTAL_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("🔮 v1.0.0")));
+        assert_eq!(expected, actual);
+    }
+}
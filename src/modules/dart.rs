@@ -0,0 +1,190 @@
+use yaml_rust::YamlLoader;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::dart::DartConfig;
+use crate::utils;
+
+/// Creates a module with the current Dart version
+///
+/// Will display the Dart version if any of the following criteria are met:
+///     - Current directory contains a `pubspec.yaml`, `pubspec.yml` or `pubspec.lock` file
+///     - Current directory contains a file with the `.dart` extension
+///     - Current directory contains a `.dart_tool` directory
+///
+/// With `detect_flutter`, the Flutter version is displayed instead for Flutter projects.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_dart_project = context
+        .try_begin_scan()?
+        .set_files(&["pubspec.yaml", "pubspec.yml", "pubspec.lock"])
+        .set_extensions(&["dart"])
+        .set_folders(&[".dart_tool"])
+        .is_match();
+
+    if !is_dart_project {
+        return None;
+    }
+
+    let mut module = context.new_module("dart");
+    let config = DartConfig::try_load(module.config);
+
+    // `flutter --version` is slow, it is only run when asked for
+    let (symbol, formatted_version) = if config.detect_flutter && is_flutter_project(context) {
+        let flutter_version = context.exec_cmd("flutter", &["--version"])?.stdout;
+        (
+            &config.flutter_symbol,
+            format_flutter_version(&flutter_version)?,
+        )
+    } else {
+        let output = context.exec_cmd("dart", &["--version"])?;
+        // Dart printed its version to stderr before 2.15
+        let dart_version = if output.stdout.trim().is_empty() {
+            output.stderr
+        } else {
+            output.stdout
+        };
+        (&config.symbol, format_dart_version(&dart_version)?)
+    };
+
+    module.set_style(config.style);
+    module.create_segment("symbol", symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+/// Flutter projects depend on the Flutter SDK in their `pubspec.yaml` file
+fn is_flutter_project(context: &Context) -> bool {
+    let pubspec = ["pubspec.yaml", "pubspec.yml"]
+        .iter()
+        .find_map(|name| utils::read_file(context.current_dir.join(name)).ok());
+    let pubspec = match pubspec.and_then(|pubspec| YamlLoader::load_from_str(&pubspec).ok()) {
+        Some(pubspec) => pubspec,
+        None => return false,
+    };
+
+    pubspec
+        .first()
+        .map(|pubspec| !pubspec["dependencies"]["flutter"].is_badvalue())
+        .unwrap_or(false)
+}
+
+fn format_dart_version(dart_output: &str) -> Option<String> {
+    // dart --version output looks like this:
+    // Dart SDK version: 2.12.2 (stable) (Wed Mar 17 10:30:20 2021 +0100) on "linux_x64"
+    let version = dart_output
+        .trim()
+        .strip_prefix("Dart SDK version: ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+fn format_flutter_version(flutter_stdout: &str) -> Option<String> {
+    // flutter --version output looks like this:
+    // Flutter 2.0.3 • channel stable • https://github.com/flutter/flutter.git
+    // Framework • revision 4d7946a68d (3 weeks ago) • 2021-03-18 17:24:33 -0700
+    // Engine • revision 3459eb2436
+    // Tools • Dart 2.12.2
+    let version = flutter_stdout
+        .trim()
+        .strip_prefix("Flutter ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use crate::utils::CommandOutput;
+    use ansi_term::Color;
+
+    const DART_VERSION: &str =
+        "Dart SDK version: 2.12.2 (stable) (Wed Mar 17 10:30:20 2021 +0100) on \"linux_x64\"\n";
+    const FLUTTER_VERSION: &str = "Flutter 2.0.3 • channel stable • https://github.com/flutter/flutter.git\nFramework • revision 4d7946a68d (3 weeks ago) • 2021-03-18 17:24:33 -0700\nEngine • revision 3459eb2436\nTools • Dart 2.12.2\n";
+    const FLUTTER_PUBSPEC: &str = "name: rocket\ndependencies:\n  flutter:\n    sdk: flutter\n";
+
+    #[test]
+    fn test_format_dart_version() {
+        assert_eq!(
+            format_dart_version(DART_VERSION),
+            Some("v2.12.2".to_string())
+        );
+        assert_eq!(format_dart_version("not dart"), None);
+    }
+
+    #[test]
+    fn test_format_flutter_version() {
+        assert_eq!(
+            format_flutter_version(FLUTTER_VERSION),
+            Some("v2.0.3".to_string())
+        );
+        assert_eq!(format_flutter_version("not flutter"), None);
+    }
+
+    #[test]
+    fn folder_without_dart_files() {
+        let actual = ModuleRenderer::new("dart")
+            .cmd("dart --version", DART_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_pubspec() {
+        let actual = ModuleRenderer::new("dart")
+            .file("pubspec.yaml", "name: rocket\n")
+            .cmd("dart --version", DART_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🎯 v2.12.2")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn dart_version_on_stderr() {
+        let actual = ModuleRenderer::new("dart")
+            .file("main.dart", "")
+            .cmd_output(
+                "dart --version",
+                Some(CommandOutput {
+                    stdout: String::new(),
+                    stderr: DART_VERSION.to_string(),
+                }),
+            )
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🎯 v2.12.2")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn flutter_project() {
+        let actual = ModuleRenderer::new("dart")
+            .file("pubspec.yaml", FLUTTER_PUBSPEC)
+            .cmd("dart --version", DART_VERSION)
+            .cmd("flutter --version", FLUTTER_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🎯 v2.12.2")));
+        assert_eq!(expected, actual);
+
+        let actual = ModuleRenderer::new("dart")
+            .file("pubspec.yaml", FLUTTER_PUBSPEC)
+            .cmd("dart --version", DART_VERSION)
+            .cmd("flutter --version", FLUTTER_VERSION)
+            .config(toml::toml! {
+                [dart]
+                detect_flutter = true
+            })
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("💙 v2.0.3")));
+        assert_eq!(expected, actual);
+    }
+}
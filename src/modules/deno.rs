@@ -0,0 +1,97 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::deno::DenoConfig;
+
+/// Creates a module with the current Deno version
+///
+/// Will display the Deno version if any of the following criteria are met:
+///     - Current directory contains a `deno.json` or `deno.jsonc` file
+///     - Current directory contains a `mod.ts` or `deps.ts` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_deno_project = context
+        .try_begin_scan()?
+        .set_files(&["deno.json", "deno.jsonc", "mod.ts", "deps.ts"])
+        .is_match();
+
+    if !is_deno_project {
+        return None;
+    }
+
+    let deno_version = context.exec_cmd("deno", &["--version"])?.stdout;
+    let formatted_version = format_deno_version(&deno_version)?;
+
+    let mut module = context.new_module("deno");
+    let config = DenoConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_deno_version(deno_stdout: &str) -> Option<String> {
+    // deno --version output looks like this:
+    // deno 1.8.3 (release, x86_64-unknown-linux-gnu)
+    // v8 9.0.257.3
+    // typescript 4.2.2
+    let version = deno_stdout
+        .lines()
+        .next()?
+        .strip_prefix("deno ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const DENO_VERSION: &str =
+        "deno 1.8.3 (release, x86_64-unknown-linux-gnu)\nv8 9.0.257.3\ntypescript 4.2.2\n";
+
+    #[test]
+    fn test_format_deno_version() {
+        assert_eq!(
+            format_deno_version(DENO_VERSION),
+            Some("v1.8.3".to_string())
+        );
+        assert_eq!(format_deno_version("not deno"), None);
+    }
+
+    #[test]
+    fn folder_without_deno_files() {
+        let actual = ModuleRenderer::new("deno")
+            .file("main.ts", "")
+            .cmd("deno --version", DENO_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_deno_json() {
+        let actual = ModuleRenderer::new("deno")
+            .file("deno.json", "{}")
+            .cmd("deno --version", DENO_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("🦕 v1.8.3")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_mod_ts() {
+        let actual = ModuleRenderer::new("deno")
+            .file("mod.ts", "")
+            .cmd("deno --version", DENO_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("🦕 v1.8.3")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,4 +1,5 @@
 use path_slash::PathExt;
+use std::collections::HashMap;
 use std::path::Path;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -6,6 +7,7 @@ use super::{Context, Module};
 
 use crate::config::{RootModuleConfig, SegmentConfig};
 use crate::configs::directory::DirectoryConfig;
+use crate::utils;
 
 /// Creates a module with the current directory
 ///
@@ -15,6 +17,10 @@ use crate::configs::directory::DirectoryConfig;
 /// inside the home directory will be contracted to `~`
 ///     - Paths containing a git repo will contract to begin at the repo root
 ///
+/// **Substitution**
+///     - Parts of the contracted path matching a key of `substitutions` are
+/// replaced with its value, before truncation
+///
 /// **Truncation**
 /// Paths will be limited in length to `3` path components by default.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
@@ -59,41 +65,93 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         // Contract the path to the home directory
         _ => contract_path(current_dir, &home_dir, HOME_SYMBOL),
     };
+    let dir_string = substitute_path(dir_string, &config.substitutions);
 
     // Truncate the dir string to the maximum number of path components
     let truncated_dir_string = truncate(dir_string, config.truncation_length as usize);
 
+    // Clicking the path opens the directory, in terminals supporting hyperlinks
+    let url = if context.hyperlinks() {
+        Some(utils::file_url(current_dir))
+    } else {
+        None
+    };
+
     if config.fish_style_pwd_dir_length > 0 {
         // If user is using fish style path, we need to add the segment first
-        let contracted_home_dir = contract_path(&current_dir, &home_dir, HOME_SYMBOL);
+        let contracted_home_dir = substitute_path(
+            contract_path(current_dir, &home_dir, HOME_SYMBOL),
+            &config.substitutions,
+        );
         let fish_style_dir = to_fish_style(
             config.fish_style_pwd_dir_length as usize,
             contracted_home_dir,
             &truncated_dir_string,
         );
 
-        module.create_segment(
+        let segment = module.create_segment(
             "path",
             &SegmentConfig {
                 value: &fish_style_dir,
                 style: None,
             },
         );
+        if let Some(url) = &url {
+            segment.set_link(url.as_str());
+        }
     }
 
-    module.create_segment(
+    let segment = module.create_segment(
         "path",
         &SegmentConfig {
             value: &truncated_dir_string,
             style: None,
         },
     );
+    if let Some(url) = &url {
+        segment.set_link(url.as_str());
+    }
+
+    if !config.read_only.value.is_empty() && is_read_only(current_dir) {
+        module.create_segment(
+            "read_only",
+            &config.read_only.with_style(Some(config.read_only_style)),
+        );
+    }
 
-    module.get_prefix().set_value("in ");
+    module.get_prefix().set_value(context.locale().in_dir);
 
     Some(module)
 }
 
+/// Check if a directory isn't writable by the current user
+#[cfg(unix)]
+fn is_read_only(dir: &Path) -> bool {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = match CString::new(dir.as_os_str().as_bytes()) {
+        Ok(path) => path,
+        Err(_) => return false,
+    };
+    // `access` checks the permissions of the real user, including ACLs and
+    // read-only file systems, unlike the mode bits of the directory
+    if unsafe { libc::access(path.as_ptr(), libc::W_OK) } == 0 {
+        return false;
+    }
+
+    let error = std::io::Error::last_os_error().raw_os_error();
+    error == Some(libc::EACCES) || error == Some(libc::EROFS)
+}
+
+/// Check if a directory isn't writable by the current user
+#[cfg(not(unix))]
+fn is_read_only(dir: &Path) -> bool {
+    std::fs::metadata(dir)
+        .map(|metadata| metadata.permissions().readonly())
+        .unwrap_or(false)
+}
+
 /// Contract the root component of a path
 ///
 /// Replaces the `top_level_path` in a given `full_path` with the provided
@@ -121,6 +179,40 @@ fn contract_path(full_path: &Path, top_level_path: &Path, top_level_replacement:
     )
 }
 
+/// Replace the parts of a path matching the keys of `substitutions` with their value
+///
+/// Keys only match whole path components, e.g. `~/work/client` doesn't match
+/// `~/work/clients`. Longer keys are substituted first.
+fn substitute_path(dir_string: String, substitutions: &HashMap<String, &str>) -> String {
+    let mut keys = substitutions
+        .keys()
+        .filter(|key| !key.is_empty())
+        .collect::<Vec<_>>();
+    keys.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+    keys.into_iter().fold(dir_string, |dir_string, key| {
+        let mut substituted = String::new();
+        let mut rest = dir_string.as_str();
+        while let Some(index) = rest.find(key.as_str()) {
+            let before = &rest[..index];
+            let after = &rest[index + key.len()..];
+            let starts_component =
+                key.starts_with('/') || before.is_empty() || before.ends_with('/');
+            let ends_component = key.ends_with('/') || after.is_empty() || after.starts_with('/');
+
+            substituted.push_str(before);
+            if starts_component && ends_component {
+                substituted.push_str(substitutions[key]);
+            } else {
+                substituted.push_str(key);
+            }
+            rest = after;
+        }
+        substituted.push_str(rest);
+        substituted
+    })
+}
+
 /// Replaces "C://" with "/c/" within a Windows path
 ///
 /// On non-Windows OS, does nothing
@@ -258,6 +350,64 @@ mod tests {
         assert_eq!(output, "/c");
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn read_only_directory() -> std::io::Result<()> {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir()?;
+        assert!(!is_read_only(dir.path()));
+        assert!(!is_read_only(&dir.path().join("missing")));
+
+        fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o555))?;
+        // root can write to any directory of a writable file system
+        let is_root = unsafe { libc::geteuid() } == 0;
+        assert_eq!(is_read_only(dir.path()), !is_root);
+
+        fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o755))?;
+        dir.close()
+    }
+
+    #[test]
+    fn substitute_prefix() {
+        let mut substitutions = HashMap::new();
+        substitutions.insert(String::from("~/work/very/long/client"), "client");
+
+        let output = substitute_path(
+            String::from("~/work/very/long/client/vendor/lib"),
+            &substitutions,
+        );
+        assert_eq!(output, "client/vendor/lib");
+
+        // Only whole path components are substituted
+        let output = substitute_path(String::from("~/work/very/long/clients"), &substitutions);
+        assert_eq!(output, "~/work/very/long/clients");
+    }
+
+    #[test]
+    fn substitute_component() {
+        let mut substitutions = HashMap::new();
+        substitutions.insert(String::from("node_modules"), "📦");
+        substitutions.insert(String::from("/usr/local"), "ul");
+
+        let output = substitute_path(
+            String::from("/usr/local/app/node_modules/left-pad/node_modules/x"),
+            &substitutions,
+        );
+        assert_eq!(output, "ul/app/📦/left-pad/📦/x");
+    }
+
+    #[test]
+    fn substitute_longest_first() {
+        let mut substitutions = HashMap::new();
+        substitutions.insert(String::from("~/src"), "src");
+        substitutions.insert(String::from("~/src/starship"), "🚀");
+
+        let output = substitute_path(String::from("~/src/starship/docs"), &substitutions);
+        assert_eq!(output, "🚀/docs");
+    }
+
     #[test]
     fn truncate_smaller_path_than_provided_length() {
         let path = "~/starship";
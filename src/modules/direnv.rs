@@ -0,0 +1,161 @@
+use std::path::Path;
+
+use serde_json as json;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::direnv::DirenvConfig;
+
+/// Creates a module with the state of the `.envrc` file of the current directory
+///
+/// Will display whether the `.envrc` file is loaded, or allowed, not allowed or
+/// denied when direnv didn't load it.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("direnv");
+    let config: DirenvConfig = DirenvConfig::try_load(module.config);
+
+    // Running direnv at every prompt isn't something to do without being asked
+    if config.disabled {
+        log::debug!(
+            "Module \"direnv\" is disabled by default, set `disabled = false` to enable it"
+        );
+        return None;
+    }
+
+    // direnv uses the `.envrc` file of the current directory or its parents
+    if !has_envrc(&context.current_dir) {
+        return None;
+    }
+
+    let output = context.exec_cmd("direnv", &["status", "--json"])?;
+    let state = get_rc_state(&output.stdout)?;
+    let msg = match state {
+        RcState::Loaded => config.loaded_msg,
+        RcState::Allowed => config.allowed_msg,
+        RcState::NotAllowed => config.not_allowed_msg,
+        RcState::Denied => config.denied_msg,
+    };
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("state", &SegmentConfig::new(msg));
+
+    Some(module)
+}
+
+fn has_envrc(dir: &Path) -> bool {
+    dir.ancestors().any(|dir| dir.join(".envrc").is_file())
+}
+
+#[derive(Debug, PartialEq)]
+enum RcState {
+    Loaded,
+    Allowed,
+    NotAllowed,
+    Denied,
+}
+
+/// Get the state of the `.envrc` file found by direnv, from the output of
+/// `direnv status --json`
+fn get_rc_state(status: &str) -> Option<RcState> {
+    let status: json::Value = json::from_str(status).ok()?;
+    let found_rc = &status["state"]["foundRC"];
+    let found_path = found_rc["path"].as_str()?;
+
+    // The loaded `.envrc` file can be the one of another directory, if the
+    // shell hook hasn't run since changing directory
+    if status["state"]["loadedRC"]["path"].as_str() == Some(found_path) {
+        return Some(RcState::Loaded);
+    }
+
+    match found_rc["allowed"].as_u64()? {
+        0 => Some(RcState::Allowed),
+        1 => Some(RcState::NotAllowed),
+        2 => Some(RcState::Denied),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    fn status(found_allowed: u8, loaded_path: &str) -> String {
+        format!(
+            r#"{{"config":{{}},"state":{{"foundRC":{{"allowed":{},"path":"/home/astronaut/rocket/.envrc"}},"loadedRC":{{"allowed":0,"path":"{}"}}}}}}"#,
+            found_allowed, loaded_path
+        )
+    }
+
+    #[test]
+    fn test_get_rc_state() {
+        let loaded = "/home/astronaut/rocket/.envrc";
+        let other = "/home/astronaut/.envrc";
+        assert_eq!(get_rc_state(&status(0, loaded)), Some(RcState::Loaded));
+        assert_eq!(get_rc_state(&status(0, other)), Some(RcState::Allowed));
+        assert_eq!(get_rc_state(&status(1, other)), Some(RcState::NotAllowed));
+        assert_eq!(get_rc_state(&status(2, other)), Some(RcState::Denied));
+
+        let not_loaded = r#"{"config":{},"state":{"foundRC":{"allowed":1,"path":"/rocket/.envrc"},"loadedRC":null}}"#;
+        assert_eq!(get_rc_state(not_loaded), Some(RcState::NotAllowed));
+
+        let not_found = r#"{"config":{},"state":{"foundRC":null,"loadedRC":null}}"#;
+        assert_eq!(get_rc_state(not_found), None);
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("direnv")
+            .file(".envrc", "")
+            .cmd("direnv status --json", &status(0, "/home/astronaut/.envrc"))
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn no_envrc() {
+        let actual = ModuleRenderer::new("direnv")
+            .cmd("direnv status --json", &status(0, "/home/astronaut/.envrc"))
+            .config(toml::toml! {
+                [direnv]
+                disabled = false
+            })
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn envrc_denied() {
+        let actual = ModuleRenderer::new("direnv")
+            .file(".envrc", "")
+            .cmd("direnv status --json", &status(2, "/home/astronaut/.envrc"))
+            .config(toml::toml! {
+                [direnv]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::Yellow.bold().paint("direnv denied")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn envrc_loaded() {
+        let actual = ModuleRenderer::new("direnv")
+            .file(".envrc", "")
+            .cmd(
+                "direnv status --json",
+                &status(0, "/home/astronaut/rocket/.envrc"),
+            )
+            .config(toml::toml! {
+                [direnv]
+                disabled = false
+                loaded_msg = "on"
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::Yellow.bold().paint("direnv on")));
+        assert_eq!(actual, expected);
+    }
+}
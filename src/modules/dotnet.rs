@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::str;
 
-use super::{Context, Module, RootModuleConfig};
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
 use crate::configs::dotnet::DotnetConfig;
 
 type JValue = serde_json::Value;
@@ -13,7 +13,8 @@ type JValue = serde_json::Value;
 const GLOBAL_JSON_FILE: &str = "global.json";
 const PROJECT_JSON_FILE: &str = "project.json";
 
-/// A module which shows the latest (or pinned) version of the dotnet SDK
+/// A module which shows the latest (or pinned) version of the dotnet SDK,
+/// and the target framework of the project in the current directory
 ///
 /// Will display if any of the following files are present in
 /// the current directory:
@@ -35,14 +36,32 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             .get_repo()
             .ok()
             .and_then(|r| r.root.as_ref().map(PathBuf::as_path));
-        estimate_dotnet_version(&dotnet_files, &context.current_dir, repo_root)?
+        estimate_dotnet_version(&dotnet_files, &context.current_dir, repo_root)
     } else {
-        get_version_from_cli()?
+        get_version_from_cli()
+    };
+
+    let target_framework = if config.show_target_framework {
+        get_target_framework(&dotnet_files)
+    } else {
+        None
     };
 
+    if version.is_none() && target_framework.is_none() {
+        return None;
+    }
+
     module.set_style(config.style);
     module.create_segment("symbol", &config.symbol);
-    module.create_segment("version", &config.version.with_value(&version.0));
+    if let Some(version) = version {
+        module.create_segment("version", &config.version.with_value(&version.0));
+    }
+    if let Some(target_framework) = target_framework {
+        module.create_segment(
+            "target_framework",
+            &SegmentConfig::new(&format!(" ({})", target_framework)),
+        );
+    }
 
     Some(module)
 }
@@ -81,38 +100,21 @@ fn estimate_dotnet_version<'a>(
 /// Looks for a `global.json` which may exist in one of the parent directories of the current path.
 /// If there is one present, and it contains valid version pinning information, then return that version.
 ///
-/// The following places are scanned:
-///     - The parent of the current directory
-///       (Unless there is a git repository, and the parent is above the root of that repository)
-///     - The root of the git repository
-///       (If there is one)
+/// Like the dotnet CLI, the nearest `global.json` is used. The parent directories are scanned
+/// up to the root of the git repository, if there is one, and otherwise up to the root of the
+/// file system.
 fn try_find_nearby_global_json(current_dir: &Path, repo_root: Option<&Path>) -> Option<Version> {
-    let current_dir_is_repo_root = repo_root.map(|r| r == current_dir).unwrap_or(false);
-    let parent_dir = if current_dir_is_repo_root {
-        // Don't scan the parent directory if it's above the root of a git repository
-        None
-    } else {
-        current_dir.parent()
-    };
-
-    // Check the parent directory, or otherwise the repository root, for a global.json
-    let mut check_dirs = parent_dir
-        .iter()
-        .chain(repo_root.iter())
-        .copied() // Copies the reference, not the Path itself
-        .collect::<Vec<&Path>>();
-
-    // The parent directory and repository root may be the same directory,
-    // so avoid checking it twice.
-    check_dirs.dedup();
-
-    check_dirs
-        .iter()
-        // repo_root may be the same as the current directory. We don't need to scan it again.
-        .filter(|&&d| d != current_dir)
-        .filter_map(|d| check_directory_for_global_json(d))
-        // This will lazily evaluate the first directory with a global.json
-        .next()
+    // Don't scan the directories above the root of a git repository
+    let scan_root = repo_root.filter(|root| current_dir.starts_with(root));
+
+    current_dir
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| match scan_root {
+            Some(root) => dir.starts_with(root),
+            None => true,
+        })
+        .find_map(check_directory_for_global_json)
 }
 
 fn check_directory_for_global_json(path: &Path) -> Option<Version> {
@@ -196,6 +198,40 @@ fn get_dotnet_file_type(path: &Path) -> Option<FileType> {
     None
 }
 
+/// Get the target framework of the project file in the current directory, which is
+/// e.g. `net8.0`, or e.g. `net6.0, net8.0` for a project targeting several frameworks
+fn get_target_framework(files: &[DotNetFile]) -> Option<String> {
+    files
+        .iter()
+        .filter(|f| f.file_type == FileType::ProjectFile)
+        .filter_map(|f| crate::utils::read_file(f.path).ok())
+        .find_map(|project| parse_target_framework(&project))
+}
+
+fn parse_target_framework(project: &str) -> Option<String> {
+    // SDK-style projects contain `<TargetFramework>net8.0</TargetFramework>`,
+    // or `<TargetFrameworks>net6.0;net8.0</TargetFrameworks>`
+    let element_text = |tag: &str| {
+        let start_tag = format!("<{}>", tag);
+        let start = project.find(&start_tag)? + start_tag.len();
+        let end = start + project[start..].find(&format!("</{}>", tag))?;
+        Some(&project[start..end])
+    };
+
+    let frameworks = element_text("TargetFramework")
+        .or_else(|| element_text("TargetFrameworks"))?
+        .split(';')
+        .map(str::trim)
+        .filter(|framework| !framework.is_empty())
+        .collect::<Vec<&str>>();
+
+    if frameworks.is_empty() {
+        return None;
+    }
+
+    Some(frameworks.join(", "))
+}
+
 fn map_str_to_lower(value: Option<&OsStr>) -> Option<String> {
     Some(value?.to_str()?.to_ascii_lowercase())
 }
@@ -311,3 +347,42 @@ fn should_ignore_empty_global_json() {
     let version = get_pinned_sdk_version(json_text);
     assert!(version.is_none());
 }
+
+#[test]
+fn should_parse_target_framework_from_project_file() {
+    let project = r#"
+        <Project Sdk="Microsoft.NET.Sdk">
+          <PropertyGroup>
+            <OutputType>Exe</OutputType>
+            <TargetFramework>net8.0</TargetFramework>
+          </PropertyGroup>
+        </Project>
+    "#;
+
+    assert_eq!(Some("net8.0".to_string()), parse_target_framework(project));
+}
+
+#[test]
+fn should_parse_several_target_frameworks_from_project_file() {
+    let project = r#"
+        <Project Sdk="Microsoft.NET.Sdk">
+          <PropertyGroup>
+            <TargetFrameworks>netstandard2.0;net8.0</TargetFrameworks>
+          </PropertyGroup>
+        </Project>
+    "#;
+
+    assert_eq!(
+        Some("netstandard2.0, net8.0".to_string()),
+        parse_target_framework(project)
+    );
+}
+
+#[test]
+fn should_ignore_project_file_without_target_framework() {
+    assert_eq!(None, parse_target_framework(""));
+    assert_eq!(
+        None,
+        parse_target_framework("<TargetFramework></TargetFramework>")
+    );
+}
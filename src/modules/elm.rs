@@ -0,0 +1,73 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::elm::ElmConfig;
+
+/// Creates a module with the current Elm version
+///
+/// Will display the Elm version if any of the following criteria are met:
+///     - Current directory contains an `elm.json` file
+///     - Current directory contains an `elm-package.json` file
+///     - Current directory contains an `elm-stuff` directory
+///     - Current directory contains a file with the `.elm` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_elm_project = context
+        .try_begin_scan()?
+        .set_files(&["elm.json", "elm-package.json"])
+        .set_extensions(&["elm"])
+        .set_folders(&["elm-stuff"])
+        .is_match();
+
+    if !is_elm_project {
+        return None;
+    }
+
+    // `elm --version` only prints the version number, e.g. "0.19.1"
+    let elm_version = context.exec_cmd("elm", &["--version"])?.stdout;
+    let formatted_version = format!("v{}", elm_version.trim());
+
+    let mut module = context.new_module("elm");
+    let config = ElmConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn folder_without_elm_files() {
+        let actual = ModuleRenderer::new("elm")
+            .cmd("elm --version", "0.19.1\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_elm_json() {
+        let actual = ModuleRenderer::new("elm")
+            .file("elm.json", "{}")
+            .cmd("elm --version", "0.19.1\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🌳 v0.19.1")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_elm_stuff() {
+        let actual = ModuleRenderer::new("elm")
+            .dir("elm-stuff")
+            .cmd("elm --version", "0.19.1\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🌳 v0.19.1")));
+        assert_eq!(expected, actual);
+    }
+}
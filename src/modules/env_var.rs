@@ -1,42 +1,79 @@
-use std::env;
-
 use super::{Context, Module, SegmentConfig};
 
 use crate::config::RootModuleConfig;
 use crate::configs::env_var::EnvVarConfig;
 
-/// Creates a module with the value of the chosen environment variable
+/// Creates a module with the value of the chosen environment variables
 ///
 /// Will display the environment variable's value if all of the following criteria are met:
 ///     - env_var.disabled is absent or false
 ///     - env_var.variable is defined
 ///     - a variable named as the value of env_var.variable is defined
+///
+/// Each table in `env_var` (e.g. `[env_var.STAGE]`) is another instance of the
+/// module, with its own options. Its `variable` defaults to the name of the table.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("env_var");
     let config: EnvVarConfig = EnvVarConfig::try_load(module.config);
 
-    let env_value = get_env_value(config.variable?, config.default)?;
+    let mut instances = Vec::new();
+    if let Some(variable) = config.variable {
+        instances.push((variable, config.clone()));
+    }
+    let tables = module
+        .config
+        .and_then(|config| config.as_table())
+        .into_iter()
+        .flatten()
+        // `symbol` can be a table too, as a segment with its own style
+        .filter(|(name, value)| value.is_table() && name.as_str() != "symbol");
+    for (name, value) in tables {
+        let instance = EnvVarConfig::load(value);
+        instances.push((instance.variable.unwrap_or(name), instance));
+    }
+
+    let mut shown = instances
+        .into_iter()
+        .filter(|(_, instance)| !instance.disabled)
+        .filter_map(|(variable, instance)| {
+            let env_value = get_env_value(context, variable, instance.default)?;
+            Some((instance, env_value))
+        })
+        .peekable();
+    shown.peek()?;
 
     module.set_style(config.style);
-    module.get_prefix().set_value("with ");
+    module.get_prefix().set_value(context.locale().with);
 
-    if let Some(symbol) = config.symbol {
-        module.create_segment("symbol", &symbol);
-    }
+    for (i, (instance, env_value)) in shown.enumerate() {
+        if i > 0 {
+            module.create_segment("separator", &SegmentConfig::new(" "));
+        }
+        let style = Some(instance.style);
 
-    // TODO: Use native prefix and suffix instead of stacking custom ones together with env_value.
-    let env_var_stacked = format!("{}{}{}", config.prefix, env_value, config.suffix);
-    module.create_segment("env_var", &SegmentConfig::new(&env_var_stacked));
+        if let Some(symbol) = instance.symbol {
+            module.create_segment("symbol", &symbol.with_style(symbol.style.or(style)));
+        }
+
+        // TODO: Use native prefix and suffix instead of stacking custom ones together with env_value.
+        let env_var_stacked = format!(
+            "{}{}{}",
+            instance.prefix,
+            instance.format.replace("$value", &env_value),
+            instance.suffix
+        );
+        module.create_segment(
+            "env_var",
+            &SegmentConfig::new(&env_var_stacked).with_style(style),
+        );
+    }
 
     Some(module)
 }
 
-fn get_env_value(name: &str, default: Option<&str>) -> Option<String> {
-    match env::var_os(name) {
-        Some(os_value) => match os_value.into_string() {
-            Ok(value) => Some(value),
-            Err(_error) => None,
-        },
+fn get_env_value(context: &Context, name: &str, default: Option<&str>) -> Option<String> {
+    match context.get_env(name) {
+        Some(value) => Some(value),
         None => default.map(|value| value.to_owned()),
     }
 }
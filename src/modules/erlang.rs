@@ -0,0 +1,154 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::erlang::ErlangConfig;
+use crate::utils;
+
+/// Creates a module with the current Erlang/OTP release
+///
+/// Will display the OTP release if any of the following criteria are met:
+///     - Current directory contains a `rebar.config` file
+///     - Current directory contains an `erlang.mk` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_erlang_project = context
+        .try_begin_scan()?
+        .set_files(&["rebar.config", "erlang.mk"])
+        .is_match();
+
+    if !is_erlang_project {
+        return None;
+    }
+
+    // Booting `erl` to print the release takes seconds, the release is read
+    // from the installation of the `erl` found in `$PATH` instead
+    let erlang_root = find_erlang_root(&context.get_env("PATH")?)?;
+    let otp_version = get_otp_version(&erlang_root)?;
+
+    let mut module = context.new_module("erlang");
+    let config = ErlangConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "version",
+        &config.version.with_value(&format!("v{}", otp_version)),
+    );
+
+    Some(module)
+}
+
+/// Find the root directory of the Erlang installation of the `erl` in `$PATH`
+///
+/// `erl` is usually a link, e.g. `/usr/bin/erl` to `/usr/lib/erlang/bin/erl`,
+/// to the `bin` directory of the installation.
+fn find_erlang_root(path: &str) -> Option<PathBuf> {
+    let erl = env::split_paths(path)
+        .flat_map(|dir| vec![dir.join("erl"), dir.join("erl.exe")])
+        .find(|erl| erl.is_file())?;
+    let erl = fs::canonicalize(erl).ok()?;
+    let root = erl.parent()?.parent()?;
+
+    // Homebrew installs Erlang as it would be in `lib/erlang` of a prefix
+    [root.to_path_buf(), root.join("lib").join("erlang")]
+        .iter()
+        .find(|root| root.join("releases").is_dir())
+        .cloned()
+}
+
+/// Get the full OTP version, e.g. `24.0.2`, from the `OTP_VERSION` file of the
+/// latest release of an installation
+fn get_otp_version(erlang_root: &Path) -> Option<String> {
+    let releases = fs::read_dir(erlang_root.join("releases")).ok()?;
+    let latest_release = releases
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let release = entry.file_name().to_str()?.parse::<u32>().ok()?;
+            Some((release, entry.path()))
+        })
+        .max_by_key(|(release, _)| *release)?;
+
+    let otp_version = utils::read_file(latest_release.1.join("OTP_VERSION")).ok()?;
+    let otp_version = otp_version.trim();
+    if otp_version.is_empty() {
+        return None;
+    }
+
+    Some(otp_version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+    use std::io;
+
+    /// Create an Erlang installation in a directory, as found in its `bin` directory
+    fn create_installation(root: &Path, otp_version: &str) -> io::Result<()> {
+        let release = otp_version.split('.').next().unwrap();
+        fs::create_dir_all(root.join("bin"))?;
+        fs::write(root.join("bin").join("erl"), "")?;
+        fs::create_dir_all(root.join("releases").join(release))?;
+        fs::write(
+            root.join("releases").join(release).join("OTP_VERSION"),
+            format!("{}\n", otp_version),
+        )
+    }
+
+    #[test]
+    fn test_get_otp_version() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        assert_eq!(get_otp_version(root.path()), None);
+
+        create_installation(root.path(), "23.3.4")?;
+        create_installation(root.path(), "24.0.2")?;
+        fs::create_dir_all(root.path().join("releases").join("RELEASES"))?;
+        assert_eq!(get_otp_version(root.path()), Some("24.0.2".to_string()));
+        root.close()
+    }
+
+    #[test]
+    fn test_find_erlang_root() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        let erlang_root = root.path().join("otp");
+        create_installation(&erlang_root, "24.0.2")?;
+
+        let path =
+            env::join_paths(vec![root.path().join("empty"), erlang_root.join("bin")]).unwrap();
+        assert_eq!(
+            find_erlang_root(&path.to_string_lossy()),
+            Some(fs::canonicalize(&erlang_root)?)
+        );
+        assert_eq!(find_erlang_root(&root.path().to_string_lossy()), None);
+        root.close()
+    }
+
+    #[test]
+    fn folder_without_erlang_files() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        create_installation(root.path(), "24.0.2")?;
+
+        let actual = ModuleRenderer::new("erlang")
+            .env("PATH", root.path().join("bin").to_string_lossy())
+            .collect();
+        assert_eq!(None, actual);
+        root.close()
+    }
+
+    #[test]
+    fn folder_with_rebar_config() -> io::Result<()> {
+        let root = tempfile::tempdir()?;
+        create_installation(root.path(), "24.0.2")?;
+
+        let actual = ModuleRenderer::new("erlang")
+            .file("rebar.config", "")
+            .env("PATH", root.path().join("bin").to_string_lossy())
+            .collect();
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("🅴 v24.0.2")));
+        assert_eq!(expected, actual);
+        root.close()
+    }
+}
@@ -0,0 +1,45 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::fill::FillConfig;
+
+/// Creates a module which fills the rest of the line, pushing the modules
+/// after it to the right edge of the terminal
+///
+/// The module is rendered with its symbol once; it is stretched to the
+/// width left on its line when the whole prompt is printed.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("fill");
+    let config: FillConfig = FillConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+    module.get_suffix().set_value("");
+
+    module.create_segment("symbol", &config.symbol);
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn default_symbol() {
+        let actual = ModuleRenderer::new("fill").collect();
+        assert_eq!(Some(String::from(" ")), actual);
+    }
+
+    #[test]
+    fn configured_symbol() {
+        let actual = ModuleRenderer::new("fill")
+            .config(toml::toml! {
+                [fill]
+                symbol = "·"
+                style = "bold green"
+            })
+            .collect();
+        assert_eq!(Some(format!("{}", Color::Green.bold().paint("·"))), actual);
+    }
+}
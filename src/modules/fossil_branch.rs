@@ -0,0 +1,120 @@
+use super::utils::truncate::truncate;
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::fossil_branch::FossilBranchConfig;
+
+/// Creates a module with the Fossil branch in the current directory
+///
+/// Will display the branch name if the current directory is in a Fossil
+/// checkout, found by its `.fslckout` (or `_FOSSIL_` on Windows) file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("fossil_branch");
+    let config = FossilBranchConfig::try_load(module.config);
+
+    // Looking for the checkout in every parent directory has a cost, so it
+    // is only done once the module has been enabled
+    if config.disabled {
+        log::debug!(
+            "Module \"fossil_branch\" is disabled by default, set `disabled = false` to enable it"
+        );
+        return None;
+    }
+
+    let is_checkout = context
+        .current_dir
+        .ancestors()
+        .any(|dir| dir.join(".fslckout").is_file() || dir.join("_FOSSIL_").is_file());
+    if !is_checkout {
+        return None;
+    }
+
+    // The checkout file is an SQLite database, which fossil reads for us
+    let output = context.exec_cmd("fossil", &["branch", "current"])?.stdout;
+    let branch_name = output.trim();
+    if branch_name.is_empty() {
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    module.create_segment("symbol", &config.symbol);
+
+    let len = if config.truncation_length <= 0 {
+        log::warn!(
+            "\"truncation_length\" should be a positive value, found {}",
+            config.truncation_length
+        );
+        usize::MAX
+    } else {
+        config.truncation_length as usize
+    };
+    let truncated_and_symbol = truncate(branch_name, len, config.truncation_symbol);
+
+    module.create_segment(
+        "name",
+        &config.branch_name.with_value(&truncated_and_symbol),
+    );
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("fossil_branch")
+            .file(".fslckout", "")
+            .cmd("fossil branch current", "trunk\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn not_in_checkout() {
+        let actual = ModuleRenderer::new("fossil_branch")
+            .cmd("fossil branch current", "trunk\n")
+            .config(toml::toml! {
+                [fossil_branch]
+                disabled = false
+            })
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn branch_in_checkout() {
+        let actual = ModuleRenderer::new("fossil_branch")
+            .file(".fslckout", "")
+            .cmd("fossil branch current", "trunk\n")
+            .config(toml::toml! {
+                [fossil_branch]
+                disabled = false
+            })
+            .collect();
+
+        let expected = Some(format!("on {} ", Color::Purple.bold().paint(" trunk")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn branch_in_windows_checkout_with_truncation() {
+        let actual = ModuleRenderer::new("fossil_branch")
+            .file("_FOSSIL_", "")
+            .cmd("fossil branch current", "release-2.10\n")
+            .config(toml::toml! {
+                [fossil_branch]
+                disabled = false
+                truncation_length = 7
+            })
+            .collect();
+
+        let expected = Some(format!("on {} ", Color::Purple.bold().paint(" release…")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,205 @@
+use std::path::PathBuf;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::gcloud::GcloudConfig;
+use crate::utils;
+
+/// The properties of the active gcloud configuration
+#[derive(Debug, Default, PartialEq)]
+struct GcloudProperties {
+    account: Option<String>,
+    project: Option<String>,
+    region: Option<String>,
+}
+
+/// Parse a gcloud configuration file, an INI file such as:
+///
+/// ```ini
+/// [core]
+/// account = user@example.com
+/// project = my-project
+///
+/// [compute]
+/// region = us-central1
+/// ```
+fn parse_properties(contents: &str) -> GcloudProperties {
+    let mut properties = GcloudProperties::default();
+    let mut section = "";
+
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') && line.ends_with(']') {
+            section = &line[1..line.len() - 1];
+            continue;
+        }
+
+        let (key, value) = match line.split_once('=') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+        if value.is_empty() {
+            continue;
+        }
+
+        match (section, key) {
+            ("core", "account") => properties.account = Some(value.to_string()),
+            ("core", "project") => properties.project = Some(value.to_string()),
+            ("compute", "region") => properties.region = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    properties
+}
+
+/// The directory of the gcloud configuration, `~/.config/gcloud` unless
+/// overridden by `$CLOUDSDK_CONFIG`
+fn get_config_dir(context: &Context) -> Option<PathBuf> {
+    context
+        .get_env("CLOUDSDK_CONFIG")
+        .map(PathBuf::from)
+        .or_else(|| Some(dirs::home_dir()?.join(".config").join("gcloud")))
+}
+
+/// Read the properties of the active configuration from the files written by
+/// gcloud, since running `gcloud config list` takes far too long for a prompt
+fn get_active_properties(context: &Context) -> Option<GcloudProperties> {
+    let config_dir = get_config_dir(context)?;
+
+    let config_name = context
+        .get_env("CLOUDSDK_ACTIVE_CONFIG_NAME")
+        .or_else(|| utils::read_file(config_dir.join("active_config")).ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "default".to_string());
+
+    let config_file = config_dir
+        .join("configurations")
+        .join(format!("config_{}", config_name));
+    let contents = utils::read_file(config_file).ok()?;
+
+    Some(parse_properties(&contents))
+}
+
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("gcloud");
+    let config: GcloudConfig = GcloudConfig::try_load(module.config);
+
+    let properties = get_active_properties(context)?;
+    if properties == GcloudProperties::default() {
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    module.create_segment("symbol", &config.symbol);
+    if let Some(account) = &properties.account {
+        module.create_segment("account", &config.account.with_value(account));
+    }
+    if let Some(project) = &properties.project {
+        let project = match properties.account {
+            Some(_) => format!("/{}", project),
+            None => project.to_string(),
+        };
+        module.create_segment("project", &config.project.with_value(&project));
+    }
+    if let Some(region) = &properties.region {
+        let region = config.region_aliases.get(region).copied().unwrap_or(region);
+        module.create_segment(
+            "region",
+            &config.region.with_value(&format!("({})", region)),
+        );
+    }
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const CONFIG: &str = "\
+[core]
+account = user@example.com
+project = my-project
+
+[compute]
+region = us-central1
+zone = us-central1-a
+";
+
+    #[test]
+    fn parse_all_properties() {
+        let expected = GcloudProperties {
+            account: Some("user@example.com".to_string()),
+            project: Some("my-project".to_string()),
+            region: Some("us-central1".to_string()),
+        };
+        assert_eq!(expected, parse_properties(CONFIG));
+    }
+
+    #[test]
+    fn parse_ignores_other_sections() {
+        let contents = "[auth]\naccount = other\n\n[core]\nproject = my-project\n";
+        let expected = GcloudProperties {
+            project: Some("my-project".to_string()),
+            ..GcloudProperties::default()
+        };
+        assert_eq!(expected, parse_properties(contents));
+    }
+
+    #[test]
+    fn no_config() {
+        let renderer = ModuleRenderer::new("gcloud");
+        let config_dir = renderer.root_path().join("gcloud");
+
+        let actual = renderer
+            .env("CLOUDSDK_CONFIG", config_dir.to_string_lossy())
+            .collect();
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn active_config() {
+        let renderer = ModuleRenderer::new("gcloud")
+            .file("gcloud/active_config", "work")
+            .file("gcloud/configurations/config_work", CONFIG);
+        let config_dir = renderer.root_path().join("gcloud");
+
+        let actual = renderer
+            .env("CLOUDSDK_CONFIG", config_dir.to_string_lossy())
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Blue
+                .bold()
+                .paint("☁️  user@example.com/my-project(us-central1)")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn active_config_from_env_with_region_alias() {
+        let renderer = ModuleRenderer::new("gcloud")
+            .file("gcloud/active_config", "work")
+            .file(
+                "gcloud/configurations/config_personal",
+                "[compute]\nregion = europe-west1\n",
+            );
+        let config_dir = renderer.root_path().join("gcloud");
+
+        let actual = renderer
+            .env("CLOUDSDK_CONFIG", config_dir.to_string_lossy())
+            .env("CLOUDSDK_ACTIVE_CONFIG_NAME", "personal")
+            .config(toml::toml! {
+                [gcloud.region_aliases]
+                europe-west1 = "be"
+            })
+            .collect();
+        let expected = Some(format!("on {} ", Color::Blue.bold().paint("☁️  (be)")));
+        assert_eq!(expected, actual);
+    }
+}
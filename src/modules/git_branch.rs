@@ -1,8 +1,9 @@
-use unicode_segmentation::UnicodeSegmentation;
-
+use super::utils::truncate::truncate;
 use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::git_branch::GitBranchConfig;
+use crate::utils;
+use git2::Repository;
 
 /// Creates a module with the Git branch in the current directory
 ///
@@ -12,9 +13,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let config = GitBranchConfig::try_load(module.config);
     module.set_style(config.style);
 
-    module.get_prefix().set_value("on ");
+    module.get_prefix().set_value(context.locale().on);
 
-    let truncation_symbol = get_graphemes(config.truncation_symbol, 1);
     module.create_segment("symbol", &config.symbol);
 
     // TODO: Once error handling is implemented, warn the user if their config
@@ -31,29 +31,93 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     let repo = context.get_repo().ok()?;
     let branch_name = repo.branch.as_ref()?;
-    let truncated_graphemes = get_graphemes(&branch_name, len);
-    // The truncation symbol should only be added if we truncated
-    let truncated_and_symbol = if len < graphemes_len(&branch_name) {
-        truncated_graphemes + &truncation_symbol
-    } else {
-        truncated_graphemes
-    };
+    let truncated_and_symbol = truncate(branch_name, len, config.truncation_symbol);
 
-    module.create_segment(
+    let segment = module.create_segment(
         "name",
         &config.branch_name.with_value(&truncated_and_symbol),
     );
 
+    // Clicking the branch opens its compare page on the forge, in terminals
+    // supporting hyperlinks
+    if context.hyperlinks() {
+        let remote_url = repo
+            .open()
+            .and_then(|repository| get_origin_url(&repository));
+        if let Some(url) = remote_url.and_then(|remote| compare_url(&remote, branch_name)) {
+            segment.set_link(url);
+        }
+    }
+
     Some(module)
 }
 
-fn get_graphemes(text: &str, length: usize) -> String {
-    UnicodeSegmentation::graphemes(text, true)
-        .take(length)
-        .collect::<Vec<&str>>()
-        .concat()
+fn get_origin_url(repository: &Repository) -> Option<String> {
+    let remote = repository.find_remote("origin").ok()?;
+    remote.url().map(String::from)
 }
 
-fn graphemes_len(text: &str) -> usize {
-    UnicodeSegmentation::graphemes(&text[..], true).count()
+/// Get the URL of the page comparing a branch with the default branch, from
+/// the URL of a remote hosted on GitHub, GitLab or a forge with the same URLs
+fn compare_url(remote_url: &str, branch: &str) -> Option<String> {
+    let remote_url = remote_url.trim_end_matches('/').trim_end_matches(".git");
+
+    // e.g. "https://github.com/starship/starship" or "ssh://git@host:22/owner/repo"
+    let (host, path) = if let Some((_, address)) = remote_url.split_once("://") {
+        let (host, path) = address.split_once('/')?;
+        (host, path)
+    } else {
+        // e.g. "git@github.com:starship/starship"
+        remote_url.split_once(':')?
+    };
+
+    let host = host.rsplit('@').next()?;
+    let host = host.split(':').next()?;
+    if host.is_empty() || path.is_empty() {
+        return None;
+    }
+
+    let branch = utils::percent_encode(branch);
+    if host.contains("gitlab") {
+        Some(format!("https://{}/{}/-/compare/{}", host, path, branch))
+    } else {
+        Some(format!("https://{}/{}/compare/{}", host, path, branch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_url() {
+        let expected = Some(String::from(
+            "https://github.com/starship/starship/compare/feature/hyperlinks",
+        ));
+        assert_eq!(
+            compare_url(
+                "https://github.com/starship/starship.git",
+                "feature/hyperlinks"
+            ),
+            expected
+        );
+        assert_eq!(
+            compare_url("git@github.com:starship/starship.git", "feature/hyperlinks"),
+            expected
+        );
+        assert_eq!(
+            compare_url(
+                "ssh://git@github.com:22/starship/starship",
+                "feature/hyperlinks"
+            ),
+            expected
+        );
+        assert_eq!(
+            compare_url("https://gitlab.com/group/project.git", "main"),
+            Some(String::from(
+                "https://gitlab.com/group/project/-/compare/main"
+            ))
+        );
+        assert_eq!(compare_url("/srv/git/project.git", "main"), None);
+    }
 }
@@ -0,0 +1,61 @@
+use git2::{DescribeFormatOptions, DescribeOptions, Repository};
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::git_commit::GitCommitConfig;
+
+/// Creates a module with the abbreviated hash of the current commit
+///
+/// By default the hash is only shown when `HEAD` is detached, e.g. while
+/// checking out a tag or during a rebase. With `tag_disabled = false`, the
+/// nearest tag reachable from the commit is shown too.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("git_commit");
+    let config: GitCommitConfig = GitCommitConfig::try_load(module.config);
+
+    let repo = context.get_repo().ok()?;
+    let repository = repo.open()?;
+
+    let is_detached = repository.head_detached().unwrap_or(false);
+    if config.only_detached && !is_detached {
+        return None;
+    }
+
+    let head_commit = repository.head().ok()?.peel_to_commit().ok()?;
+    let commit_id = head_commit.id().to_string();
+    let hash: String = commit_id.chars().take(config.commit_hash_length).collect();
+
+    module
+        .get_prefix()
+        .set_value(config.prefix)
+        .set_style(config.style);
+    module
+        .get_suffix()
+        .set_value(config.suffix)
+        .set_style(config.style);
+    module.set_style(config.style);
+
+    module.create_segment("hash", &config.hash.with_value(&hash));
+
+    if !config.tag_disabled {
+        if let Some(tag) = get_nearest_tag(&repository) {
+            module.create_segment(
+                "tag",
+                &SegmentConfig::new(&format!("{}{}", config.tag_symbol, tag)),
+            );
+        }
+    }
+
+    Some(module)
+}
+
+/// Get the name of the nearest tag reachable from `HEAD`, like
+/// `git describe --tags --abbrev=0`
+fn get_nearest_tag(repository: &Repository) -> Option<String> {
+    let describe = repository
+        .describe(DescribeOptions::new().describe_tags())
+        .ok()?;
+    describe
+        .format(Some(DescribeFormatOptions::new().abbreviated_size(0)))
+        .ok()
+}
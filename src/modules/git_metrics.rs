@@ -0,0 +1,71 @@
+use git2::{DiffStats, Repository};
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::git_metrics::GitMetricsConfig;
+
+/// Creates a module with the number of lines added and deleted in the working
+/// tree (including the staged changes), compared to `HEAD`
+///
+/// Will display the following:
+///     - `+12/-4` when lines were added and deleted
+///     - `+12` or `-4` when lines were only added or deleted, unless `only_nonzero_diffs` is false
+///     - nothing when the working tree is clean
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("git_metrics");
+    let config: GitMetricsConfig = GitMetricsConfig::try_load(module.config);
+
+    // Diffing the working tree has a cost, so it is only done once the
+    // module has been enabled
+    if config.disabled {
+        log::debug!(
+            "Module \"git_metrics\" is disabled by default, set `disabled = false` to enable it"
+        );
+        return None;
+    }
+
+    let repo = context.get_repo().ok()?;
+    let stats = get_diff_stats(&*repo.open()?).ok()?;
+    let (added, deleted) = (stats.insertions(), stats.deletions());
+    log::debug!("Lines added: {}, deleted: {}", added, deleted);
+
+    if added == 0 && deleted == 0 {
+        return None;
+    }
+
+    module.get_prefix().set_value("");
+
+    let show_added = added > 0 || !config.only_nonzero_diffs;
+    let show_deleted = deleted > 0 || !config.only_nonzero_diffs;
+
+    let added = format!("+{}", added);
+    let deleted = format!("-{}", deleted);
+    if show_added {
+        module.create_segment(
+            "added",
+            &SegmentConfig::new(&added).with_style(Some(config.added_style)),
+        );
+    }
+    if show_added && show_deleted {
+        module.create_segment("separator", &SegmentConfig::new(config.separator));
+    }
+    if show_deleted {
+        module.create_segment(
+            "deleted",
+            &SegmentConfig::new(&deleted).with_style(Some(config.deleted_style)),
+        );
+    }
+
+    Some(module)
+}
+
+/// Diff the working tree and the index against `HEAD`, or against an empty
+/// tree in a repository without commits
+fn get_diff_stats(repository: &Repository) -> Result<DiffStats, git2::Error> {
+    let head_tree = repository
+        .head()
+        .ok()
+        .and_then(|head| head.peel_to_tree().ok());
+    let diff = repository.diff_tree_to_workdir_with_index(head_tree.as_ref(), None)?;
+    diff.stats()
+}
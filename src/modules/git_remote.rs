@@ -0,0 +1,61 @@
+use git2::{BranchType, Repository};
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::git_remote::GitRemoteConfig;
+
+/// Creates a module with the remote and the remote branch tracked by the
+/// current branch, where `git push` and `git pull` go by default
+///
+/// Will display nothing if the current branch doesn't track a remote branch
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("git_remote");
+    let config = GitRemoteConfig::try_load(module.config);
+
+    if config.disabled {
+        log::debug!(
+            "Module \"git_remote\" is disabled by default, set `disabled = false` to enable it"
+        );
+        return None;
+    }
+
+    let repo = context.get_repo().ok()?;
+    let branch_name = repo.branch.as_ref()?;
+    let (remote_name, remote_branch) = get_upstream(&*repo.open()?, branch_name)?;
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("remote_name", &config.remote_name.with_value(&remote_name));
+    module.create_segment(
+        "remote_branch",
+        &config
+            .remote_branch
+            .with_value(&format!("/{}", remote_branch)),
+    );
+
+    Some(module)
+}
+
+/// Get the name of the remote and of the remote branch tracked by a branch
+fn get_upstream(repository: &Repository, branch_name: &str) -> Option<(String, String)> {
+    let branch = repository
+        .find_branch(branch_name, BranchType::Local)
+        .ok()?;
+    let upstream = branch.upstream().ok()?;
+    // e.g. "refs/remotes/origin/feature/login"
+    let upstream_ref = upstream.get().name()?;
+
+    let remote_name = repository
+        .branch_upstream_remote(branch.get().name()?)
+        .ok()?;
+    let remote_name = remote_name.as_str()?;
+
+    let remote_branch = upstream_ref
+        .strip_prefix("refs/remotes/")?
+        .strip_prefix(remote_name)?
+        .trim_start_matches('/');
+
+    Some((remote_name.to_string(), remote_branch.to_string()))
+}
@@ -1,5 +1,5 @@
 use git2::RepositoryState;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use super::{Context, Module, RootModuleConfig, SegmentConfig};
 use crate::configs::git_state::GitStateConfig;
@@ -17,10 +17,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.get_suffix().set_value(") ");
 
     let repo = context.get_repo().ok()?;
-    let repo_root = repo.root.as_ref()?;
     let repo_state = repo.state?;
+    // The git directory isn't always `.git` in the root of the repository,
+    // e.g. in a worktree or a submodule
+    let git_dir = repo.open()?.path().to_path_buf();
 
-    let state_description = get_state_description(repo_state, repo_root, config);
+    let progress_divider = config.progress_divider.clone();
+    let state_description = get_state_description(repo_state, &git_dir, config);
 
     let label = match &state_description {
         StateDescription::Label(label) => label,
@@ -37,7 +40,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             "progress_current",
             &SegmentConfig::new(&format!(" {}", progress.current)),
         );
-        module.create_segment("progress_divider", &SegmentConfig::new("/"));
+        module.create_segment("progress_divider", &progress_divider);
         module.create_segment(
             "progress_total",
             &SegmentConfig::new(&format!("{}", progress.total)),
@@ -52,7 +55,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 /// During a git operation it will show: REBASING, BISECTING, MERGING, etc.
 fn get_state_description<'a>(
     state: RepositoryState,
-    root: &'a std::path::PathBuf,
+    git_dir: &Path,
     config: GitStateConfig<'a>,
 ) -> StateDescription<'a> {
     match state {
@@ -73,20 +76,25 @@ fn get_state_description<'a>(
         RepositoryState::Bisect => {
             StateDescription::Label(StateLabel::new("bisect", config.bisect))
         }
-        RepositoryState::ApplyMailbox => StateDescription::Label(StateLabel::new("am", config.am)),
-        RepositoryState::ApplyMailboxOrRebase => {
-            StateDescription::Label(StateLabel::new("am_or_rebase", config.am_or_rebase))
+        RepositoryState::ApplyMailbox => describe_rebase(git_dir, StateLabel::new("am", config.am)),
+        RepositoryState::ApplyMailboxOrRebase => describe_rebase(
+            git_dir,
+            StateLabel::new("am_or_rebase", config.am_or_rebase),
+        ),
+        RepositoryState::Rebase => {
+            describe_rebase(git_dir, StateLabel::new("rebase", config.rebase))
+        }
+        RepositoryState::RebaseInteractive => {
+            describe_rebase(git_dir, StateLabel::new("rebase", config.rebase))
+        }
+        RepositoryState::RebaseMerge => {
+            describe_rebase(git_dir, StateLabel::new("rebase", config.rebase))
         }
-        RepositoryState::Rebase => describe_rebase(root, config.rebase),
-        RepositoryState::RebaseInteractive => describe_rebase(root, config.rebase),
-        RepositoryState::RebaseMerge => describe_rebase(root, config.rebase),
     }
 }
 
-fn describe_rebase<'a>(
-    root: &'a PathBuf,
-    rebase_config: SegmentConfig<'a>,
-) -> StateDescription<'a> {
+/// Describe a rebase, or a `git am` which also applies patches one by one
+fn describe_rebase<'a>(dot_git: &Path, label: StateLabel<'a>) -> StateDescription<'a> {
     /*
      *  Sadly, libgit2 seems to have some issues with reading the state of
      *  interactive rebases. So, instead, we'll poke a few of the .git files
@@ -95,8 +103,6 @@ fn describe_rebase<'a>(
      *  The following is based heavily on: https://github.com/magicmonty/bash-git-prompt
      */
 
-    let dot_git = root.join(".git");
-
     let has_path = |relative_path: &str| {
         let path = dot_git.join(Path::new(relative_path));
         path.exists()
@@ -124,10 +130,8 @@ fn describe_rebase<'a>(
     };
 
     match progress {
-        None => StateDescription::Label(StateLabel::new("rebase", rebase_config)),
-        Some(progress) => {
-            StateDescription::LabelAndProgress(StateLabel::new("rebase", rebase_config), progress)
-        }
+        None => StateDescription::Label(label),
+        Some(progress) => StateDescription::LabelAndProgress(label, progress),
     }
 }
 
@@ -1,9 +1,13 @@
-use git2::{Repository, Status};
+use git2::{BranchType, Repository, Status};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
 use super::{Context, Module, RootModuleConfig};
 
 use crate::config::SegmentConfig;
-use crate::configs::git_status::{CountConfig, GitStatusConfig};
+use crate::configs::git_status::{CountConfig, GitBackend, GitStatusConfig, UntrackedFiles};
 
 /// Creates a module with the Git branch in the current directory
 ///
@@ -22,8 +26,7 @@ use crate::configs::git_status::{CountConfig, GitStatusConfig};
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let repo = context.get_repo().ok()?;
     let branch_name = repo.branch.as_ref()?;
-    let repo_root = repo.root.as_ref()?;
-    let repository = Repository::open(repo_root).ok()?;
+    let repository = repo.open()?;
 
     let mut module = context.new_module("git_status");
     let config: GitStatusConfig = GitStatusConfig::try_load(module.config);
@@ -45,16 +48,44 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         log::debug!("Repo ahead/behind: {:?}", ahead_behind);
     }
 
-    let stash_object = repository.revparse_single("refs/stash");
-    if stash_object.is_ok() {
-        log::debug!("Stash object: {:?}", stash_object);
+    let stash_count = get_stash_count(&repository);
+    if stash_count > 0 {
+        log::debug!("Stash count: {}", stash_count);
     } else {
-        log::trace!("No stash object found");
+        log::trace!("No stash found");
     }
 
-    let repo_status = get_repo_status(&repository);
+    let untracked_files = config
+        .untracked_files
+        .unwrap_or_else(|| get_untracked_files_config(&repository));
+    let repo_status = match (config.status_timeout, repo.root.as_ref()) {
+        #[cfg(feature = "gitoxide")]
+        (0, Some(root)) if config.backend == GitBackend::Gitoxide => {
+            Some(get_repo_status_gitoxide(root, untracked_files))
+        }
+        (0, _) | (_, None) => Some(get_repo_status(&repository, untracked_files)),
+        (timeout, Some(root)) => get_repo_status_with_timeout(
+            root.to_path_buf(),
+            config.backend,
+            untracked_files,
+            Duration::from_millis(timeout),
+        ),
+    };
     log::debug!("Repo status: {:?}", repo_status);
 
+    // Show that the status is unknown, rather than pretending the working tree is clean
+    let repo_status = match repo_status {
+        Some(repo_status) => repo_status,
+        None => {
+            log::warn!(
+                "git status timed out after {}ms, increase `status_timeout` or set `untracked_files = \"none\"`",
+                config.status_timeout
+            );
+            module.create_segment("timed_out", &config.timed_out);
+            Err(git2::Error::from_str("Status timed out"))
+        }
+    };
+
     // Add the conflicted segment
     if let Ok(repo_status) = repo_status {
         create_segment_with_count(
@@ -68,36 +99,26 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     // Add the ahead/behind segment
     if let Ok((ahead, behind)) = ahead_behind {
+        // `show_sync_count` shows both counts, without changing their style
+        let sync_count = |count_config: CountConfig| CountConfig {
+            enabled: count_config.enabled || config.show_sync_count,
+            style: count_config.style,
+        };
+        let ahead_count = sync_count(config.ahead_count);
+        let behind_count = sync_count(config.behind_count);
+
         let add_ahead = |m: &mut Module<'a>| {
-            create_segment_with_count(
-                m,
-                "ahead",
-                ahead,
-                &config.ahead,
-                CountConfig {
-                    enabled: config.show_sync_count,
-                    style: None,
-                },
-            );
+            create_segment_with_count(m, "ahead", ahead, &config.ahead, ahead_count);
         };
 
         let add_behind = |m: &mut Module<'a>| {
-            create_segment_with_count(
-                m,
-                "behind",
-                behind,
-                &config.behind,
-                CountConfig {
-                    enabled: config.show_sync_count,
-                    style: None,
-                },
-            );
+            create_segment_with_count(m, "behind", behind, &config.behind, behind_count);
         };
 
         if ahead > 0 && behind > 0 {
             module.create_segment("diverged", &config.diverged);
 
-            if config.show_sync_count {
+            if ahead_count.enabled || behind_count.enabled {
                 add_ahead(&mut module);
                 add_behind(&mut module);
             }
@@ -113,9 +134,13 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 
     // Add the stashed segment
-    if stash_object.is_ok() {
-        module.create_segment("stashed", &config.stashed);
-    }
+    create_segment_with_count(
+        &mut module,
+        "stashed",
+        stash_count,
+        &config.stashed,
+        config.stashed_count,
+    );
 
     // Add all remaining status segments
     if let Ok(repo_status) = repo_status {
@@ -186,14 +211,59 @@ fn create_segment_with_count<'a>(
     }
 }
 
+/// Gets the number of stashes, which are the entries of the reflog of `refs/stash`
+fn get_stash_count(repository: &Repository) -> usize {
+    repository
+        .reflog("refs/stash")
+        .map(|reflog| reflog.len())
+        .unwrap_or(0)
+}
+
+/// Gets which untracked files git itself shows, from `status.showUntrackedFiles`
+fn get_untracked_files_config(repository: &Repository) -> UntrackedFiles {
+    let value = repository
+        .config()
+        .and_then(|config| config.get_string("status.showUntrackedFiles"));
+
+    match value.as_deref() {
+        Ok("no") => UntrackedFiles::None,
+        Ok("all") => UntrackedFiles::All,
+        _ => UntrackedFiles::Normal,
+    }
+}
+
+/// Gets the status of the repository on another thread, giving up after
+/// `timeout`. The thread opens its own handle, since the shared one can't be
+/// sent to another thread, and is left running when timing out.
+fn get_repo_status_with_timeout(
+    root: PathBuf,
+    backend: GitBackend,
+    untracked_files: UntrackedFiles,
+    timeout: Duration,
+) -> Option<Result<RepoStatus, git2::Error>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let repo_status = match backend {
+            GitBackend::Libgit2 => Repository::open(root)
+                .and_then(|repository| get_repo_status(&repository, untracked_files)),
+            #[cfg(feature = "gitoxide")]
+            GitBackend::Gitoxide => get_repo_status_gitoxide(&root, untracked_files),
+        };
+        let _ = sender.send(repo_status);
+    });
+
+    receiver.recv_timeout(timeout).ok()
+}
+
 /// Gets the number of files in various git states (staged, modified, deleted, etc...)
-fn get_repo_status(repository: &Repository) -> Result<RepoStatus, git2::Error> {
+fn get_repo_status(
+    repository: &Repository,
+    untracked_files: UntrackedFiles,
+) -> Result<RepoStatus, git2::Error> {
     let mut status_options = git2::StatusOptions::new();
 
-    match repository.config()?.get_entry("status.showUntrackedFiles") {
-        Ok(entry) => status_options.include_untracked(entry.value() != Some("no")),
-        _ => status_options.include_untracked(true),
-    };
+    status_options.include_untracked(untracked_files != UntrackedFiles::None);
+    status_options.recurse_untracked_dirs(untracked_files == UntrackedFiles::All);
     status_options.renames_from_rewrites(true);
     status_options.renames_head_to_index(true);
     status_options.renames_index_to_workdir(true);
@@ -220,6 +290,57 @@ fn get_repo_status(repository: &Repository) -> Result<RepoStatus, git2::Error> {
     Ok(repo_status)
 }
 
+/// Gets the number of files in various git states with gitoxide, counting
+/// them like libgit2 does
+#[cfg(feature = "gitoxide")]
+fn get_repo_status_gitoxide(
+    root: &std::path::Path,
+    untracked_files: UntrackedFiles,
+) -> Result<RepoStatus, git2::Error> {
+    use gix::diff::index::Change;
+    use gix::status::index_worktree::iter::Summary;
+    use gix::status::Item;
+
+    let to_error = |e: &dyn std::error::Error| git2::Error::from_str(&e.to_string());
+
+    let repository = gix::open(root).map_err(|e| to_error(&e))?;
+    let untracked_files = match untracked_files {
+        UntrackedFiles::All => gix::status::UntrackedFiles::Files,
+        UntrackedFiles::Normal => gix::status::UntrackedFiles::Collapsed,
+        UntrackedFiles::None => gix::status::UntrackedFiles::None,
+    };
+    let items = repository
+        .status(gix::progress::Discard)
+        .map_err(|e| to_error(&e))?
+        .untracked_files(untracked_files)
+        .index_worktree_rewrites(gix::diff::Rewrites::default())
+        .into_iter(None)
+        .map_err(|e| to_error(&e))?;
+
+    let mut repo_status = RepoStatus::default();
+    for item in items {
+        match item.map_err(|e| to_error(&e))? {
+            Item::IndexWorktree(item) => match item.summary() {
+                Some(Summary::Conflict) => repo_status.conflicted += 1,
+                Some(Summary::Removed) => repo_status.deleted += 1,
+                Some(Summary::Renamed) => repo_status.renamed += 1,
+                Some(Summary::Modified) | Some(Summary::TypeChange) => repo_status.modified += 1,
+                Some(Summary::Added) => repo_status.untracked += 1,
+                Some(Summary::Copied) | Some(Summary::IntentToAdd) | None => {}
+            },
+            Item::TreeIndex(change) => match change {
+                Change::Deletion { .. } => repo_status.deleted += 1,
+                Change::Rewrite { copy: false, .. } => repo_status.renamed += 1,
+                Change::Addition { .. } | Change::Modification { .. } | Change::Rewrite { .. } => {
+                    repo_status.staged += 1
+                }
+            },
+        }
+    }
+
+    Ok(repo_status)
+}
+
 fn is_conflicted(status: Status) -> bool {
     status.is_conflicted()
 }
@@ -250,12 +371,11 @@ fn get_ahead_behind(
     repository: &Repository,
     branch_name: &str,
 ) -> Result<(usize, usize), git2::Error> {
-    let branch_object = repository.revparse_single(branch_name)?;
-    let tracking_branch_name = format!("{}@{{upstream}}", branch_name);
-    let tracking_object = repository.revparse_single(&tracking_branch_name)?;
+    let branch = repository.find_branch(branch_name, BranchType::Local)?;
+    let upstream = branch.upstream()?;
 
-    let branch_oid = branch_object.id();
-    let tracking_oid = tracking_object.id();
+    let branch_oid = branch.get().peel_to_commit()?.id();
+    let tracking_oid = upstream.get().peel_to_commit()?.id();
 
     repository.graph_ahead_behind(branch_oid, tracking_oid)
 }
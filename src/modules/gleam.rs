@@ -0,0 +1,86 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::gleam::GleamConfig;
+
+/// Creates a module with the current Gleam version
+///
+/// Will display the Gleam version if any of the following criteria are met:
+///     - Current directory contains a `gleam.toml` file
+///     - Current directory contains a file with the `.gleam` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_gleam_project = context
+        .try_begin_scan()?
+        .set_files(&["gleam.toml"])
+        .set_extensions(&["gleam"])
+        .is_match();
+
+    if !is_gleam_project {
+        return None;
+    }
+
+    let gleam_version = context.exec_cmd("gleam", &["--version"])?.stdout;
+    let formatted_version = format_gleam_version(&gleam_version)?;
+
+    let mut module = context.new_module("gleam");
+    let config = GleamConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_gleam_version(gleam_stdout: &str) -> Option<String> {
+    // gleam --version output looks like this:
+    // gleam 0.25.0
+    let version = gleam_stdout.trim().strip_prefix("gleam ")?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_gleam_version() {
+        assert_eq!(
+            format_gleam_version("gleam 0.25.0\n"),
+            Some("v0.25.0".to_string())
+        );
+        assert_eq!(format_gleam_version("not gleam"), None);
+    }
+
+    #[test]
+    fn folder_without_gleam_files() {
+        let actual = ModuleRenderer::new("gleam")
+            .cmd("gleam --version", "gleam 0.25.0\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_gleam_toml() {
+        let actual = ModuleRenderer::new("gleam")
+            .file("gleam.toml", "name = \"starship\"\n")
+            .cmd("gleam --version", "gleam 0.25.0\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("⭐ v0.25.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_gleam_file_and_no_compiler() {
+        let actual = ModuleRenderer::new("gleam")
+            .file("main.gleam", "")
+            .cmd_output("gleam --version", None)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+}
@@ -0,0 +1,168 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::godot::GodotConfig;
+use crate::utils;
+
+/// Creates a module with the Godot version the current project targets
+///
+/// Will display the Godot version if the current directory contains a
+/// `project.godot` file. The version is taken from the project's
+/// `config/features`, which is only written by Godot 4 and later.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_godot_project = context
+        .try_begin_scan()?
+        .set_files(&["project.godot"])
+        .is_match();
+
+    if !is_godot_project {
+        return None;
+    }
+
+    let project = utils::read_file(context.current_dir.join("project.godot")).ok()?;
+
+    let mut module = context.new_module("godot");
+    let config = GodotConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+
+    if let Some(version) = parse_feature_version(&project) {
+        let formatted_version = format!("v{}", version);
+        module.create_segment("version", &config.version.with_value(&formatted_version));
+    }
+
+    if config.show_project_name {
+        if let Some(name) = parse_project_name(&project) {
+            let formatted_name = format!(" ({})", name);
+            module.create_segment(
+                "project_name",
+                &config.project_name.with_value(&formatted_name),
+            );
+        }
+    }
+
+    Some(module)
+}
+
+/// Get the value of a `key=value` line of the `[application]` section
+fn get_application_value<'a>(project: &'a str, key: &str) -> Option<&'a str> {
+    let mut in_application = false;
+    for line in project.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_application = line == "[application]";
+        } else if in_application {
+            if let Some((line_key, value)) = line.split_once('=') {
+                if line_key.trim() == key {
+                    return Some(value.trim());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn parse_feature_version(project: &str) -> Option<&str> {
+    // Godot 4 projects list the engine version amongst their features:
+    // config/features=PackedStringArray("4.2", "Forward Plus")
+    get_application_value(project, "config/features")?
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .find(|feature| feature.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+fn parse_project_name(project: &str) -> Option<&str> {
+    // config/name="Rocket"
+    let name = get_application_value(project, "config/name")?.trim_matches('"');
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const GODOT_4_PROJECT: &str = r#"config_version=5
+
+[application]
+
+config/name="Rocket"
+run/main_scene="res://main.tscn"
+config/features=PackedStringArray("4.2", "Forward Plus")
+
+[rendering]
+
+renderer/rendering_method="forward_plus"
+"#;
+
+    const GODOT_3_PROJECT: &str = r#"config_version=4
+
+[application]
+
+config/name="Rocket"
+"#;
+
+    #[test]
+    fn test_parse_feature_version() {
+        assert_eq!(parse_feature_version(GODOT_4_PROJECT), Some("4.2"));
+        assert_eq!(parse_feature_version(GODOT_3_PROJECT), None);
+    }
+
+    #[test]
+    fn test_parse_project_name() {
+        assert_eq!(parse_project_name(GODOT_4_PROJECT), Some("Rocket"));
+        assert_eq!(parse_project_name("[application]\nconfig/name=\"\""), None);
+        assert_eq!(parse_project_name("config/name=\"Rocket\""), None);
+    }
+
+    #[test]
+    fn folder_without_godot_project() {
+        let actual = ModuleRenderer::new("godot").file("main.tscn", "").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_godot_4_project() {
+        let actual = ModuleRenderer::new("godot")
+            .file("project.godot", GODOT_4_PROJECT)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🤖 v4.2")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_godot_3_project() {
+        let actual = ModuleRenderer::new("godot")
+            .file("project.godot", GODOT_3_PROJECT)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🤖 ")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn project_name_enabled() {
+        let actual = ModuleRenderer::new("godot")
+            .file("project.godot", GODOT_4_PROJECT)
+            .config(toml::toml! {
+                [godot]
+                show_project_name = true
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Blue.bold().paint("🤖 v4.2 (Rocket)")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,5 +1,3 @@
-use std::process::Command;
-
 use super::{Context, Module, RootModuleConfig};
 
 use crate::configs::go::GoConfig;
@@ -32,18 +30,16 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.set_style(config.style);
     module.create_segment("symbol", &config.symbol);
 
-    let formatted_version = format_go_version(&get_go_version()?)?;
+    let formatted_version = format_go_version(&get_go_version(context)?)?;
     module.create_segment("version", &config.version.with_value(&formatted_version));
 
     Some(module)
 }
 
-fn get_go_version() -> Option<String> {
-    Command::new("go")
-        .arg("version")
-        .output()
-        .ok()
-        .and_then(|output| String::from_utf8(output.stdout).ok())
+fn get_go_version(context: &Context) -> Option<String> {
+    context
+        .exec_cmd("go", &["version"])
+        .map(|output| output.stdout)
 }
 
 fn format_go_version(go_stdout: &str) -> Option<String> {
@@ -66,10 +62,32 @@ fn format_go_version(go_stdout: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
 
     #[test]
     fn test_format_go_version() {
         let input = "go version go1.12 darwin/amd64";
         assert_eq!(format_go_version(input), Some("v1.12".to_string()));
     }
+
+    #[test]
+    fn folder_with_go_mod() {
+        let actual = ModuleRenderer::new("golang")
+            .file("go.mod", "")
+            .cmd("go version", "go version go1.12.1 linux/amd64\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🐹 v1.12.1")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_without_go_files() {
+        let actual = ModuleRenderer::new("golang")
+            .cmd("go version", "go version go1.12.1 linux/amd64\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
 }
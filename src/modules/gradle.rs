@@ -0,0 +1,174 @@
+use std::path::{Path, PathBuf};
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::gradle::GradleConfig;
+use crate::utils;
+
+const WRAPPER_PROPERTIES: &str = "gradle/wrapper/gradle-wrapper.properties";
+
+/// Creates a module with the Gradle version used by the project's wrapper
+///
+/// Will display the Gradle version if any of the following criteria are met:
+///     - Current directory contains a `gradlew` file
+///     - Current directory contains a `build.gradle(.kts)` or `settings.gradle(.kts)` file
+///     - Current directory contains a `gradle/wrapper/gradle-wrapper.properties` file
+///
+/// The version is read from the wrapper's `distributionUrl`, so that showing
+/// it doesn't require starting the JVM.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_gradle_project = context.current_dir.join(WRAPPER_PROPERTIES).is_file()
+        || context
+            .try_begin_scan()?
+            .set_files(&[
+                "gradlew",
+                "build.gradle",
+                "build.gradle.kts",
+                "settings.gradle",
+                "settings.gradle.kts",
+            ])
+            .is_match();
+
+    if !is_gradle_project {
+        return None;
+    }
+
+    let properties_path = find_wrapper_properties(&context.current_dir)?;
+    let properties = utils::read_file(&properties_path).ok()?;
+    let gradle_version = parse_wrapper_version(&properties)?;
+
+    let mut module = context.new_module("gradle");
+    let config = GradleConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&gradle_version));
+
+    Some(module)
+}
+
+/// The wrapper of a multi-project build is in its root directory, which can
+/// be a parent of the directory of a subproject
+fn find_wrapper_properties(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(WRAPPER_PROPERTIES))
+        .find(|properties| properties.is_file())
+}
+
+fn parse_wrapper_version(properties: &str) -> Option<String> {
+    // The wrapper properties contain a line like this one:
+    // distributionUrl=https\://services.gradle.org/distributions/gradle-6.0.1-bin.zip
+    let (_, distribution_url) = properties
+        .lines()
+        .map(str::trim)
+        .find(|line| line.starts_with("distributionUrl"))?
+        .split_once('=')?;
+
+    // return "gradle-6.0.1-bin.zip"
+    let file_name = distribution_url.rsplit('/').next()?;
+    let version = file_name
+        .trim_start_matches("gradle-")
+        .trim_end_matches(".zip")
+        .trim_end_matches("-bin")
+        .trim_end_matches("-all");
+
+    if version.is_empty() || version == file_name {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+    use std::fs;
+    use std::io;
+
+    const PROPERTIES: &str = "distributionBase=GRADLE_USER_HOME
+distributionPath=wrapper/dists
+distributionUrl=https\\://services.gradle.org/distributions/gradle-6.0.1-bin.zip
+zipStoreBase=GRADLE_USER_HOME
+zipStorePath=wrapper/dists
+";
+
+    #[test]
+    fn test_parse_wrapper_version() {
+        assert_eq!(
+            parse_wrapper_version(PROPERTIES),
+            Some("v6.0.1".to_string())
+        );
+        assert_eq!(
+            parse_wrapper_version(
+                "distributionUrl=https\\://services.gradle.org/distributions/gradle-5.6.4-all.zip"
+            ),
+            Some("v5.6.4".to_string())
+        );
+        assert_eq!(
+            parse_wrapper_version(
+                "distributionUrl=https\\://services.gradle.org/distributions/gradle-6.1-rc-1-bin.zip"
+            ),
+            Some("v6.1-rc-1".to_string())
+        );
+        assert_eq!(
+            parse_wrapper_version("distributionBase=GRADLE_USER_HOME"),
+            None
+        );
+    }
+
+    #[test]
+    fn folder_without_gradle_wrapper() {
+        let actual = ModuleRenderer::new("gradle")
+            .file("build.gradle", "")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_wrapper_properties() {
+        let actual = ModuleRenderer::new("gradle")
+            .file("gradlew", "")
+            .file(WRAPPER_PROPERTIES, PROPERTIES)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🅶 v6.0.1")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_build_gradle_kts() {
+        let actual = ModuleRenderer::new("gradle")
+            .file("build.gradle.kts", "")
+            .file(WRAPPER_PROPERTIES, PROPERTIES)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🅶 v6.0.1")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn subproject_with_wrapper_in_parent() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let subproject = dir.path().join("app");
+        fs::create_dir_all(&subproject)?;
+        fs::write(subproject.join("build.gradle"), "")?;
+        fs::create_dir_all(dir.path().join("gradle/wrapper"))?;
+        fs::write(dir.path().join(WRAPPER_PROPERTIES), PROPERTIES)?;
+
+        let actual = ModuleRenderer::new("gradle").path(&subproject).collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("🅶 v6.0.1")));
+        assert_eq!(expected, actual);
+        dir.close()
+    }
+
+    #[test]
+    fn folder_with_gradlew_without_properties() {
+        let actual = ModuleRenderer::new("gradle").file("gradlew", "").collect();
+
+        assert_eq!(None, actual);
+    }
+}
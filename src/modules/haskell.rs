@@ -0,0 +1,130 @@
+use yaml_rust::YamlLoader;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::haskell::HaskellConfig;
+use crate::utils;
+
+/// Creates a module with the current GHC version, or Stack resolver
+///
+/// Will display the version if any of the following criteria are met:
+///     - Current directory contains a `stack.yaml` or `cabal.project` file
+///     - Current directory contains a file with the `.cabal` or `.hs` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_haskell_project = context
+        .try_begin_scan()?
+        .set_files(&["stack.yaml", "cabal.project"])
+        .set_extensions(&["cabal", "hs"])
+        .is_match();
+
+    if !is_haskell_project {
+        return None;
+    }
+
+    // `ghc --version` is slow, the resolver pinned by Stack is used when there is one
+    let formatted_version = match get_stack_resolver(context) {
+        Some(resolver) => format_resolver(&resolver),
+        None => {
+            let ghc_version = context.exec_cmd("ghc", &["--numeric-version"])?.stdout;
+            format_ghc_version(&ghc_version)?
+        }
+    };
+
+    let mut module = context.new_module("haskell");
+    let config = HaskellConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+/// Get the resolver of the Stack project, e.g. `lts-18.5` or `ghc-8.10.7`
+fn get_stack_resolver(context: &Context) -> Option<String> {
+    let stack_yaml = utils::read_file(context.current_dir.join("stack.yaml")).ok()?;
+    let yaml_docs = YamlLoader::load_from_str(&stack_yaml).ok()?;
+    let stack_yaml = yaml_docs.first()?;
+    // `snapshot` is the newer name of `resolver`
+    let resolver = stack_yaml["resolver"]
+        .as_str()
+        .or_else(|| stack_yaml["snapshot"].as_str())?;
+
+    Some(resolver.trim().to_string())
+}
+
+/// A resolver pinning a compiler gives its version, the name of a snapshot is
+/// shown as is
+fn format_resolver(resolver: &str) -> String {
+    match resolver.strip_prefix("ghc-") {
+        Some(ghc_version) => format!("v{}", ghc_version),
+        None => resolver.to_string(),
+    }
+}
+
+fn format_ghc_version(ghc_stdout: &str) -> Option<String> {
+    // ghc --numeric-version output looks like this:
+    // 8.10.7
+    let version = ghc_stdout.trim();
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_resolver() {
+        assert_eq!(format_resolver("lts-18.5"), "lts-18.5");
+        assert_eq!(format_resolver("nightly-2021-08-01"), "nightly-2021-08-01");
+        assert_eq!(format_resolver("ghc-8.10.7"), "v8.10.7");
+    }
+
+    #[test]
+    fn folder_without_haskell_files() {
+        let actual = ModuleRenderer::new("haskell")
+            .cmd("ghc --numeric-version", "8.10.7\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_hs_file() {
+        let actual = ModuleRenderer::new("haskell")
+            .file("Main.hs", "")
+            .cmd("ghc --numeric-version", "8.10.7\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("λ v8.10.7")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_stack_yaml() {
+        let actual = ModuleRenderer::new("haskell")
+            .file("stack.yaml", "resolver: lts-18.5\npackages:\n- .\n")
+            .cmd("ghc --numeric-version", "8.10.7\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("λ lts-18.5")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_stack_yaml_without_resolver() {
+        let actual = ModuleRenderer::new("haskell")
+            .file("stack.yaml", "packages:\n- .\n")
+            .cmd("ghc --numeric-version", "8.10.7\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("λ v8.10.7")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,137 @@
+use super::{Context, Module, RootModuleConfig};
+
+use serde_json as json;
+
+use crate::configs::haxe::HaxeConfig;
+use crate::utils;
+
+/// Creates a module with the current Haxe version
+///
+/// Will display the Haxe version if any of the following criteria are met:
+///     - Current directory contains a `haxe.json`, `haxelib.json` or `.haxerc` file
+///     - Current directory contains a file with the `.hxml` or `.hx` extension
+///
+/// If the project pins a Haxe version in a `.haxerc` file (used by lix and
+/// haxeshim), that version is shown instead of the one from `haxe --version`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_haxe_project = context
+        .try_begin_scan()?
+        .set_files(&["haxe.json", "haxelib.json", ".haxerc"])
+        .set_extensions(&["hxml", "hx"])
+        .is_match();
+
+    if !is_haxe_project {
+        return None;
+    }
+
+    let mut module = context.new_module("haxe");
+    let config = HaxeConfig::try_load(module.config);
+
+    let pinned_version = if config.use_haxerc {
+        utils::read_file(context.current_dir.join(".haxerc"))
+            .ok()
+            .and_then(|haxerc| parse_haxerc_version(&haxerc))
+    } else {
+        None
+    };
+    let haxe_version = match pinned_version {
+        Some(version) => version,
+        None => context.exec_cmd("haxe", &["--version"])?.stdout,
+    };
+    let formatted_version = format_haxe_version(&haxe_version)?;
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn parse_haxerc_version(haxerc: &str) -> Option<String> {
+    // .haxerc looks like this:
+    // {"version": "4.0.5", "resolveLibs": "scoped"}
+    let haxerc: json::Value = json::from_str(haxerc).ok()?;
+    let version = haxerc.get("version")?.as_str()?;
+
+    Some(version.to_string())
+}
+
+fn format_haxe_version(version: &str) -> Option<String> {
+    // `haxe --version` only prints the version, with nightly builds appending
+    // the commit, e.g. "4.1.0-rc.1+ef7e3f5"
+    let version = version.trim();
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_parse_haxerc_version() {
+        assert_eq!(
+            parse_haxerc_version(r#"{"version": "4.0.5", "resolveLibs": "scoped"}"#),
+            Some("4.0.5".to_string())
+        );
+        assert_eq!(parse_haxerc_version(r#"{"resolveLibs": "scoped"}"#), None);
+        assert_eq!(parse_haxerc_version("4.0.5"), None);
+    }
+
+    #[test]
+    fn folder_without_haxe_files() {
+        let actual = ModuleRenderer::new("haxe")
+            .cmd("haxe --version", "4.0.5\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_hxml_file() {
+        let actual = ModuleRenderer::new("haxe")
+            .file("build.hxml", "")
+            .cmd("haxe --version", "4.0.5\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("⌘ v4.0.5")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_haxerc() {
+        let actual = ModuleRenderer::new("haxe")
+            .file(
+                ".haxerc",
+                r#"{"version": "4.1.0", "resolveLibs": "scoped"}"#,
+            )
+            .cmd_output("haxe --version", None)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("⌘ v4.1.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_haxerc_ignored() {
+        let actual = ModuleRenderer::new("haxe")
+            .file(
+                ".haxerc",
+                r#"{"version": "4.1.0", "resolveLibs": "scoped"}"#,
+            )
+            .cmd("haxe --version", "4.0.5\n")
+            .config(toml::toml! {
+                [haxe]
+                use_haxerc = false
+            })
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("⌘ v4.0.5")));
+        assert_eq!(expected, actual);
+    }
+}
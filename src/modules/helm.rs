@@ -0,0 +1,103 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::helm::HelmConfig;
+
+/// Creates a module with the current Helm version
+///
+/// Will display the Helm version if any of the following criteria are met:
+///     - Current directory contains a `Chart.yaml` file
+///     - Current directory contains a `helmfile.yaml` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_helm_project = context
+        .try_begin_scan()?
+        .set_files(&["Chart.yaml", "helmfile.yaml"])
+        .is_match();
+
+    if !is_helm_project {
+        return None;
+    }
+
+    let helm_version = context
+        .exec_cmd("helm", &["version", "--short", "--client"])?
+        .stdout;
+    let formatted_version = format_helm_version(&helm_version)?;
+
+    let mut module = context.new_module("helm");
+    let config = HelmConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_helm_version(helm_stdout: &str) -> Option<String> {
+    // helm version --short --client output looks like this with helm 3:
+    // v3.1.1+gafe7058
+    // and like this with helm 2:
+    // Client: v2.16.0+ge13bc94
+    let version = helm_stdout.trim();
+    let version = version.strip_prefix("Client: ").unwrap_or(version);
+    let version = version.split('+').next()?;
+
+    if version.starts_with('v') {
+        Some(version.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_helm_version() {
+        assert_eq!(
+            format_helm_version("v3.1.1+gafe7058\n"),
+            Some("v3.1.1".to_string())
+        );
+        assert_eq!(
+            format_helm_version("Client: v2.16.0+ge13bc94\n"),
+            Some("v2.16.0".to_string())
+        );
+        assert_eq!(format_helm_version("not helm"), None);
+    }
+
+    #[test]
+    fn folder_without_helm_files() {
+        let actual = ModuleRenderer::new("helm")
+            .cmd("helm version --short --client", "v3.1.1+gafe7058\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_chart_file() {
+        let actual = ModuleRenderer::new("helm")
+            .file("Chart.yaml", "")
+            .cmd("helm version --short --client", "v3.1.1+gafe7058\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::White.bold().paint("⎈ v3.1.1")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_helmfile() {
+        let actual = ModuleRenderer::new("helm")
+            .file("helmfile.yaml", "")
+            .cmd(
+                "helm version --short --client",
+                "Client: v2.16.0+ge13bc94\n",
+            )
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::White.bold().paint("⎈ v2.16.0")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,141 @@
+use std::path::Path;
+
+use super::utils::truncate::truncate;
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::hg_branch::HgBranchConfig;
+use crate::utils;
+
+/// Creates a module with the Mercurial bookmark or branch in the current directory
+///
+/// Will display the active bookmark, or else the branch, if the current
+/// directory is in a Mercurial repository
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("hg_branch");
+    let config = HgBranchConfig::try_load(module.config);
+
+    // Looking for the repository in every parent directory has a cost, so it
+    // is only done once the module has been enabled
+    if config.disabled {
+        log::debug!(
+            "Module \"hg_branch\" is disabled by default, set `disabled = false` to enable it"
+        );
+        return None;
+    }
+
+    let dot_hg = context
+        .current_dir
+        .ancestors()
+        .map(|dir| dir.join(".hg"))
+        .find(|dot_hg| dot_hg.is_dir())?;
+    let branch_name = get_hg_branch_name(&dot_hg);
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    module.create_segment("symbol", &config.symbol);
+
+    let len = if config.truncation_length <= 0 {
+        log::warn!(
+            "\"truncation_length\" should be a positive value, found {}",
+            config.truncation_length
+        );
+        usize::MAX
+    } else {
+        config.truncation_length as usize
+    };
+    let truncated_and_symbol = truncate(&branch_name, len, config.truncation_symbol);
+
+    module.create_segment(
+        "name",
+        &config.branch_name.with_value(&truncated_and_symbol),
+    );
+
+    Some(module)
+}
+
+/// Get the active bookmark, or else the branch, from the `.hg` directory.
+/// Mercurial doesn't write the branch file while on the `default` branch.
+fn get_hg_branch_name(dot_hg: &Path) -> String {
+    let read = |file: &str| {
+        utils::read_file(dot_hg.join(file))
+            .ok()
+            .map(|contents| contents.trim().to_string())
+            .filter(|contents| !contents.is_empty())
+    };
+
+    read("bookmarks.current")
+        .or_else(|| read("branch"))
+        .unwrap_or_else(|| "default".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("hg_branch")
+            .file(".hg/branch", "feature\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn not_in_repository() {
+        let actual = ModuleRenderer::new("hg_branch")
+            .config(toml::toml! {
+                [hg_branch]
+                disabled = false
+            })
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn default_branch() {
+        let actual = ModuleRenderer::new("hg_branch")
+            .dir(".hg")
+            .config(toml::toml! {
+                [hg_branch]
+                disabled = false
+            })
+            .collect();
+
+        let expected = Some(format!("on {} ", Color::Purple.bold().paint(" default")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn named_branch() {
+        let actual = ModuleRenderer::new("hg_branch")
+            .file(".hg/branch", "feature\n")
+            .config(toml::toml! {
+                [hg_branch]
+                disabled = false
+            })
+            .collect();
+
+        let expected = Some(format!("on {} ", Color::Purple.bold().paint(" feature")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn bookmark_with_truncation() {
+        let actual = ModuleRenderer::new("hg_branch")
+            .file(".hg/branch", "feature\n")
+            .file(".hg/bookmarks.current", "my-bookmark")
+            .config(toml::toml! {
+                [hg_branch]
+                disabled = false
+                truncation_length = 5
+            })
+            .collect();
+
+        let expected = Some(format!("on {} ", Color::Purple.bold().paint(" my-bo…")));
+        assert_eq!(expected, actual);
+    }
+}
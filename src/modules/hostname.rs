@@ -1,5 +1,3 @@
-use std::env;
-
 use super::{Context, Module, SegmentConfig};
 use std::ffi::OsString;
 
@@ -11,11 +9,13 @@ use crate::configs::hostname::HostnameConfig;
 /// Will display the hostname if all of the following criteria are met:
 ///     - hostname.disabled is absent or false
 ///     - hostname.ssh_only is false OR the user is currently connected as an SSH session (`$SSH_CONNECTION`)
+///
+/// In an SSH session, it is styled with `ssh_style` if set, to tell remote hosts apart
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("hostname");
     let config: HostnameConfig = HostnameConfig::try_load(module.config);
 
-    let ssh_connection = env::var("SSH_CONNECTION").ok();
+    let ssh_connection = context.get_env("SSH_CONNECTION");
     if config.ssh_only && ssh_connection.is_none() {
         return None;
     }
@@ -42,10 +42,14 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         host.as_ref()
     };
 
-    module.set_style(config.style);
+    let style = match (ssh_connection, config.ssh_style) {
+        (Some(_), Some(ssh_style)) => ssh_style,
+        _ => config.style,
+    };
+    module.set_style(style);
     let hostname_stacked = format!("{}{}{}", config.prefix, host, config.suffix);
     module.create_segment("hostname", &SegmentConfig::new(&hostname_stacked));
-    module.get_prefix().set_value("on ");
+    module.get_prefix().set_value(context.locale().on);
 
     Some(module)
 }
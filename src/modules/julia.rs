@@ -0,0 +1,86 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::julia::JuliaConfig;
+
+/// Creates a module with the current Julia version
+///
+/// Will display the Julia version if any of the following criteria are met:
+///     - Current directory contains a `Project.toml` or `Manifest.toml` file
+///     - Current directory contains a file with the `.jl` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_julia_project = context
+        .try_begin_scan()?
+        .set_files(&["Project.toml", "Manifest.toml"])
+        .set_extensions(&["jl"])
+        .is_match();
+
+    if !is_julia_project {
+        return None;
+    }
+
+    let julia_version = context.exec_cmd("julia", &["--version"])?.stdout;
+    let formatted_version = format_julia_version(&julia_version)?;
+
+    let mut module = context.new_module("julia");
+    let config = JuliaConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_julia_version(julia_stdout: &str) -> Option<String> {
+    // julia --version output looks like this:
+    // julia version 1.6.1
+    let version = julia_stdout.trim().strip_prefix("julia version ")?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_julia_version() {
+        assert_eq!(
+            format_julia_version("julia version 1.6.1\n"),
+            Some("v1.6.1".to_string())
+        );
+        assert_eq!(format_julia_version("not julia"), None);
+    }
+
+    #[test]
+    fn folder_without_julia_files() {
+        let actual = ModuleRenderer::new("julia")
+            .cmd("julia --version", "julia version 1.6.1\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_project_toml() {
+        let actual = ModuleRenderer::new("julia")
+            .file("Project.toml", "name = \"Rocket\"\n")
+            .cmd("julia --version", "julia version 1.6.1\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("ஃ v1.6.1")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_jl_file_and_no_julia() {
+        let actual = ModuleRenderer::new("julia")
+            .file("main.jl", "")
+            .cmd_output("julia --version", None)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+}
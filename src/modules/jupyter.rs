@@ -0,0 +1,115 @@
+use super::{Context, Module, RootModuleConfig};
+
+use serde_json as json;
+
+use crate::configs::jupyter::JupyterConfig;
+use crate::context::has_extension;
+use crate::utils;
+
+/// Creates a module with the Jupyter kernel used by the notebooks in the current directory
+///
+/// Will display the kernel if the current directory contains a file with the
+/// `.ipynb` extension. The kernel is read from the notebook's metadata, so the
+/// Jupyter server doesn't need to be running.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut notebooks: Vec<_> = context
+        .get_dir_files()
+        .ok()?
+        .iter()
+        .filter(|path| has_extension(path, &["ipynb"]))
+        .collect();
+
+    if notebooks.is_empty() {
+        return None;
+    }
+
+    // Directory entries aren't returned in any particular order, so sort them
+    // to show the same kernel every time
+    notebooks.sort();
+    let kernel = notebooks
+        .iter()
+        .filter_map(|path| utils::read_file(path).ok())
+        .find_map(|notebook| parse_kernel_name(&notebook));
+
+    let mut module = context.new_module("jupyter");
+    let config = JupyterConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+
+    if let Some(kernel) = kernel {
+        module.create_segment("kernel", &config.kernel.with_value(&kernel));
+    }
+
+    Some(module)
+}
+
+fn parse_kernel_name(notebook: &str) -> Option<String> {
+    // The kernel is stored in the notebook metadata:
+    // {"metadata": {"kernelspec": {"display_name": "Python 3", "name": "python3"}}}
+    let notebook: json::Value = json::from_str(notebook).ok()?;
+    let kernelspec = notebook.get("metadata")?.get("kernelspec")?;
+    let name = kernelspec
+        .get("display_name")
+        .or_else(|| kernelspec.get("name"))?
+        .as_str()?;
+
+    Some(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const NOTEBOOK: &str = r#"{
+  "cells": [],
+  "metadata": {
+    "kernelspec": {"display_name": "Python 3", "language": "python", "name": "python3"}
+  },
+  "nbformat": 4,
+  "nbformat_minor": 2
+}"#;
+
+    #[test]
+    fn test_parse_kernel_name() {
+        assert_eq!(parse_kernel_name(NOTEBOOK), Some("Python 3".to_string()));
+        assert_eq!(
+            parse_kernel_name(r#"{"metadata": {"kernelspec": {"name": "ir"}}}"#),
+            Some("ir".to_string())
+        );
+        assert_eq!(parse_kernel_name(r#"{"metadata": {}}"#), None);
+        assert_eq!(parse_kernel_name("not json"), None);
+    }
+
+    #[test]
+    fn folder_without_notebooks() {
+        let actual = ModuleRenderer::new("jupyter").file("main.py", "").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_notebook() {
+        let actual = ModuleRenderer::new("jupyter")
+            .file("analysis.ipynb", NOTEBOOK)
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Yellow.bold().paint("🪐 Python 3")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_notebook_without_kernel() {
+        let actual = ModuleRenderer::new("jupyter")
+            .file("analysis.ipynb", r#"{"cells": [], "metadata": {}}"#)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🪐 ")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,121 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::kotlin::KotlinConfig;
+
+/// Creates a module with the current Kotlin version
+///
+/// Will display the Kotlin version if any of the following criteria are met:
+///     - Current directory contains a file with the `.kt` or `.kts` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_kotlin_project = context
+        .try_begin_scan()?
+        .set_extensions(&["kt", "kts"])
+        .is_match();
+
+    if !is_kotlin_project {
+        return None;
+    }
+
+    let mut module = context.new_module("kotlin");
+    let config = KotlinConfig::try_load(module.config);
+
+    // `kotlinc` prints its version to stderr, unlike `kotlin`
+    let output = context.exec_cmd(config.kotlin_binary, &["-version"])?;
+    let kotlin_version = if output.stdout.trim().is_empty() {
+        output.stderr
+    } else {
+        output.stdout
+    };
+    let formatted_version = format_kotlin_version(&kotlin_version)?;
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_kotlin_version(kotlin_output: &str) -> Option<String> {
+    // kotlin -version output looks like this:
+    // Kotlin version 1.4.21-release-411 (JRE 14.0.1+7)
+    //
+    // kotlinc -version output looks like this:
+    // info: kotlinc-jvm 1.4.21 (JRE 14.0.1+7)
+    let kotlin_output = kotlin_output.trim();
+    let version = kotlin_output
+        .strip_prefix("Kotlin version ")
+        .or_else(|| kotlin_output.strip_prefix("info: kotlinc-jvm "))?
+        .split_whitespace()
+        .next()?;
+    let version = version.split("-release").next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use crate::utils::CommandOutput;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_kotlin_version() {
+        assert_eq!(
+            format_kotlin_version("Kotlin version 1.4.21-release-411 (JRE 14.0.1+7)\n"),
+            Some("v1.4.21".to_string())
+        );
+        assert_eq!(
+            format_kotlin_version("info: kotlinc-jvm 1.4.21 (JRE 14.0.1+7)\n"),
+            Some("v1.4.21".to_string())
+        );
+        assert_eq!(format_kotlin_version("not kotlin"), None);
+    }
+
+    #[test]
+    fn folder_without_kotlin_files() {
+        let actual = ModuleRenderer::new("kotlin")
+            .cmd(
+                "kotlin -version",
+                "Kotlin version 1.4.21-release-411 (JRE 14.0.1+7)\n",
+            )
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_kotlin_file() {
+        let actual = ModuleRenderer::new("kotlin")
+            .file("main.kt", "")
+            .cmd(
+                "kotlin -version",
+                "Kotlin version 1.4.21-release-411 (JRE 14.0.1+7)\n",
+            )
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🅺 v1.4.21")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn kotlinc_binary() {
+        let actual = ModuleRenderer::new("kotlin")
+            .file("build.gradle.kts", "")
+            .cmd_output(
+                "kotlinc -version",
+                Some(CommandOutput {
+                    stdout: String::new(),
+                    stderr: "info: kotlinc-jvm 1.4.20 (JRE 14.0.1+7)\n".to_string(),
+                }),
+            )
+            .config(toml::toml! {
+                [kotlin]
+                kotlin_binary = "kotlinc"
+            })
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🅺 v1.4.20")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -9,8 +9,6 @@ use super::{Context, Module, RootModuleConfig};
 use crate::configs::kubernetes::KubernetesConfig;
 use crate::utils;
 
-const KUBERNETES_PREFIX: &str = "on ";
-
 fn get_kube_context(contents: &str) -> Option<(String, String)> {
     let yaml_docs = YamlLoader::load_from_str(&contents).ok()?;
     if yaml_docs.is_empty() {
@@ -43,47 +41,100 @@ fn parse_kubectl_file(filename: &path::PathBuf) -> Option<(String, String)> {
     get_kube_context(&contents)
 }
 
-pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    let kube_cfg = match env::var("KUBECONFIG") {
-        Ok(paths) => env::split_paths(&paths)
+/// Get the current kubernetes context and its namespace, from the files in
+/// `$KUBECONFIG` or from `~/.kube/config`
+pub fn get_current_context(context: &Context) -> Option<(String, String)> {
+    match context.get_env("KUBECONFIG") {
+        Some(paths) => env::split_paths(&paths)
             .filter_map(|filename| parse_kubectl_file(&filename))
             .nth(0),
-        Err(_) => {
+        None => {
             let filename = dirs::home_dir()?.join(".kube").join("config");
             parse_kubectl_file(&filename)
         }
+    }
+}
+
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("kubernetes");
+    let config: KubernetesConfig = KubernetesConfig::try_load(module.config);
+
+    // Parsing the kubeconfig on every prompt has a cost, so it is only done
+    // once the module has been enabled
+    if config.disabled {
+        log::debug!(
+            "Module \"kubernetes\" is disabled by default, set `disabled = false` to enable it"
+        );
+        return None;
     };
 
-    match kube_cfg {
-        Some(kube_cfg) => {
-            let (kube_ctx, kube_ns) = kube_cfg;
-
-            let mut module = context.new_module("kubernetes");
-            let config: KubernetesConfig = KubernetesConfig::try_load(module.config);
-            if config.disabled {
-                return None;
-            };
-
-            module.set_style(config.style);
-            module.get_prefix().set_value(KUBERNETES_PREFIX);
-
-            module.create_segment("symbol", &config.symbol);
-            module.create_segment("context", &config.context.with_value(&kube_ctx));
-            if kube_ns != "" {
-                module.create_segment(
-                    "namespace",
-                    &config.namespace.with_value(&format!(" ({})", kube_ns)),
-                );
-            }
-            Some(module)
-        }
-        None => None,
+    let (kube_ctx, kube_ns) = get_current_context(context)?;
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("context", &config.context.with_value(&kube_ctx));
+    if !kube_ns.is_empty() {
+        module.create_segment(
+            "namespace",
+            &config.namespace.with_value(&format!(" ({})", kube_ns)),
+        );
     }
+
+    Some(module)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const KUBECONFIG: &str = r#"
+apiVersion: v1
+clusters: []
+contexts:
+- context:
+    cluster: test_cluster
+    user: test_user
+    namespace: test_namespace
+  name: test_context
+current-context: test_context
+kind: Config
+preferences: {}
+users: []
+"#;
+
+    #[test]
+    fn disabled_by_default() {
+        let renderer = ModuleRenderer::new("kubernetes").file("kubeconfig", KUBECONFIG);
+        let kubeconfig = renderer.root_path().join("kubeconfig");
+
+        let actual = renderer
+            .env("KUBECONFIG", kubeconfig.to_string_lossy())
+            .collect();
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn context_and_namespace_from_kubeconfig_env() {
+        let renderer = ModuleRenderer::new("kubernetes").file("kubeconfig", KUBECONFIG);
+        let kubeconfig = renderer.root_path().join("kubeconfig");
+
+        let actual = renderer
+            .env("KUBECONFIG", kubeconfig.to_string_lossy())
+            .config(toml::toml! {
+                [kubernetes]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Cyan.bold().paint("☸ test_context (test_namespace)")
+        ));
+        assert_eq!(expected, actual);
+    }
 
     #[test]
     fn parse_empty_config() {
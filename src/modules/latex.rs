@@ -0,0 +1,111 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::latex::LatexConfig;
+
+/// Creates a module with the active TeX distribution
+///
+/// Will display the TeX distribution if any of the following criteria are met:
+///     - Current directory contains a `latexmkrc` or `.latexmkrc` file
+///     - Current directory contains a file with the `.tex` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_latex_project = context
+        .try_begin_scan()?
+        .set_files(&["latexmkrc", ".latexmkrc"])
+        .set_extensions(&["tex"])
+        .is_match();
+
+    if !is_latex_project {
+        return None;
+    }
+
+    let tex_version = context.exec_cmd("tex", &["--version"])?.stdout;
+    let distribution = parse_tex_distribution(&tex_version)?;
+
+    let mut module = context.new_module("latex");
+    let config = LatexConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(distribution));
+
+    Some(module)
+}
+
+fn parse_tex_distribution(tex_stdout: &str) -> Option<&str> {
+    // The first line of `tex --version` names the distribution in parentheses:
+    // TeX 3.141592653 (TeX Live 2023/Debian)
+    // MiKTeX-TeX 4.10 (MiKTeX 22.1)
+    let first_line = tex_stdout.lines().next()?;
+    let start = first_line.find('(')? + 1;
+    let end = first_line.rfind(')')?;
+    let distribution = first_line.get(start..end)?.trim();
+
+    if distribution.is_empty() {
+        None
+    } else {
+        Some(distribution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const TEX_LIVE_VERSION: &str = "TeX 3.141592653 (TeX Live 2023/Debian)
+kpathsea version 6.3.5
+Copyright 2023 D.E. Knuth.
+";
+
+    #[test]
+    fn test_parse_tex_distribution() {
+        assert_eq!(
+            parse_tex_distribution(TEX_LIVE_VERSION),
+            Some("TeX Live 2023/Debian")
+        );
+        assert_eq!(
+            parse_tex_distribution("MiKTeX-TeX 4.10 (MiKTeX 22.1)\n"),
+            Some("MiKTeX 22.1")
+        );
+        assert_eq!(parse_tex_distribution("TeX 3.141592653\n"), None);
+        assert_eq!(parse_tex_distribution(""), None);
+    }
+
+    #[test]
+    fn folder_without_tex_files() {
+        let actual = ModuleRenderer::new("latex")
+            .cmd("tex --version", TEX_LIVE_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_tex_file() {
+        let actual = ModuleRenderer::new("latex")
+            .file("paper.tex", "")
+            .cmd("tex --version", TEX_LIVE_VERSION)
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green.bold().paint("📜 TeX Live 2023/Debian")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_latexmkrc() {
+        let actual = ModuleRenderer::new("latex")
+            .file(".latexmkrc", "")
+            .cmd("tex --version", "MiKTeX-TeX 4.10 (MiKTeX 22.1)\n")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Green.bold().paint("📜 MiKTeX 22.1")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
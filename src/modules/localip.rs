@@ -0,0 +1,179 @@
+use std::fs;
+use std::net::{IpAddr, UdpSocket};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::localip::LocalipConfig;
+
+/// Creates a module with the local IP address of the machine
+///
+/// Will display the address of the interface used to reach the internet, if
+/// `ssh_only` is false or the user is connected as an SSH session. The
+/// addresses are cached for `cache_duration` seconds.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("localip");
+    let config: LocalipConfig = LocalipConfig::try_load(module.config);
+
+    if config.disabled {
+        log::debug!(
+            "Module \"localip\" is disabled by default, set `disabled = false` to enable it"
+        );
+        return None;
+    }
+
+    if config.ssh_only && context.get_env("SSH_CONNECTION").is_none() {
+        return None;
+    }
+
+    let addresses = get_local_addresses(Duration::from_secs(config.cache_duration));
+    let ipv6 = addresses.ipv6.filter(|_| config.ipv6);
+    if addresses.ipv4.is_none() && ipv6.is_none() {
+        log::debug!("Unable to find a local IP address");
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    if let Some(ipv4) = &addresses.ipv4 {
+        module.create_segment("ipv4", &SegmentConfig::new(ipv4));
+    }
+    if let Some(ipv6) = &ipv6 {
+        if addresses.ipv4.is_some() {
+            module.create_segment("separator", &config.separator);
+        }
+        module.create_segment("ipv6", &SegmentConfig::new(ipv6));
+    }
+
+    Some(module)
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct LocalAddresses {
+    ipv4: Option<String>,
+    ipv6: Option<String>,
+}
+
+/// Get the local addresses from the cache if it's recent enough, or look them
+/// up and cache them
+fn get_local_addresses(cache_duration: Duration) -> LocalAddresses {
+    let cache = dirs::cache_dir().map(|dir| dir.join("starship").join("localip"));
+
+    if cache_duration > Duration::from_secs(0) {
+        if let Some(addresses) = cache
+            .as_ref()
+            .and_then(|cache| read_cache(cache, cache_duration))
+        {
+            return addresses;
+        }
+    }
+
+    let addresses = LocalAddresses {
+        ipv4: local_address("0.0.0.0:0", "8.8.8.8:53"),
+        ipv6: local_address("[::]:0", "[2001:4860:4860::8888]:53"),
+    };
+    if let Some(cache) = cache {
+        write_cache(&cache, &addresses);
+    }
+    addresses
+}
+
+/// Get the address of the interface which would be used to reach `remote`
+///
+/// Connecting a UDP socket only picks a route and a local address for it,
+/// without sending anything.
+fn local_address(local: &str, remote: &str) -> Option<String> {
+    let socket = UdpSocket::bind(local).ok()?;
+    socket.connect(remote).ok()?;
+    match socket.local_addr().ok()?.ip() {
+        IpAddr::V4(ip) if ip.is_unspecified() => None,
+        IpAddr::V6(ip) if ip.is_unspecified() => None,
+        ip => Some(ip.to_string()),
+    }
+}
+
+/// Read the cached addresses, one per line, if they were cached less than
+/// `max_age` ago
+fn read_cache(path: &Path, max_age: Duration) -> Option<LocalAddresses> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    let age = SystemTime::now().duration_since(modified).ok()?;
+    if age >= max_age {
+        return None;
+    }
+
+    let contents = fs::read_to_string(path).ok()?;
+    let mut lines = contents.lines().map(|line| match line.trim() {
+        "" => None,
+        address => Some(address.to_string()),
+    });
+    Some(LocalAddresses {
+        ipv4: lines.next()?,
+        ipv6: lines.next()?,
+    })
+}
+
+fn write_cache(path: &Path, addresses: &LocalAddresses) {
+    let contents = format!(
+        "{}\n{}\n",
+        addresses.ipv4.as_deref().unwrap_or_default(),
+        addresses.ipv6.as_deref().unwrap_or_default()
+    );
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, contents));
+    if let Err(error) = result {
+        log::debug!("Unable to cache the local addresses: {}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use std::io;
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("localip")
+            .env("SSH_CONNECTION", "192.168.223.17 36673 192.168.223.229 22")
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn ssh_only() {
+        let actual = ModuleRenderer::new("localip")
+            .config(toml::toml! {
+                [localip]
+                disabled = false
+            })
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn cache_round_trip() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("starship").join("localip");
+        let addresses = LocalAddresses {
+            ipv4: Some(String::from("192.168.1.2")),
+            ipv6: None,
+        };
+
+        write_cache(&path, &addresses);
+        assert_eq!(read_cache(&path, Duration::from_secs(60)), Some(addresses));
+        assert_eq!(read_cache(&path, Duration::from_secs(0)), None);
+        dir.close()
+    }
+
+    #[test]
+    fn cache_missing() {
+        assert_eq!(
+            read_cache(Path::new("/nonexistent/localip"), Duration::from_secs(60)),
+            None
+        );
+    }
+}
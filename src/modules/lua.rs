@@ -0,0 +1,119 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::lua::LuaConfig;
+
+/// Creates a module with the current Lua version
+///
+/// Will display the Lua version if any of the following criteria are met:
+///     - Current directory contains a `.lua-version` file
+///     - Current directory contains a `lua` directory
+///     - Current directory contains a file with the `.lua` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_lua_project = context
+        .try_begin_scan()?
+        .set_files(&[".lua-version"])
+        .set_folders(&["lua"])
+        .set_extensions(&["lua"])
+        .is_match();
+
+    if !is_lua_project {
+        return None;
+    }
+
+    let mut module = context.new_module("lua");
+    let config = LuaConfig::try_load(module.config);
+
+    // Lua prints its version to stderr before 5.4
+    let output = context.exec_cmd(config.lua_binary, &["-v"])?;
+    let lua_version = if output.stdout.trim().is_empty() {
+        output.stderr
+    } else {
+        output.stdout
+    };
+    let formatted_version = format_lua_version(&lua_version)?;
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_lua_version(lua_output: &str) -> Option<String> {
+    // lua -v output looks like this:
+    // Lua 5.4.2  Copyright (C) 1994-2020 Lua.org, PUC-Rio
+    //
+    // luajit -v output looks like this:
+    // LuaJIT 2.1.0-beta3 -- Copyright (C) 2005-2017 Mike Pall. http://luajit.org/
+    let mut words = lua_output.split_whitespace();
+    match words.next()? {
+        "Lua" | "LuaJIT" => Some(format!("v{}", words.next()?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use crate::utils::CommandOutput;
+    use ansi_term::Color;
+
+    const LUA_VERSION: &str = "Lua 5.4.2  Copyright (C) 1994-2020 Lua.org, PUC-Rio\n";
+    const LUAJIT_VERSION: &str =
+        "LuaJIT 2.1.0-beta3 -- Copyright (C) 2005-2017 Mike Pall. http://luajit.org/\n";
+
+    #[test]
+    fn test_format_lua_version() {
+        assert_eq!(format_lua_version(LUA_VERSION), Some("v5.4.2".to_string()));
+        assert_eq!(
+            format_lua_version(LUAJIT_VERSION),
+            Some("v2.1.0-beta3".to_string())
+        );
+        assert_eq!(format_lua_version("not lua"), None);
+    }
+
+    #[test]
+    fn folder_without_lua_files() {
+        let actual = ModuleRenderer::new("lua")
+            .cmd("lua -v", LUA_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_lua_file() {
+        let actual = ModuleRenderer::new("lua")
+            .file("main.lua", "")
+            .cmd_output(
+                "lua -v",
+                Some(CommandOutput {
+                    stdout: String::new(),
+                    stderr: "Lua 5.1.5  Copyright (C) 1994-2012 Lua.org, PUC-Rio\n".to_string(),
+                }),
+            )
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🌙 v5.1.5")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn luajit_binary() {
+        let actual = ModuleRenderer::new("lua")
+            .file(".lua-version", "luajit-2.1\n")
+            .cmd("luajit -v", LUAJIT_VERSION)
+            .config(toml::toml! {
+                [lua]
+                lua_binary = "luajit"
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Blue.bold().paint("🌙 v2.1.0-beta3")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
@@ -12,6 +12,30 @@ fn format_kib(n_kib: u64) -> String {
     display_bytes
 }
 
+/// Format the usage of some memory, as a percentage or as used/total. The
+/// total is 0 when the system doesn't report it, e.g. in some containers.
+fn format_usage(
+    used_kib: u64,
+    total_kib: u64,
+    show_percentage: bool,
+    percent_sign: &str,
+) -> Option<String> {
+    if total_kib == 0 {
+        return None;
+    }
+
+    if show_percentage {
+        let percent_used = (used_kib as f64 / total_kib as f64) * 100.;
+        Some(format!("{:.0}{}", percent_used, percent_sign))
+    } else {
+        Some(format!(
+            "{}/{}",
+            format_kib(used_kib),
+            format_kib(total_kib)
+        ))
+    }
+}
+
 /// Creates a module with system memory usage information
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let mut module = context.new_module("memory_usage");
@@ -19,14 +43,18 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     // TODO: Update when v1.0 printing refactor is implemented to only
     // print escapes in a prompt context.
-    let shell = std::env::var("STARSHIP_SHELL").unwrap_or_default();
+    let shell = context.get_env("STARSHIP_SHELL").unwrap_or_default();
     let percent_sign = match shell.as_str() {
-        "zsh" => "%%", // % is an escape in zsh, see PROMPT in `man zshmisc`
+        "zsh" => "%%",  // % is an escape in zsh, see PROMPT in `man zshmisc`
+        "tcsh" => "%%", // % is an escape in tcsh too, see prompt in `man tcsh`
         "powershell" => "`%",
         _ => "%",
     };
 
     if config.disabled {
+        log::debug!(
+            "Module \"memory_usage\" is disabled by default, set `disabled = false` to enable it"
+        );
         return None;
     }
 
@@ -37,6 +65,10 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     let used_memory_kib = system.get_used_memory();
     let total_memory_kib = system.get_total_memory();
+    if total_memory_kib == 0 {
+        log::debug!("Unable to get the total memory of the system");
+        return None;
+    }
 
     let percent_mem_used = (used_memory_kib as f64 / total_memory_kib as f64) * 100.;
 
@@ -48,36 +80,50 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     let show_percentage = config.show_percentage;
 
-    let ram = if show_percentage {
-        format!("{:.0}{}", percent_mem_used, percent_sign)
-    } else {
-        format!(
-            "{}/{}",
-            format_kib(used_memory_kib),
-            format_kib(total_memory_kib)
-        )
-    };
+    let ram = format_usage(
+        used_memory_kib,
+        total_memory_kib,
+        show_percentage,
+        percent_sign,
+    )?;
     module.create_segment("ram", &config.ram.with_value(&ram));
 
     // swap only shown if enabled and there is swap on the system
-    let total_swap_kib = system.get_total_swap();
-    if config.show_swap && total_swap_kib > 0 {
-        let used_swap_kib = system.get_used_swap();
-        let percent_swap_used = (used_swap_kib as f64 / total_swap_kib as f64) * 100.;
-
-        let swap = if show_percentage {
-            format!("{:.0}{}", percent_swap_used, percent_sign)
-        } else {
-            format!(
-                "{}/{}",
-                format_kib(used_swap_kib),
-                format_kib(total_swap_kib)
-            )
-        };
-
+    let swap = format_usage(
+        system.get_used_swap(),
+        system.get_total_swap(),
+        show_percentage,
+        percent_sign,
+    );
+    if let (true, Some(swap)) = (config.show_swap, swap) {
         module.create_segment("separator", &config.separator);
         module.create_segment("swap", &config.swap.with_value(&swap));
     }
 
     Some(module)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_kib() {
+        assert_eq!(format_kib(512), "512KiB");
+        assert_eq!(format_kib(1024 * 1024), "1024MiB");
+        assert_eq!(format_kib(8 * 1024 * 1024), "8GiB");
+    }
+
+    #[test]
+    fn test_format_usage() {
+        assert_eq!(
+            format_usage(1024 * 1024, 4 * 1024 * 1024, false, "%"),
+            Some("1024MiB/4GiB".to_string())
+        );
+        assert_eq!(
+            format_usage(1024 * 1024, 4 * 1024 * 1024, true, "%%"),
+            Some("25%%".to_string())
+        );
+        assert_eq!(format_usage(0, 0, true, "%"), None);
+    }
+}
@@ -0,0 +1,124 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::meson::MesonConfig;
+use crate::utils;
+
+/// Creates a module with the current Meson project and version
+///
+/// Will display the Meson project and version if any of the following criteria are met:
+///     - Current shell was started by `meson devenv`
+///     - Current directory contains a `meson.build` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    // `meson devenv` sets the name of the project in the environment
+    let project_name = match context.get_env("MESON_PROJECT_NAME") {
+        Some(name) => Some(name),
+        None if context.get_env("MESON_DEVENV").is_some() => None,
+        None => {
+            let is_meson_project = context
+                .try_begin_scan()?
+                .set_files(&["meson.build"])
+                .is_match();
+            if !is_meson_project {
+                return None;
+            }
+
+            utils::read_file(context.current_dir.join("meson.build"))
+                .ok()
+                .and_then(|meson_build| parse_project_name(&meson_build))
+        }
+    };
+
+    let meson_version = context.exec_cmd("meson", &["--version"])?.stdout;
+    let meson_version = meson_version.trim();
+    if meson_version.is_empty() {
+        return None;
+    }
+
+    let mut module = context.new_module("meson");
+    let config = MesonConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    if let Some(project_name) = project_name {
+        module.create_segment(
+            "project",
+            &SegmentConfig::new(&format!("{} ", project_name)),
+        );
+    }
+    module.create_segment(
+        "version",
+        &config.version.with_value(&format!("v{}", meson_version)),
+    );
+
+    Some(module)
+}
+
+/// Get the name of the project declared in a `meson.build` file, which starts
+/// with e.g. `project('rocket', 'c')`
+fn parse_project_name(meson_build: &str) -> Option<String> {
+    let start = meson_build.find("project(")? + "project(".len();
+    let args = meson_build[start..].trim_start();
+    let quote = args.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    let name = &args[1..];
+    let end = name.find(quote)?;
+
+    Some(name[..end].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_parse_project_name() {
+        assert_eq!(
+            parse_project_name("project('rocket', 'c',\n  version : '1.0.0')\n"),
+            Some("rocket".to_string())
+        );
+        assert_eq!(
+            parse_project_name("# Rocket\nproject(\n  'rocket',\n  'cpp',\n)\n"),
+            Some("rocket".to_string())
+        );
+        assert_eq!(parse_project_name("executable('rocket', 'main.c')\n"), None);
+    }
+
+    #[test]
+    fn folder_without_meson_build() {
+        let actual = ModuleRenderer::new("meson")
+            .cmd("meson --version", "0.57.1\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_meson_build() {
+        let actual = ModuleRenderer::new("meson")
+            .file("meson.build", "project('rocket', 'c')\n")
+            .cmd("meson --version", "0.57.1\n")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Blue.bold().paint("🅼 rocket v0.57.1")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn meson_devenv() {
+        let actual = ModuleRenderer::new("meson")
+            .env("MESON_DEVENV", "1")
+            .env("MESON_PROJECT_NAME", "starship")
+            .cmd("meson --version", "0.57.1\n")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Blue.bold().paint("🅼 starship v0.57.1")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
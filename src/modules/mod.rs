@@ -1,30 +1,90 @@
 // While adding out new module add out module to src/module.rs ALL_MODULES const array also.
 mod aws;
+mod azure;
+mod bazel;
+mod buf;
+mod bun;
 mod character;
-mod cmd_duration;
+mod cmake;
+pub(crate) mod cmd_duration;
+mod cobol;
 mod conda;
+mod container;
+mod crystal;
+mod dart;
+mod deno;
 mod directory;
+mod direnv;
 mod dotnet;
+mod elm;
 mod env_var;
+mod erlang;
+mod fill;
+mod fossil_branch;
+mod gcloud;
 mod git_branch;
+mod git_commit;
+mod git_metrics;
+mod git_remote;
 mod git_state;
 mod git_status;
+mod gleam;
+mod godot;
 mod golang;
+mod gradle;
+mod haskell;
+mod haxe;
+mod helm;
+mod hg_branch;
 mod hostname;
 mod java;
 mod jobs;
-mod kubernetes;
+mod julia;
+mod jupyter;
+mod kotlin;
+pub(crate) mod kubernetes;
+mod latex;
 mod line_break;
+mod localip;
+mod lua;
 mod memory_usage;
+mod meson;
+mod nim;
 mod nix_shell;
 mod nodejs;
+mod nomad;
+mod ocaml;
+mod odin;
+mod openstack;
+mod os;
 mod package;
+mod php;
+mod pixi;
+mod pulumi;
+mod purescript;
 mod python;
+mod quarto;
+mod raku;
+mod rlang;
 mod ruby;
 mod rust;
+mod scala;
+mod shell;
+mod singularity;
+mod solidity;
+mod spack;
+mod status;
+mod sudo;
+mod svn;
+mod terraform;
 mod time;
+mod typst;
+mod unity;
+mod unreal;
 mod username;
 mod utils;
+mod vagrant;
+mod vlang;
 
 #[cfg(feature = "battery")]
 mod battery;
@@ -38,32 +98,92 @@ pub fn handle<'a>(module: &str, context: &'a Context) -> Option<Module<'a>> {
         // Keep these ordered alphabetically.
         // Default ordering is handled in configs/mod.rs
         "aws" => aws::module(context),
+        "azure" => azure::module(context),
         #[cfg(feature = "battery")]
         "battery" => battery::module(context),
+        "bazel" => bazel::module(context),
+        "buf" => buf::module(context),
+        "bun" => bun::module(context),
         "character" => character::module(context),
+        "cmake" => cmake::module(context),
         "cmd_duration" => cmd_duration::module(context),
+        "cobol" => cobol::module(context),
         "conda" => conda::module(context),
+        "container" => container::module(context),
+        "crystal" => crystal::module(context),
+        "dart" => dart::module(context),
+        "deno" => deno::module(context),
         "directory" => directory::module(context),
+        "direnv" => direnv::module(context),
         "dotnet" => dotnet::module(context),
+        "elm" => elm::module(context),
         "env_var" => env_var::module(context),
+        "erlang" => erlang::module(context),
+        "fill" => fill::module(context),
+        "fossil_branch" => fossil_branch::module(context),
+        "gcloud" => gcloud::module(context),
         "git_branch" => git_branch::module(context),
+        "git_commit" => git_commit::module(context),
+        "git_metrics" => git_metrics::module(context),
+        "git_remote" => git_remote::module(context),
         "git_state" => git_state::module(context),
         "git_status" => git_status::module(context),
+        "gleam" => gleam::module(context),
+        "godot" => godot::module(context),
         "golang" => golang::module(context),
+        "gradle" => gradle::module(context),
+        "haskell" => haskell::module(context),
+        "haxe" => haxe::module(context),
+        "helm" => helm::module(context),
+        "hg_branch" => hg_branch::module(context),
         "hostname" => hostname::module(context),
         "java" => java::module(context),
         "jobs" => jobs::module(context),
+        "julia" => julia::module(context),
+        "jupyter" => jupyter::module(context),
+        "kotlin" => kotlin::module(context),
         "kubernetes" => kubernetes::module(context),
+        "latex" => latex::module(context),
         "line_break" => line_break::module(context),
+        "localip" => localip::module(context),
+        "lua" => lua::module(context),
         "memory_usage" => memory_usage::module(context),
+        "meson" => meson::module(context),
+        "nim" => nim::module(context),
         "nix_shell" => nix_shell::module(context),
         "nodejs" => nodejs::module(context),
+        "nomad" => nomad::module(context),
+        "ocaml" => ocaml::module(context),
+        "odin" => odin::module(context),
+        "openstack" => openstack::module(context),
+        "os" => os::module(context),
         "package" => package::module(context),
+        "php" => php::module(context),
+        "pixi" => pixi::module(context),
+        "pulumi" => pulumi::module(context),
+        "purescript" => purescript::module(context),
         "python" => python::module(context),
+        "quarto" => quarto::module(context),
+        "raku" => raku::module(context),
+        "rlang" => rlang::module(context),
         "ruby" => ruby::module(context),
         "rust" => rust::module(context),
+        "scala" => scala::module(context),
+        "shell" => shell::module(context),
+        "singularity" => singularity::module(context),
+        "solidity" => solidity::module(context),
+        "spack" => spack::module(context),
+        "status" => status::module(context),
+        "sudo" => sudo::module(context),
+        "svn" => svn::module(context),
+        "terraform" => terraform::module(context),
         "time" => time::module(context),
+        "typst" => typst::module(context),
+        "unity" => unity::module(context),
+        "unreal" => unreal::module(context),
         "username" => username::module(context),
+        "vagrant" => vagrant::module(context),
+        "vlang" => vlang::module(context),
         _ => {
             eprintln!("Error: Unknown module {}. Use starship module --list to list out all supported modules.", module);
             None
@@ -0,0 +1,92 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::nim::NimConfig;
+
+/// Creates a module with the current Nim version
+///
+/// Will display the Nim version if any of the following criteria are met:
+///     - Current directory contains a `nim.cfg` file
+///     - Current directory contains a file with the `.nim`, `.nims` or `.nimble` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_nim_project = context
+        .try_begin_scan()?
+        .set_files(&["nim.cfg"])
+        .set_extensions(&["nim", "nims", "nimble"])
+        .is_match();
+
+    if !is_nim_project {
+        return None;
+    }
+
+    let nim_version = context.exec_cmd("nim", &["--version"])?.stdout;
+    let formatted_version = format_nim_version(&nim_version)?;
+
+    let mut module = context.new_module("nim");
+    let config = NimConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_nim_version(nim_stdout: &str) -> Option<String> {
+    // nim --version output looks like this:
+    // Nim Compiler Version 1.4.8 [Linux: amd64]
+    // Compiled at 2021-05-25
+    // Copyright (c) 2006-2021 by Andreas Rumpf
+    let version = nim_stdout
+        .lines()
+        .next()?
+        .strip_prefix("Nim Compiler Version ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const NIM_VERSION: &str = "Nim Compiler Version 1.4.8 [Linux: amd64]\nCompiled at 2021-05-25\nCopyright (c) 2006-2021 by Andreas Rumpf\n";
+
+    #[test]
+    fn test_format_nim_version() {
+        assert_eq!(format_nim_version(NIM_VERSION), Some("v1.4.8".to_string()));
+        assert_eq!(format_nim_version("not nim"), None);
+    }
+
+    #[test]
+    fn folder_without_nim_files() {
+        let actual = ModuleRenderer::new("nim")
+            .cmd("nim --version", NIM_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_nimble_file() {
+        let actual = ModuleRenderer::new("nim")
+            .file("rocket.nimble", "")
+            .cmd("nim --version", NIM_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("👑 v1.4.8")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_nim_file_and_no_compiler() {
+        let actual = ModuleRenderer::new("nim")
+            .file("main.nim", "")
+            .cmd_output("nim --version", None)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+}
@@ -1,5 +1,3 @@
-use std::env;
-
 use super::{Context, Module, RootModuleConfig, SegmentConfig};
 
 use crate::configs::nix_shell::NixShellConfig;
@@ -12,10 +10,15 @@ use crate::configs::nix_shell::NixShellConfig;
 /// The module will use the `$IN_NIX_SHELL` and `$name` environment variable to
 /// determine if it's inside a nix-shell and the name of it.
 ///
+/// `nix shell` doesn't set `$IN_NIX_SHELL`. With `heuristic` enabled, a shell
+/// whose `$PATH` contains a directory of the nix store is assumed to be one.
+///
 /// The following options are availables:
 ///     - use_name   (bool)   // print the name of the nix-shell
 ///     - impure_msg (string) // change the impure msg
 ///     - pure_msg (string)   // change the pure msg
+///     - unknown_msg (string) // change the msg when found by the heuristic
+///     - heuristic (bool)    // look for the nix store in $PATH
 ///
 /// Will display the following:
 ///     - name (pure)    // use_name == true in a pure nix-shell
@@ -28,17 +31,18 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     module.set_style(config.style);
 
-    let shell_type = env::var("IN_NIX_SHELL").ok()?;
-    let shell_type_segment: SegmentConfig = match shell_type.as_ref() {
-        "1" | "impure" => config.impure_msg,
-        "pure" => config.pure_msg,
+    let shell_type = context.get_env("IN_NIX_SHELL");
+    let shell_type_segment: SegmentConfig = match shell_type.as_deref() {
+        Some("1") | Some("impure") => config.impure_msg,
+        Some("pure") => config.pure_msg,
+        None if config.heuristic && is_nix_store_in_path(context) => config.unknown_msg,
         _ => {
             return None;
         }
     };
 
     if config.use_name {
-        if let Ok(name) = env::var("name") {
+        if let Some(name) = context.get_env("name") {
             module.create_segment(
                 "nix_shell",
                 &shell_type_segment.with_value(&format!("{} ({})", name, shell_type_segment.value)),
@@ -52,3 +56,12 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     Some(module)
 }
+
+/// Check if `$PATH` contains a directory of the nix store, as added by `nix shell`
+fn is_nix_store_in_path(context: &Context) -> bool {
+    let path = match context.get_env("PATH") {
+        Some(path) => path,
+        None => return false,
+    };
+    std::env::split_paths(&path).any(|dir| dir.starts_with("/nix/store"))
+}
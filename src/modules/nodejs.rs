@@ -1,8 +1,8 @@
-use std::process::Command;
-
 use super::{Context, Module, RootModuleConfig, SegmentConfig};
 
 use crate::configs::nodejs::NodejsConfig;
+use crate::utils;
+use semver::{Version, VersionReq};
 
 /// Creates a module with the current Node.js version
 ///
@@ -10,6 +10,12 @@ use crate::configs::nodejs::NodejsConfig;
 ///     - Current directory contains a `.js` file
 ///     - Current directory contains a `package.json` file
 ///     - Current directory contains a `node_modules` directory
+///
+/// Deno projects without a `package.json` file are left to the `deno` module,
+/// and Bun projects to the `bun` module.
+///
+/// The version is shown in the `not_capable_style` when it doesn't satisfy the
+/// `engines.node` range of the `package.json` file.
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     let is_js_project = context
         .try_begin_scan()?
@@ -18,11 +24,11 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         .set_folders(&["node_modules"])
         .is_match();
 
-    if !is_js_project {
+    if !is_js_project || is_deno_project(context) || is_bun_project(context) {
         return None;
     }
 
-    match get_node_version() {
+    match get_node_version(context) {
         Some(node_version) => {
             let mut module = context.new_module("nodejs");
             let config: NodejsConfig = NodejsConfig::try_load(module.config);
@@ -30,8 +36,16 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
             module.set_style(config.style);
 
             let formatted_version = node_version.trim();
+            let is_capable = match get_engines_version(context) {
+                Some(engines_version) => check_engines_version(formatted_version, &engines_version),
+                None => true,
+            };
+
             module.create_segment("symbol", &config.symbol);
-            module.create_segment("version", &SegmentConfig::new(formatted_version));
+            let version = module.create_segment("version", &SegmentConfig::new(formatted_version));
+            if !is_capable {
+                version.set_style(config.not_capable_style);
+            }
 
             Some(module)
         }
@@ -39,9 +53,145 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 }
 
-fn get_node_version() -> Option<String> {
-    match Command::new("node").arg("--version").output() {
-        Ok(output) => Some(String::from_utf8(output.stdout).unwrap()),
-        Err(_) => None,
+fn is_deno_project(context: &Context) -> bool {
+    let has_deno_config = context
+        .try_begin_scan()
+        .map(|scan| scan.set_files(&["deno.json", "deno.jsonc"]).is_match())
+        .unwrap_or(false);
+
+    has_deno_config && !context.current_dir.join("package.json").is_file()
+}
+
+fn is_bun_project(context: &Context) -> bool {
+    context
+        .try_begin_scan()
+        .map(|scan| {
+            scan.set_files(&["bun.lockb", "bun.lock", "bunfig.toml"])
+                .is_match()
+        })
+        .unwrap_or(false)
+}
+
+fn get_node_version(context: &Context) -> Option<String> {
+    context
+        .exec_cmd("node", &["--version"])
+        .map(|output| output.stdout)
+}
+
+fn get_engines_version(context: &Context) -> Option<String> {
+    let json_str = utils::read_file(context.current_dir.join("package.json")).ok()?;
+    let package_json: serde_json::Value = serde_json::from_str(&json_str).ok()?;
+    let engines_version = package_json.get("engines")?.get("node")?.as_str()?;
+    Some(engines_version.to_string())
+}
+
+/// Check if a node version satisfies an npm range, e.g. `>=10 <12 || ^14.17.0`
+///
+/// Ranges which can't be parsed are assumed to be satisfied, so that the
+/// version isn't shown as not capable by mistake.
+fn check_engines_version(node_version: &str, engines_version: &str) -> bool {
+    let version = match Version::parse(node_version.trim_start_matches('v')) {
+        Ok(version) => version,
+        Err(_) => return true,
+    };
+
+    let requirements = engines_version
+        .split("||")
+        .map(parse_npm_range)
+        .collect::<Option<Vec<VersionReq>>>();
+
+    match requirements {
+        Some(requirements) => requirements.iter().any(|req| req.matches(&version)),
+        None => true,
+    }
+}
+
+/// npm separates the comparators of a range with spaces, e.g. `>= 10 <12`,
+/// where the semver crate expects commas, e.g. `>=10,<12`
+fn parse_npm_range(range: &str) -> Option<VersionReq> {
+    let mut comparators: Vec<String> = Vec::new();
+    for word in range.split_whitespace() {
+        match comparators.last_mut() {
+            Some(operator) if operator.chars().all(|c| "<>=~^".contains(c)) => {
+                operator.push_str(word)
+            }
+            _ => comparators.push(word.to_string()),
+        }
+    }
+
+    VersionReq::parse(&comparators.join(",")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::{ANSIStrings, Color};
+
+    #[test]
+    fn deno_project() {
+        let actual = ModuleRenderer::new("nodejs")
+            .file("deno.json", "{}")
+            .file("main.js", "")
+            .cmd("node --version", "v12.0.0\n")
+            .collect();
+        assert_eq!(actual, None);
+
+        let actual = ModuleRenderer::new("nodejs")
+            .file("deno.json", "{}")
+            .file("package.json", "{}")
+            .cmd("node --version", "v12.0.0\n")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ v12.0.0")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn bun_project() {
+        let actual = ModuleRenderer::new("nodejs")
+            .file("bun.lockb", "")
+            .file("package.json", "{}")
+            .cmd("node --version", "v12.0.0\n")
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn test_check_engines_version() {
+        assert!(check_engines_version("v12.0.0", ">=12"));
+        assert!(check_engines_version("v12.0.0", "^12.0.0"));
+        assert!(check_engines_version("v12.0.0", "12.x"));
+        assert!(check_engines_version("v10.4.1", ">= 10 <12"));
+        assert!(check_engines_version("v14.17.3", ">=10 <12 || ^14.17.0"));
+        assert!(!check_engines_version("v12.0.0", ">=10 <12 || ^14.17.0"));
+        assert!(!check_engines_version("v12.0.0", ">=14"));
+        // Ranges which can't be parsed are ignored
+        assert!(check_engines_version("v12.0.0", "latest"));
+    }
+
+    #[test]
+    fn engines_version_satisfied() {
+        let actual = ModuleRenderer::new("nodejs")
+            .file("package.json", r#"{"engines": {"node": ">=12.0.0"}}"#)
+            .cmd("node --version", "v12.0.0\n")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Green.bold().paint("⬢ v12.0.0")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn engines_version_not_satisfied() {
+        let actual = ModuleRenderer::new("nodejs")
+            .file("package.json", r#"{"engines": {"node": "^14.17.0"}}"#)
+            .cmd("node --version", "v12.0.0\n")
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            ANSIStrings(&[
+                Color::Green.bold().paint("⬢ "),
+                Color::Red.bold().paint("v12.0.0")
+            ])
+        ));
+        assert_eq!(actual, expected);
     }
 }
@@ -0,0 +1,72 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::nomad::NomadConfig;
+
+/// Creates a module with the Nomad namespace and region used by the `nomad` CLI
+///
+/// Will display the namespace and region if `$NOMAD_NAMESPACE` or
+/// `$NOMAD_REGION` is set.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let namespace = context.get_env("NOMAD_NAMESPACE");
+    let region = context.get_env("NOMAD_REGION");
+    if namespace.is_none() && region.is_none() {
+        return None;
+    }
+
+    let mut module = context.new_module("nomad");
+    let config = NomadConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    module.create_segment("symbol", &config.symbol);
+    if let Some(namespace) = &namespace {
+        module.create_segment("namespace", &config.namespace.with_value(namespace));
+    }
+    if let Some(region) = region {
+        let region = match namespace {
+            Some(_) => format!(" ({})", region),
+            None => region,
+        };
+        module.create_segment("region", &config.region.with_value(&region));
+    }
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn no_env_set() {
+        let actual = ModuleRenderer::new("nomad").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn namespace_and_region() {
+        let actual = ModuleRenderer::new("nomad")
+            .env("NOMAD_NAMESPACE", "web")
+            .env("NOMAD_REGION", "eu-west")
+            .collect();
+
+        let expected = Some(format!(
+            "on {} ",
+            Color::Green.bold().paint("🅽 web (eu-west)")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn region_only() {
+        let actual = ModuleRenderer::new("nomad")
+            .env("NOMAD_REGION", "eu-west")
+            .collect();
+
+        let expected = Some(format!("on {} ", Color::Green.bold().paint("🅽 eu-west")));
+        assert_eq!(expected, actual);
+    }
+}
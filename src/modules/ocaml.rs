@@ -0,0 +1,132 @@
+use std::path::Path;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::ocaml::OCamlConfig;
+
+/// Creates a module with the current OCaml version and opam switch
+///
+/// Will display the OCaml version if any of the following criteria are met:
+///     - Current directory contains a `dune-project`, `dune` or `.merlin` file
+///     - Current directory contains a file with the `.opam`, `.ml` or `.mli` extension
+///     - Current directory contains an `_opam` directory
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_ocaml_project = context
+        .try_begin_scan()?
+        .set_files(&["dune-project", "dune", ".merlin"])
+        .set_extensions(&["opam", "ml", "mli"])
+        .set_folders(&["_opam"])
+        .is_match();
+
+    if !is_ocaml_project {
+        return None;
+    }
+
+    let ocaml_version = context.exec_cmd("ocaml", &["-vnum"])?.stdout;
+    let formatted_version = format_ocaml_version(&ocaml_version)?;
+
+    let mut module = context.new_module("ocaml");
+    let config = OCamlConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    // opam sets the prefix of the active switch in the environment, which is
+    // much faster than running `opam switch show`
+    if let Some(switch) = context
+        .get_env("OPAM_SWITCH_PREFIX")
+        .and_then(|prefix| get_switch_name(&prefix, &config))
+    {
+        module.create_segment("switch", &SegmentConfig::new(&format!(" ({})", switch)));
+    }
+
+    Some(module)
+}
+
+/// Get the name of an opam switch from its prefix
+///
+/// The prefix of a global switch is in the opam root, e.g. `~/.opam/default`,
+/// and the prefix of a local switch is the `_opam` directory of a project,
+/// which is named after the directory of the project.
+fn get_switch_name(prefix: &str, config: &OCamlConfig) -> Option<String> {
+    let prefix = Path::new(prefix);
+    let name = prefix.file_name()?.to_str()?;
+
+    if name == "_opam" {
+        let project = prefix.parent()?.file_name()?.to_str()?;
+        Some(format!("{}{}", config.local_switch_indicator, project))
+    } else {
+        Some(format!("{}{}", config.global_switch_indicator, name))
+    }
+}
+
+fn format_ocaml_version(ocaml_stdout: &str) -> Option<String> {
+    // ocaml -vnum output looks like this:
+    // 4.12.0
+    let version = ocaml_stdout.trim();
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_get_switch_name() {
+        let config = OCamlConfig::new();
+        assert_eq!(
+            get_switch_name("/home/astronaut/.opam/default", &config),
+            Some("default".to_string())
+        );
+        assert_eq!(
+            get_switch_name("/home/astronaut/rocket/_opam", &config),
+            Some("*rocket".to_string())
+        );
+    }
+
+    #[test]
+    fn folder_without_ocaml_files() {
+        let actual = ModuleRenderer::new("ocaml")
+            .cmd("ocaml -vnum", "4.12.0\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_dune_project() {
+        let actual = ModuleRenderer::new("ocaml")
+            .file("dune-project", "(lang dune 2.8)\n")
+            .cmd("ocaml -vnum", "4.12.0\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐫 v4.12.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_opam_switch() {
+        let actual = ModuleRenderer::new("ocaml")
+            .file("main.ml", "")
+            .cmd("ocaml -vnum", "4.12.0\n")
+            .env("OPAM_SWITCH_PREFIX", "/home/astronaut/rocket/_opam")
+            .config(toml::toml! {
+                [ocaml]
+                local_switch_indicator = "local:"
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Yellow.bold().paint("🐫 v4.12.0 (local:rocket)")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,119 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::odin::OdinConfig;
+
+/// Creates a module with the current Odin compiler version
+///
+/// Will display the Odin version if any of the following criteria are met:
+///     - Current directory contains an `ols.json` file
+///     - Current directory contains a file with the `.odin` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_odin_project = context
+        .try_begin_scan()?
+        .set_files(&["ols.json"])
+        .set_extensions(&["odin"])
+        .is_match();
+
+    if !is_odin_project {
+        return None;
+    }
+
+    let mut module = context.new_module("odin");
+    let config = OdinConfig::try_load(module.config);
+
+    let odin_version = context.exec_cmd("odin", &["version"])?.stdout;
+    let formatted_version = format_odin_version(&odin_version, config.show_commit)?;
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_odin_version(odin_stdout: &str, show_commit: bool) -> Option<String> {
+    // Odin doesn't use semantic versioning, `odin version` output looks like this:
+    // odin version dev-2023-07:d9a5d9e4
+    // Older releases don't include the commit hash:
+    // odin version dev-2021-03
+    let (_, version) = odin_stdout.split_once("odin version ")?;
+    let version = version.trim();
+
+    if version.is_empty() {
+        return None;
+    }
+
+    if show_commit {
+        Some(version.to_string())
+    } else {
+        // return "dev-2023-07"
+        version.split(':').next().map(String::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_odin_version() {
+        let input = "odin version dev-2023-07:d9a5d9e4\n";
+        assert_eq!(
+            format_odin_version(input, false),
+            Some("dev-2023-07".to_string())
+        );
+        assert_eq!(
+            format_odin_version(input, true),
+            Some("dev-2023-07:d9a5d9e4".to_string())
+        );
+        assert_eq!(
+            format_odin_version("odin version dev-2021-03\n", true),
+            Some("dev-2021-03".to_string())
+        );
+        assert_eq!(format_odin_version("odin version \n", false), None);
+        assert_eq!(format_odin_version("", false), None);
+    }
+
+    #[test]
+    fn folder_without_odin_files() {
+        let actual = ModuleRenderer::new("odin")
+            .cmd("odin version", "odin version dev-2023-07:d9a5d9e4\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_odin_file() {
+        let actual = ModuleRenderer::new("odin")
+            .file("main.odin", "")
+            .cmd("odin version", "odin version dev-2023-07:d9a5d9e4\n")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Blue.bold().paint("Ø dev-2023-07")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_ols_json_showing_commit() {
+        let actual = ModuleRenderer::new("odin")
+            .file("ols.json", "{}")
+            .cmd("odin version", "odin version dev-2023-07:d9a5d9e4\n")
+            .config(toml::toml! {
+                [odin]
+                show_commit = true
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Blue.bold().paint("Ø dev-2023-07:d9a5d9e4")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
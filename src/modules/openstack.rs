@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+
+use yaml_rust::YamlLoader;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::openstack::OpenStackConfig;
+use crate::utils;
+
+/// Get the project of a cloud from the contents of a `clouds.yaml` file
+fn get_project(contents: &str, cloud: &str) -> Option<String> {
+    let yaml_docs = YamlLoader::load_from_str(contents).ok()?;
+    let project = yaml_docs.first()?["clouds"][cloud]["auth"]["project_name"].as_str()?;
+
+    Some(project.to_string())
+}
+
+/// The `clouds.yaml` files, in the order the OpenStack clients look for them
+fn get_clouds_files(context: &Context) -> Vec<PathBuf> {
+    let mut files = vec![context.current_dir.join("clouds.yaml")];
+    if let Some(home) = dirs::home_dir() {
+        files.push(home.join(".config").join("openstack").join("clouds.yaml"));
+    }
+    files.push(PathBuf::from("/etc/openstack/clouds.yaml"));
+    files
+}
+
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("openstack");
+    let config: OpenStackConfig = OpenStackConfig::try_load(module.config);
+
+    let cloud = context.get_env("OS_CLOUD")?;
+    let project = context.get_env("OS_PROJECT_NAME").or_else(|| {
+        get_clouds_files(context)
+            .iter()
+            .filter_map(|file| utils::read_file(file).ok())
+            .find_map(|contents| get_project(&contents, &cloud))
+    });
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("cloud", &config.cloud.with_value(&cloud));
+    if let Some(project) = project {
+        module.create_segment(
+            "project",
+            &config.project.with_value(&format!("({})", project)),
+        );
+    }
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const CLOUDS: &str = "\
+clouds:
+  corp:
+    auth:
+      auth_url: https://identity.example.com
+      project_name: testproject
+  lab:
+    auth:
+      auth_url: https://lab.example.com
+";
+
+    #[test]
+    fn parse_project() {
+        assert_eq!(Some("testproject".to_string()), get_project(CLOUDS, "corp"));
+        assert_eq!(None, get_project(CLOUDS, "lab"));
+        assert_eq!(None, get_project(CLOUDS, "missing"));
+        assert_eq!(None, get_project("", "corp"));
+    }
+
+    #[test]
+    fn no_cloud() {
+        let actual = ModuleRenderer::new("openstack")
+            .file("clouds.yaml", CLOUDS)
+            .collect();
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn cloud_and_project_from_clouds_yaml() {
+        let actual = ModuleRenderer::new("openstack")
+            .file("clouds.yaml", CLOUDS)
+            .env("OS_CLOUD", "corp")
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow.bold().paint("☁️  corp(testproject)")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn project_from_env() {
+        let actual = ModuleRenderer::new("openstack")
+            .file("clouds.yaml", CLOUDS)
+            .env("OS_CLOUD", "corp")
+            .env("OS_PROJECT_NAME", "otherproject")
+            .collect();
+        let expected = Some(format!(
+            "on {} ",
+            Color::Yellow.bold().paint("☁️  corp(otherproject)")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
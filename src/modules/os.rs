@@ -0,0 +1,147 @@
+use os_info::Type;
+
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::os::OsConfig;
+
+/// The operating systems with a default symbol: their key in `[os.symbols]`,
+/// which is their name in `os_info`, their displayed name and their symbol
+const OS_TYPES: &[(&str, &str, &str)] = &[
+    ("Alpine", "Alpine Linux", "🏔️ "),
+    ("Amazon", "Amazon Linux", "🙂 "),
+    ("Android", "Android", "🤖 "),
+    ("Arch", "Arch Linux", "🎗️ "),
+    ("CentOS", "CentOS", "💠 "),
+    ("Debian", "Debian", "🌀 "),
+    ("Fedora", "Fedora", "🎩 "),
+    ("FreeBSD", "FreeBSD", "😈 "),
+    ("Gentoo", "Gentoo", "🗜️ "),
+    ("Linux", "Linux", "🐧 "),
+    ("Macos", "macOS", "🍎 "),
+    ("Manjaro", "Manjaro", "🥭 "),
+    ("Mint", "Linux Mint", "🌿 "),
+    ("NixOS", "NixOS", "❄️ "),
+    ("openSUSE", "openSUSE", "🦎 "),
+    ("Pop", "Pop!_OS", "🍭 "),
+    ("Raspbian", "Raspbian", "🍓 "),
+    ("Redhat", "Red Hat Linux", "🎩 "),
+    ("RedHatEnterprise", "Red Hat Enterprise Linux", "🎩 "),
+    ("SUSE", "SUSE Linux Enterprise", "🦎 "),
+    ("Ubuntu", "Ubuntu", "🎯 "),
+    ("Unknown", "Unknown", "❓ "),
+    ("Windows", "Windows", "🪟 "),
+];
+
+/// Creates a module with the symbol of the current operating system
+///
+/// The operating system, and the distribution on Linux, are detected by the
+/// `os_info` crate.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("os");
+    let config: OsConfig = OsConfig::try_load(module.config);
+
+    if config.disabled {
+        log::debug!("Module \"os\" is disabled by default, set `disabled = false` to enable it");
+        return None;
+    }
+
+    let (key, name, default_symbol) = get_os_type(os_info::get().os_type());
+    let symbol = config.symbols.get(&key).copied().unwrap_or(default_symbol);
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+    module.create_segment("symbol", &SegmentConfig::new(symbol));
+    if config.show_name {
+        module.create_segment("name", &SegmentConfig::new(&name));
+    }
+
+    Some(module)
+}
+
+/// Get the key, name and default symbol of an operating system
+///
+/// The systems without a symbol of their own, e.g. the Linux distributions
+/// missing from `OS_TYPES`, get the one of Linux or the unknown one.
+fn get_os_type(os_type: Type) -> (String, String, &'static str) {
+    let key = format!("{:?}", os_type);
+    if let Some((_, name, symbol)) = OS_TYPES.iter().find(|(os_key, _, _)| *os_key == key) {
+        return (key, name.to_string(), symbol);
+    }
+
+    let fallback = if cfg!(target_os = "linux") {
+        "🐧 "
+    } else {
+        "❓ "
+    };
+    (key, os_type.to_string(), fallback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn os_types() {
+        assert_eq!(
+            get_os_type(Type::Ubuntu),
+            ("Ubuntu".to_string(), "Ubuntu".to_string(), "🎯 ")
+        );
+        assert_eq!(
+            get_os_type(Type::RedHatEnterprise),
+            (
+                "RedHatEnterprise".to_string(),
+                "Red Hat Enterprise Linux".to_string(),
+                "🎩 "
+            )
+        );
+        assert_eq!(
+            get_os_type(Type::Macos),
+            ("Macos".to_string(), "macOS".to_string(), "🍎 ")
+        );
+    }
+
+    #[test]
+    fn os_type_without_symbol() {
+        let (key, name, _) = get_os_type(Type::Void);
+        assert_eq!(key, "Void");
+        assert_eq!(name, Type::Void.to_string());
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("os").collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn default_symbol() {
+        let (_, _, symbol) = get_os_type(os_info::get().os_type());
+        let actual = ModuleRenderer::new("os")
+            .config(toml::toml! {
+                [os]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::White.bold().paint(symbol)));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn symbol_and_name_from_config() {
+        let (key, name, _) = get_os_type(os_info::get().os_type());
+        let config = format!(
+            "[os]\ndisabled = false\nshow_name = true\n[os.symbols]\n{} = \"OS \"\n",
+            key
+        );
+        let actual = ModuleRenderer::new("os")
+            .config(toml::from_str(&config).unwrap())
+            .collect();
+        let expected = Some(format!(
+            "{} ",
+            Color::White.bold().paint(format!("OS {}", name))
+        ));
+        assert_eq!(actual, expected);
+    }
+}
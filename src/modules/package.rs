@@ -1,43 +1,254 @@
+use std::path::PathBuf;
+
 use super::{Context, Module};
+use crate::context::{has_extension, path_has_name};
 use crate::utils;
 
 use serde_json as json;
 use toml;
+use yaml_rust::YamlLoader;
 
 use super::{RootModuleConfig, SegmentConfig};
 use crate::configs::package::PackageConfig;
 
 /// Creates a module with the current package version
 ///
-/// Will display if a version is defined for your Node.js or Rust project (if one exists)
+/// Will display if a version is defined in the manifest of the project in the
+/// current directory, e.g. its `Cargo.toml` or `package.json` file
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    match get_package_version() {
-        Some(package_version) => {
-            let mut module = context.new_module("package");
-            let config: PackageConfig = PackageConfig::try_load(module.config);
+    let mut module = context.new_module("package");
+    let config: PackageConfig = PackageConfig::try_load(module.config);
 
-            module.set_style(config.style);
-            module.get_prefix().set_value("is ");
+    let package = get_package(context)?;
+    if package.private && !config.display_private {
+        return None;
+    }
 
-            module.create_segment("symbol", &config.symbol);
-            module.create_segment("version", &SegmentConfig::new(&package_version));
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().is);
 
-            Some(module)
-        }
-        None => None,
+    module.create_segment("symbol", &config.symbol);
+    let segment = module.create_segment("version", &SegmentConfig::new(&package.version));
+
+    // Clicking the version opens its page on the package registry, in
+    // terminals supporting hyperlinks
+    if let Some(url) = package.registry_url.filter(|_| context.hyperlinks()) {
+        segment.set_link(url);
     }
+
+    Some(module)
+}
+
+/// A package, as described by its manifest
+#[derive(Debug, PartialEq)]
+struct Package {
+    version: String,
+    /// Whether the package is marked as not meant to be published
+    private: bool,
+    /// The URL of the version on its registry, if it is published
+    registry_url: Option<String>,
+}
+
+/// How a manifest file is named, either exactly or by its extension
+enum ManifestFile {
+    Name(&'static str),
+    Extension(&'static str),
 }
 
-fn extract_cargo_version(file_contents: &str) -> Option<String> {
+/// Get the package described by the contents of a manifest file
+type Parser = fn(&str) -> Option<Package>;
+
+/// The supported manifest files, in the order they are looked for
+///
+/// Supporting a new manifest format is a matter of adding its parser here.
+const MANIFESTS: &[(ManifestFile, Parser)] = &[
+    (ManifestFile::Name("Cargo.toml"), parse_cargo_toml),
+    (ManifestFile::Name("package.json"), parse_package_json),
+    (ManifestFile::Name("pyproject.toml"), parse_pyproject_toml),
+    (ManifestFile::Name("composer.json"), parse_composer_json),
+    (ManifestFile::Extension("gemspec"), parse_gemspec),
+    (ManifestFile::Name("build.gradle"), parse_gradle),
+    (ManifestFile::Name("build.gradle.kts"), parse_gradle),
+    (ManifestFile::Name("Chart.yaml"), parse_helm_chart),
+];
+
+/// Get the package in the current directory, from the first supported
+/// manifest found there
+fn get_package(context: &Context) -> Option<Package> {
+    let dir_files = context.get_dir_files().ok()?;
+    let find_file = |manifest: &ManifestFile| -> Option<&PathBuf> {
+        match manifest {
+            ManifestFile::Name(name) => dir_files.iter().find(|path| path_has_name(path, &[name])),
+            ManifestFile::Extension(ext) => {
+                dir_files.iter().find(|path| has_extension(path, &[ext]))
+            }
+        }
+    };
+
+    let (file, parse) = MANIFESTS
+        .iter()
+        .find_map(|(manifest, parse)| Some((find_file(manifest)?, parse)))?;
+    parse(&utils::read_file(file).ok()?)
+}
+
+fn parse_cargo_toml(file_contents: &str) -> Option<Package> {
     let cargo_toml: toml::Value = toml::from_str(file_contents).ok()?;
+    // `publish` can also be the list of registries the package is published to
+    let private = match cargo_toml.get("package")?.get("publish") {
+        Some(toml::Value::Boolean(publish)) => !publish,
+        Some(toml::Value::Array(registries)) => registries.is_empty(),
+        _ => false,
+    };
+
+    Some(Package {
+        version: extract_cargo_version(&cargo_toml)?,
+        private,
+        registry_url: extract_cargo_registry_url(&cargo_toml).filter(|_| !private),
+    })
+}
+
+fn parse_package_json(file_contents: &str) -> Option<Package> {
+    let package_json: json::Value = json::from_str(file_contents).ok()?;
+    let private = package_json.get("private").and_then(json::Value::as_bool) == Some(true);
+
+    Some(Package {
+        version: extract_package_version(&package_json)?,
+        private,
+        registry_url: extract_package_registry_url(&package_json).filter(|_| !private),
+    })
+}
+
+fn parse_pyproject_toml(file_contents: &str) -> Option<Package> {
+    let pyproject: toml::Value = toml::from_str(file_contents).ok()?;
+    // PyPI rejects packages with this classifier, to avoid publishing them by mistake
+    let private = get_pyproject_metadata(&pyproject)?
+        .get("classifiers")
+        .and_then(toml::Value::as_array)
+        .map(|classifiers| {
+            classifiers
+                .iter()
+                .any(|classifier| classifier.as_str() == Some("Private :: Do Not Upload"))
+        })
+        .unwrap_or(false);
+
+    Some(Package {
+        version: extract_pyproject_version(&pyproject)?,
+        private,
+        registry_url: extract_pyproject_registry_url(&pyproject).filter(|_| !private),
+    })
+}
+
+/// Get the metadata of a Python project, from the standard `[project]` table
+/// of its `pyproject.toml` file or from the `[tool.poetry]` table of Poetry
+fn get_pyproject_metadata(pyproject: &toml::Value) -> Option<&toml::Value> {
+    match pyproject.get("project") {
+        Some(project) if project.get("version").is_some() => Some(project),
+        _ => pyproject.get("tool")?.get("poetry"),
+    }
+}
+
+fn parse_composer_json(file_contents: &str) -> Option<Package> {
+    let composer_json: json::Value = json::from_str(file_contents).ok()?;
+    let raw_version = composer_json.get("version")?.as_str()?;
+
+    let registry_url = composer_json
+        .get("name")
+        .and_then(json::Value::as_str)
+        .map(|name| {
+            format!(
+                "https://packagist.org/packages/{}#{}",
+                name,
+                raw_version.trim()
+            )
+        });
+
+    Some(Package {
+        version: format_version(raw_version),
+        private: false,
+        registry_url,
+    })
+}
+
+/// Parse a gemspec, where the version is set with e.g. `spec.version = "1.0.0"`
+///
+/// Only literal versions can be read, a version set from a constant of the gem
+/// isn't known without running Ruby.
+fn parse_gemspec(file_contents: &str) -> Option<Package> {
+    let attribute = |attribute: &str| {
+        file_contents.lines().find_map(|line| {
+            let (_, rest) = line.trim().split_once('.')?;
+            let rest = rest.strip_prefix(attribute)?;
+            extract_assigned_string(rest)
+        })
+    };
+
+    let raw_version = attribute("version")?;
+    let registry_url = attribute("name").map(|name| {
+        format!(
+            "https://rubygems.org/gems/{}/versions/{}",
+            name, raw_version
+        )
+    });
+
+    Some(Package {
+        version: format_version(raw_version),
+        private: false,
+        registry_url,
+    })
+}
+
+/// Parse a Gradle build script, where the version is set with e.g.
+/// `version = "1.0.0"` or `version '1.0.0'`
+fn parse_gradle(file_contents: &str) -> Option<Package> {
+    let raw_version = file_contents.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix("version")?;
+        extract_assigned_string(rest)
+    })?;
+
+    Some(Package {
+        version: format_version(raw_version),
+        private: false,
+        registry_url: None,
+    })
+}
+
+/// Parse the `Chart.yaml` file of a Helm chart
+fn parse_helm_chart(file_contents: &str) -> Option<Package> {
+    let yaml_docs = YamlLoader::load_from_str(file_contents).ok()?;
+    let raw_version = yaml_docs.first()?["version"].as_str()?;
+
+    Some(Package {
+        version: format_version(raw_version),
+        private: false,
+        registry_url: None,
+    })
+}
+
+/// Extract the string literal assigned after the name of a property, e.g.
+/// `"1.0.0"` from ` = "1.0.0"`, with or without the `=`
+fn extract_assigned_string(rest: &str) -> Option<&str> {
+    // The name of the property must end where the assignment starts
+    if !rest.starts_with(|c: char| c == '=' || c.is_whitespace()) {
+        return None;
+    }
+
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix('=').unwrap_or(rest).trim_start();
+    let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &rest[1..];
+    let end = value.find(quote)?;
+
+    Some(&value[..end])
+}
+
+fn extract_cargo_version(cargo_toml: &toml::Value) -> Option<String> {
     let raw_version = cargo_toml.get("package")?.get("version")?.as_str()?;
 
     let formatted_version = format_version(raw_version);
     Some(formatted_version)
 }
 
-fn extract_package_version(file_contents: &str) -> Option<String> {
-    let package_json: json::Value = json::from_str(file_contents).ok()?;
+fn extract_package_version(package_json: &json::Value) -> Option<String> {
     let raw_version = package_json.get("version")?.as_str()?;
     if raw_version == "null" {
         return None;
@@ -47,11 +258,8 @@ fn extract_package_version(file_contents: &str) -> Option<String> {
     Some(formatted_version)
 }
 
-fn extract_poetry_version(file_contents: &str) -> Option<String> {
-    let poetry_toml: toml::Value = toml::from_str(file_contents).ok()?;
-    let raw_version = poetry_toml
-        .get("tool")?
-        .get("poetry")?
+fn extract_pyproject_version(pyproject: &toml::Value) -> Option<String> {
+    let raw_version = get_pyproject_metadata(pyproject)?
         .get("version")?
         .as_str()?;
 
@@ -59,16 +267,37 @@ fn extract_poetry_version(file_contents: &str) -> Option<String> {
     Some(formatted_version)
 }
 
-fn get_package_version() -> Option<String> {
-    if let Ok(cargo_toml) = utils::read_file("Cargo.toml") {
-        extract_cargo_version(&cargo_toml)
-    } else if let Ok(package_json) = utils::read_file("package.json") {
-        extract_package_version(&package_json)
-    } else if let Ok(poetry_toml) = utils::read_file("pyproject.toml") {
-        extract_poetry_version(&poetry_toml)
-    } else {
-        None
-    }
+fn extract_cargo_registry_url(cargo_toml: &toml::Value) -> Option<String> {
+    let package = cargo_toml.get("package")?;
+    let name = package.get("name")?.as_str()?;
+    let version = package.get("version")?.as_str()?;
+    Some(format!(
+        "https://crates.io/crates/{}/{}",
+        name,
+        version.trim()
+    ))
+}
+
+fn extract_package_registry_url(package_json: &json::Value) -> Option<String> {
+    let name = package_json.get("name")?.as_str()?;
+    let version = package_json.get("version")?.as_str()?;
+    Some(format!(
+        "https://www.npmjs.com/package/{}/v/{}",
+        name,
+        version.trim()
+    ))
+}
+
+fn extract_pyproject_registry_url(pyproject: &toml::Value) -> Option<String> {
+    let project = get_pyproject_metadata(pyproject)?;
+
+    let name = project.get("name")?.as_str()?;
+    let version = project.get("version")?.as_str()?;
+    Some(format!(
+        "https://pypi.org/project/{}/{}/",
+        name,
+        version.trim()
+    ))
 }
 
 fn format_version(version: &str) -> String {
@@ -83,6 +312,8 @@ fn format_version(version: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
 
     #[test]
     fn test_format_version() {
@@ -105,8 +336,7 @@ mod tests {
             [package]
             name = "starship"
             version = "0.1.0"
-        }
-        .to_string();
+        };
 
         let expected_version = Some("v0.1.0".to_string());
         assert_eq!(extract_cargo_version(&cargo_with_version), expected_version);
@@ -114,8 +344,7 @@ mod tests {
         let cargo_without_version = toml::toml! {
             [package]
             name = "starship"
-        }
-        .to_string();
+        };
 
         let expected_version = None;
         assert_eq!(
@@ -129,8 +358,7 @@ mod tests {
         let package_with_version = json::json!({
             "name": "spacefish",
             "version": "0.1.0"
-        })
-        .to_string();
+        });
 
         let expected_version = Some("v0.1.0".to_string());
         assert_eq!(
@@ -140,8 +368,7 @@ mod tests {
 
         let package_without_version = json::json!({
             "name": "spacefish"
-        })
-        .to_string();
+        });
 
         let expected_version = None;
         assert_eq!(
@@ -151,30 +378,229 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_poetry_version() {
+    fn test_extract_pyproject_version() {
         let poetry_with_version = toml::toml! {
             [tool.poetry]
             name = "starship"
             version = "0.1.0"
-        }
-        .to_string();
+        };
 
         let expected_version = Some("v0.1.0".to_string());
         assert_eq!(
-            extract_poetry_version(&poetry_with_version),
+            extract_pyproject_version(&poetry_with_version),
             expected_version
         );
 
         let poetry_without_version = toml::toml! {
             [tool.poetry]
             name = "starship"
-        }
-        .to_string();
+        };
 
         let expected_version = None;
         assert_eq!(
-            extract_poetry_version(&poetry_without_version),
+            extract_pyproject_version(&poetry_without_version),
             expected_version
         );
+
+        let project_with_version = toml::toml! {
+            [project]
+            name = "starship"
+            version = "0.2.0"
+        };
+
+        let expected_version = Some("v0.2.0".to_string());
+        assert_eq!(
+            extract_pyproject_version(&project_with_version),
+            expected_version
+        );
+    }
+
+    #[test]
+    fn test_parse_private_packages() {
+        let private_package = Some(Package {
+            version: "v0.1.0".to_string(),
+            private: true,
+            registry_url: None,
+        });
+
+        let unpublished_cargo_toml =
+            "[package]\nname = \"starship\"\nversion = \"0.1.0\"\npublish = []\n";
+        assert_eq!(parse_cargo_toml(unpublished_cargo_toml), private_package);
+
+        let unpublished_cargo_toml =
+            "[package]\nname = \"starship\"\nversion = \"0.1.0\"\npublish = false\n";
+        assert_eq!(parse_cargo_toml(unpublished_cargo_toml), private_package);
+
+        let private_package_json = json::json!({
+            "name": "spacefish",
+            "version": "0.1.0",
+            "private": true
+        })
+        .to_string();
+        assert_eq!(parse_package_json(&private_package_json), private_package);
+
+        let private_pyproject = toml::toml! {
+            [project]
+            name = "starship"
+            version = "0.1.0"
+            classifiers = ["Private :: Do Not Upload"]
+        }
+        .to_string();
+        assert_eq!(parse_pyproject_toml(&private_pyproject), private_package);
+    }
+
+    #[test]
+    fn test_parse_composer_json() {
+        let composer_json = json::json!({
+            "name": "starship/starship",
+            "version": "1.4.2"
+        })
+        .to_string();
+        assert_eq!(
+            parse_composer_json(&composer_json),
+            Some(Package {
+                version: "v1.4.2".to_string(),
+                private: false,
+                registry_url: Some(
+                    "https://packagist.org/packages/starship/starship#1.4.2".to_string()
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_gemspec() {
+        let gemspec = "Gem::Specification.new do |spec|
+  spec.name          = \"starship\"
+  spec.version       = '1.4.2'
+  spec.required_ruby_version = \">= 2.5\"
+end
+";
+        assert_eq!(
+            parse_gemspec(gemspec),
+            Some(Package {
+                version: "v1.4.2".to_string(),
+                private: false,
+                registry_url: Some("https://rubygems.org/gems/starship/versions/1.4.2".to_string()),
+            })
+        );
+
+        let gemspec_with_constant = "Gem::Specification.new do |s|
+  s.name    = \"starship\"
+  s.version = Starship::VERSION
+end
+";
+        assert_eq!(parse_gemspec(gemspec_with_constant), None);
+    }
+
+    #[test]
+    fn test_parse_gradle() {
+        let build_gradle = "plugins {\n    id 'java'\n}\n\ngroup 'org.starship'\nversion '1.4.2'\n";
+        assert_eq!(
+            parse_gradle(build_gradle).map(|package| package.version),
+            Some("v1.4.2".to_string())
+        );
+
+        let build_gradle_kts = "group = \"org.starship\"\nversion = \"1.4.2-SNAPSHOT\"\n";
+        assert_eq!(
+            parse_gradle(build_gradle_kts).map(|package| package.version),
+            Some("v1.4.2-SNAPSHOT".to_string())
+        );
+
+        let versionless_build_gradle = "versionCode = \"12\"\n";
+        assert_eq!(parse_gradle(versionless_build_gradle), None);
+    }
+
+    #[test]
+    fn test_parse_helm_chart() {
+        let chart_yaml = "apiVersion: v2\nname: starship\nversion: 1.4.2\n";
+        assert_eq!(
+            parse_helm_chart(chart_yaml).map(|package| package.version),
+            Some("v1.4.2".to_string())
+        );
+    }
+
+    #[test]
+    fn private_package_hidden() {
+        let package_json = "{\"name\": \"spacefish\", \"version\": \"0.1.0\", \"private\": true}";
+
+        let actual = ModuleRenderer::new("package")
+            .file("package.json", package_json)
+            .collect();
+        assert_eq!(actual, None);
+
+        let actual = ModuleRenderer::new("package")
+            .file("package.json", package_json)
+            .config(toml::toml! {
+                [package]
+                display_private = true
+            })
+            .collect();
+        let expected = Some(format!("is {} ", Color::Red.bold().paint("📦 v0.1.0")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn version_from_gemspec() {
+        let actual = ModuleRenderer::new("package")
+            .file(
+                "starship.gemspec",
+                "Gem::Specification.new do |spec|\n  spec.version = \"1.4.2\"\nend\n",
+            )
+            .collect();
+        let expected = Some(format!("is {} ", Color::Red.bold().paint("📦 v1.4.2")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_extract_registry_urls() {
+        let cargo_toml = toml::toml! {
+            [package]
+            name = "starship"
+            version = "0.1.0"
+        };
+        assert_eq!(
+            extract_cargo_registry_url(&cargo_toml),
+            Some("https://crates.io/crates/starship/0.1.0".to_string())
+        );
+
+        let package_json = json::json!({
+            "name": "spacefish",
+            "version": "0.1.0"
+        });
+        assert_eq!(
+            extract_package_registry_url(&package_json),
+            Some("https://www.npmjs.com/package/spacefish/v/0.1.0".to_string())
+        );
+
+        let poetry_toml = toml::toml! {
+            [tool.poetry]
+            name = "starship"
+            version = "0.1.0"
+        };
+        assert_eq!(
+            extract_pyproject_registry_url(&poetry_toml),
+            Some("https://pypi.org/project/starship/0.1.0/".to_string())
+        );
+    }
+
+    #[test]
+    fn version_links_to_registry() {
+        let cargo_toml = "[package]\nname = \"starship\"\nversion = \"0.1.0\"\n";
+
+        let actual = ModuleRenderer::new("package")
+            .file("Cargo.toml", cargo_toml)
+            .config(toml::toml! {
+                hyperlinks = true
+            })
+            .collect()
+            .unwrap();
+        assert!(actual.contains("\u{1b}]8;;https://crates.io/crates/starship/0.1.0\u{1b}\\"));
+
+        let actual = ModuleRenderer::new("package")
+            .file("Cargo.toml", cargo_toml)
+            .collect()
+            .unwrap();
+        assert!(!actual.contains("\u{1b}]8;;"));
     }
 }
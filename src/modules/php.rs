@@ -0,0 +1,154 @@
+use serde_json as json;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::php::PhpConfig;
+use crate::utils;
+
+/// Creates a module with the current PHP version
+///
+/// Will display the PHP version if any of the following criteria are met:
+///     - Current directory contains a `composer.json` or `.php-version` file
+///     - Current directory contains a file with the `.php` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_php_project = context
+        .try_begin_scan()?
+        .set_files(&["composer.json", ".php-version"])
+        .set_extensions(&["php"])
+        .is_match();
+
+    if !is_php_project {
+        return None;
+    }
+
+    let php_version = match get_pinned_version(context) {
+        Some(version) => version,
+        None => {
+            let php_version = context.exec_cmd("php", &["-v"])?.stdout;
+            parse_php_version(&php_version)?
+        }
+    };
+
+    let mut module = context.new_module("php");
+    let config = PhpConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "version",
+        &config.version.with_value(&format!("v{}", php_version)),
+    );
+
+    Some(module)
+}
+
+/// Get the version of PHP pinned for the project, from the `.php-version` file
+/// of phpenv, or the platform PHP version set in `composer.json`
+fn get_pinned_version(context: &Context) -> Option<String> {
+    let from_version_file = utils::read_file(context.current_dir.join(".php-version"))
+        .ok()
+        .map(|version| version.trim().to_string());
+    let from_composer = || {
+        let composer_json = utils::read_file(context.current_dir.join("composer.json")).ok()?;
+        let composer_json: json::Value = json::from_str(&composer_json).ok()?;
+        let version = composer_json["config"]["platform"]["php"].as_str()?;
+        Some(version.trim().to_string())
+    };
+
+    from_version_file
+        .filter(|version| !version.is_empty())
+        .or_else(from_composer)
+        .filter(|version| !version.is_empty())
+}
+
+fn parse_php_version(php_stdout: &str) -> Option<String> {
+    // php -v output looks like this:
+    // PHP 7.4.3 (cli) (built: Oct  6 2020 15:47:56) ( NTS )
+    // Copyright (c) The PHP Group
+    // Zend Engine v3.4.0, Copyright (c) Zend Technologies
+    let version = php_stdout
+        .trim()
+        .strip_prefix("PHP ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const PHP_VERSION: &str = "PHP 7.4.3 (cli) (built: Oct  6 2020 15:47:56) ( NTS )\nCopyright (c) The PHP Group\nZend Engine v3.4.0, Copyright (c) Zend Technologies\n";
+
+    #[test]
+    fn test_parse_php_version() {
+        assert_eq!(parse_php_version(PHP_VERSION), Some("7.4.3".to_string()));
+        assert_eq!(parse_php_version("not php"), None);
+    }
+
+    #[test]
+    fn folder_without_php_files() {
+        let actual = ModuleRenderer::new("php")
+            .cmd("php -v", PHP_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_php_file() {
+        let actual = ModuleRenderer::new("php")
+            .file("index.php", "")
+            .cmd("php -v", PHP_VERSION)
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(147).bold().paint("🐘 v7.4.3")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_php_version_file() {
+        let actual = ModuleRenderer::new("php")
+            .file(".php-version", "8.0.3\n")
+            .cmd("php -v", PHP_VERSION)
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(147).bold().paint("🐘 v8.0.3")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_composer_platform() {
+        let actual = ModuleRenderer::new("php")
+            .file(
+                "composer.json",
+                "{\"require\": {\"php\": \"^7.3\"}, \"config\": {\"platform\": {\"php\": \"7.3.27\"}}}",
+            )
+            .cmd("php -v", PHP_VERSION)
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(147).bold().paint("🐘 v7.3.27")
+        ));
+        assert_eq!(expected, actual);
+
+        let actual = ModuleRenderer::new("php")
+            .file("composer.json", "{\"require\": {\"php\": \"^7.3\"}}")
+            .cmd("php -v", PHP_VERSION)
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(147).bold().paint("🐘 v7.4.3")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
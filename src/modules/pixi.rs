@@ -0,0 +1,141 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::pixi::PixiConfig;
+
+/// Creates a module with the current pixi version and environment
+///
+/// Will display the module if any of the following criteria are met:
+///     - Current directory contains a `pixi.toml` file
+///     - `$PIXI_ENVIRONMENT_NAME` is set (i.e. in a `pixi shell`)
+///
+/// The environment is only shown when it isn't the default one, unless
+/// `show_default_environment` is set.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let pixi_env = context
+        .get_env("PIXI_ENVIRONMENT_NAME")
+        .filter(|name| !name.trim().is_empty());
+    let is_pixi_project = pixi_env.is_some()
+        || context
+            .try_begin_scan()?
+            .set_files(&["pixi.toml"])
+            .is_match();
+
+    if !is_pixi_project {
+        return None;
+    }
+
+    let mut module = context.new_module("pixi");
+    let config = PixiConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+
+    let pixi_version = context
+        .exec_cmd("pixi", &["--version"])
+        .and_then(|output| format_pixi_version(&output.stdout));
+    if let Some(pixi_version) = pixi_version {
+        module.create_segment("version", &config.version.with_value(&pixi_version));
+    }
+
+    if let Some(pixi_env) = pixi_env {
+        if pixi_env != "default" || config.show_default_environment {
+            let formatted_env = format!(" ({})", pixi_env);
+            module.create_segment(
+                "environment",
+                &config.environment.with_value(&formatted_env),
+            );
+        }
+    }
+
+    Some(module)
+}
+
+fn format_pixi_version(pixi_stdout: &str) -> Option<String> {
+    // pixi --version output looks like this:
+    // pixi 0.13.0
+    let mut words = pixi_stdout.split_whitespace();
+    if words.next()? != "pixi" {
+        return None;
+    }
+
+    Some(format!("v{}", words.next()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_pixi_version() {
+        assert_eq!(
+            format_pixi_version("pixi 0.13.0\n"),
+            Some("v0.13.0".to_string())
+        );
+        assert_eq!(format_pixi_version(""), None);
+    }
+
+    #[test]
+    fn folder_without_pixi_project() {
+        let actual = ModuleRenderer::new("pixi")
+            .cmd("pixi --version", "pixi 0.13.0\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_pixi_toml() {
+        let actual = ModuleRenderer::new("pixi")
+            .file("pixi.toml", "")
+            .cmd("pixi --version", "pixi 0.13.0\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🧚 v0.13.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn in_pixi_shell() {
+        let actual = ModuleRenderer::new("pixi")
+            .env("PIXI_ENVIRONMENT_NAME", "cuda")
+            .cmd("pixi --version", "pixi 0.13.0\n")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Yellow.bold().paint("🧚 v0.13.0 (cuda)")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn default_environment_hidden() {
+        let actual = ModuleRenderer::new("pixi")
+            .env("PIXI_ENVIRONMENT_NAME", "default")
+            .cmd("pixi --version", "pixi 0.13.0\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🧚 v0.13.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn default_environment_shown() {
+        let actual = ModuleRenderer::new("pixi")
+            .env("PIXI_ENVIRONMENT_NAME", "default")
+            .cmd("pixi --version", "pixi 0.13.0\n")
+            .config(toml::toml! {
+                [pixi]
+                show_default_environment = true
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Yellow.bold().paint("🧚 v0.13.0 (default)")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,231 @@
+use std::path::{Path, PathBuf};
+
+use serde_json as json;
+use yaml_rust::YamlLoader;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::pulumi::PulumiConfig;
+use crate::utils;
+
+/// Creates a module with the selected Pulumi stack
+///
+/// Will display the stack if the current directory or one of its parents
+/// contains a `Pulumi.yaml` file, and a stack was selected with `pulumi stack select`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let project_file = find_project_file(&context.current_dir)?;
+    let pulumi_home = get_pulumi_home(context)?;
+
+    let project = utils::read_file(&project_file).ok()?;
+    let project_name = get_project_name(&project)?;
+    let stack = get_stack(&pulumi_home, &project_name, &project_file)?;
+    let username = utils::read_file(pulumi_home.join("credentials.json"))
+        .ok()
+        .and_then(|credentials| get_username(&credentials));
+
+    let mut module = context.new_module("pulumi");
+    let config = PulumiConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    if let Some(username) = username {
+        module.create_segment(
+            "username",
+            &config.username.with_value(&format!("{}@", username)),
+        );
+    }
+    module.create_segment("stack", &config.stack.with_value(&stack));
+
+    Some(module)
+}
+
+/// Pulumi runs the project found in the current directory or its parents
+fn find_project_file(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .flat_map(|dir| vec![dir.join("Pulumi.yaml"), dir.join("Pulumi.yml")])
+        .find(|file| file.is_file())
+}
+
+/// The directory of the Pulumi configuration, `~/.pulumi` unless overridden
+/// by `$PULUMI_HOME`
+fn get_pulumi_home(context: &Context) -> Option<PathBuf> {
+    context
+        .get_env("PULUMI_HOME")
+        .map(PathBuf::from)
+        .or_else(|| Some(dirs::home_dir()?.join(".pulumi")))
+}
+
+fn get_project_name(project: &str) -> Option<String> {
+    let yaml_docs = YamlLoader::load_from_str(project).ok()?;
+    let name = yaml_docs.first()?["name"].as_str()?;
+
+    Some(name.to_string())
+}
+
+/// Get the selected stack from the workspace file of the project, which
+/// Pulumi names after the project and the SHA-1 of the path of its project file
+fn get_stack(pulumi_home: &Path, project_name: &str, project_file: &Path) -> Option<String> {
+    let hash = sha1(project_file.to_string_lossy().as_bytes());
+    let hash: String = hash.iter().map(|byte| format!("{:02x}", byte)).collect();
+    let workspace_file = pulumi_home
+        .join("workspaces")
+        .join(format!("{}-{}-workspace.json", project_name, hash));
+
+    let workspace: json::Value = json::from_str(&utils::read_file(workspace_file).ok()?).ok()?;
+    let stack = workspace["stack"].as_str()?;
+
+    Some(stack.to_string())
+}
+
+/// Get the name of the user logged in to the current backend
+fn get_username(credentials: &str) -> Option<String> {
+    let credentials: json::Value = json::from_str(credentials).ok()?;
+    let current = credentials["current"].as_str()?;
+    let username = credentials["accounts"][current]["username"].as_str()?;
+
+    Some(username.to_string())
+}
+
+/// Compute the SHA-1 digest of some data
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [
+        0x6745_2301,
+        0xefcd_ab89,
+        0x98ba_dcfe,
+        0x1032_5476,
+        0xc3d2_e1f0,
+    ];
+
+    // Pad the message to a multiple of 64 bytes, ending with its length in bits
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&(data.len() as u64 * 8).to_be_bytes());
+
+    for block in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ed9_eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1b_bcdc),
+                _ => (b ^ c ^ d, 0xca62_c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (value, new) in state.iter_mut().zip(&[a, b, c, d, e]) {
+            *value = value.wrapping_add(*new);
+        }
+    }
+
+    let mut digest = [0u8; 20];
+    for (bytes, value) in digest.chunks_mut(4).zip(&state) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    fn hex(digest: [u8; 20]) -> String {
+        digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_sha1() {
+        assert_eq!(hex(sha1(b"")), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(
+            hex(sha1(b"abc")),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hex(sha1(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+        );
+    }
+
+    #[test]
+    fn test_get_username() {
+        let credentials = r#"{
+            "current": "https://api.pulumi.com",
+            "accounts": {
+                "https://api.pulumi.com": {"accessToken": "secret", "username": "astronaut"}
+            }
+        }"#;
+        assert_eq!(get_username(credentials), Some("astronaut".to_string()));
+        assert_eq!(get_username("{}"), None);
+    }
+
+    #[test]
+    fn folder_without_pulumi_project() {
+        let actual = ModuleRenderer::new("pulumi").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_selected_stack() {
+        let renderer = ModuleRenderer::new("pulumi")
+            .file("Pulumi.yaml", "name: infra\nruntime: nodejs\n")
+            .file(
+                "home/credentials.json",
+                r#"{"current": "https://api.pulumi.com", "accounts": {"https://api.pulumi.com": {"username": "astronaut"}}}"#,
+            );
+        let pulumi_home = renderer.root_path().join("home");
+        let project_file = renderer.root_path().join("Pulumi.yaml");
+        let workspace = format!(
+            "home/workspaces/infra-{}-workspace.json",
+            hex(sha1(project_file.to_string_lossy().as_bytes()))
+        );
+
+        let actual = renderer
+            .file(workspace, r#"{"stack": "production"}"#)
+            .env("PULUMI_HOME", pulumi_home.to_string_lossy())
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Purple.bold().paint("🧊 astronaut@production")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_without_selected_stack() {
+        let renderer = ModuleRenderer::new("pulumi").file("Pulumi.yaml", "name: infra\n");
+        let pulumi_home = renderer.root_path().join("home");
+
+        let actual = renderer
+            .env("PULUMI_HOME", pulumi_home.to_string_lossy())
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+}
@@ -0,0 +1,91 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::purescript::PureScriptConfig;
+
+/// Creates a module with the current PureScript version
+///
+/// Will display the PureScript version if any of the following criteria are met:
+///     - Current directory contains a `spago.dhall` file
+///     - Current directory contains a `spago.yaml` file
+///     - Current directory contains a file with the `.purs` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_purs_project = context
+        .try_begin_scan()?
+        .set_files(&["spago.dhall", "spago.yaml"])
+        .set_extensions(&["purs"])
+        .is_match();
+
+    if !is_purs_project {
+        return None;
+    }
+
+    let purs_version = context.exec_cmd("purs", &["--version"])?.stdout;
+    let formatted_version = format_purs_version(&purs_version)?;
+
+    let mut module = context.new_module("purescript");
+    let config = PureScriptConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_purs_version(purs_stdout: &str) -> Option<String> {
+    // purs --version output looks like this:
+    // 0.13.5
+    // Development builds append extra information:
+    // 0.13.5 [development build; commit: 5d4ff0e]
+    let version = purs_stdout.split_whitespace().next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_purs_version() {
+        assert_eq!(format_purs_version("0.13.5\n"), Some("v0.13.5".to_string()));
+        assert_eq!(
+            format_purs_version("0.13.5 [development build; commit: 5d4ff0e]\n"),
+            Some("v0.13.5".to_string())
+        );
+        assert_eq!(format_purs_version(""), None);
+    }
+
+    #[test]
+    fn folder_without_purescript_files() {
+        let actual = ModuleRenderer::new("purescript")
+            .cmd("purs --version", "0.13.5\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_spago_dhall() {
+        let actual = ModuleRenderer::new("purescript")
+            .file("spago.dhall", "")
+            .cmd("purs --version", "0.13.5\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::White.bold().paint("<=> v0.13.5")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_purs_file() {
+        let actual = ModuleRenderer::new("purescript")
+            .file("Main.purs", "")
+            .cmd("purs --version", "0.13.5\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::White.bold().paint("<=> v0.13.5")));
+        assert_eq!(expected, actual);
+    }
+}
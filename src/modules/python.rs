@@ -1,9 +1,9 @@
 use std::env;
-use std::path::Path;
-use std::process::Command;
+use std::path::{Path, PathBuf};
 
 use super::{Context, Module, RootModuleConfig, SegmentConfig};
 use crate::configs::python::PythonConfig;
+use crate::utils;
 
 /// Creates a module with the current Python version
 ///
@@ -27,7 +27,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         .set_extensions(&["py"])
         .is_match();
 
-    let is_venv = env::var("VIRTUAL_ENV").ok().is_some();
+    let is_venv = context.get_env("VIRTUAL_ENV").is_some();
 
     if !is_py_project && !is_venv {
         return None;
@@ -40,55 +40,106 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     module.create_segment("symbol", &config.symbol);
 
     if config.pyenv_version_name {
-        let python_version = get_pyenv_version()?;
+        let python_version = get_pyenv_version(context)?;
         module.create_segment("pyenv_prefix", &config.pyenv_prefix);
-        module.create_segment("version", &SegmentConfig::new(&python_version.trim()));
+        module.create_segment("version", &SegmentConfig::new(&python_version));
     } else {
-        let python_version = get_python_version()?;
-        let formatted_version = format_python_version(&python_version);
+        // Running `python` is slow, especially through pyenv's shims, so a
+        // version pinned for the directory is used when there is one
+        let formatted_version = match get_pinned_version(context) {
+            Some(version) => format!("v{}", version),
+            None => format_python_version(&get_python_version(context)?),
+        };
         module.create_segment("version", &SegmentConfig::new(&formatted_version));
+    };
 
-        if let Some(virtual_env) = get_python_virtual_env() {
-            module.create_segment(
-                "virtualenv",
-                &SegmentConfig::new(&format!(" ({})", virtual_env)),
-            );
-        };
+    if let Some(virtual_env) = get_python_virtual_env(context) {
+        module.create_segment(
+            "virtualenv",
+            &SegmentConfig::new(&format!(" ({})", virtual_env)),
+        );
     };
 
     Some(module)
 }
 
-fn get_pyenv_version() -> Option<String> {
-    Command::new("pyenv")
-        .arg("version-name")
-        .output()
-        .ok()
-        .and_then(|output| String::from_utf8(output.stdout).ok())
+/// Get the name of the pyenv version, as `pyenv version-name` would print it
+fn get_pyenv_version(context: &Context) -> Option<String> {
+    get_pyenv_version_from_files(context).or_else(|| {
+        let output = context.exec_cmd("pyenv", &["version-name"])?;
+        Some(output.stdout.trim().to_string())
+    })
 }
 
-fn get_python_version() -> Option<String> {
-    match Command::new("python").arg("--version").output() {
-        Ok(output) => {
-            if !output.status.success() {
-                log::warn!(
-                    "Non-Zero exit code '{}' when executing `python --version`",
-                    output.status
-                );
-                return None;
-            }
-            // We have to check both stdout and stderr since for Python versions
-            // < 3.4, Python reports to stderr and for Python version >= 3.5,
-            // Python reports to stdout
-            if output.stdout.is_empty() {
-                let stderr_string = String::from_utf8(output.stderr).unwrap();
-                Some(stderr_string)
-            } else {
-                let stdout_string = String::from_utf8(output.stdout).unwrap();
-                Some(stdout_string)
-            }
-        }
-        Err(_) => None,
+/// Get the pyenv version without running pyenv, from `$PYENV_VERSION`, else
+/// the closest `.python-version` file, else the global version file of pyenv
+fn get_pyenv_version_from_files(context: &Context) -> Option<String> {
+    // Several versions can be selected, the first one runs `python`
+    let first_version = |versions: &str| {
+        versions
+            .split(|c: char| c == ':' || c.is_whitespace())
+            .find(|version| !version.is_empty())
+            .map(String::from)
+    };
+
+    if let Some(version) = context.get_env("PYENV_VERSION") {
+        return first_version(&version);
+    }
+
+    let local_version = context
+        .current_dir
+        .ancestors()
+        .map(|dir| dir.join(".python-version"))
+        .find(|file| file.is_file());
+    let version_file = local_version.or_else(|| {
+        let pyenv_root = context
+            .get_env("PYENV_ROOT")
+            .map(PathBuf::from)
+            .or_else(|| Some(dirs::home_dir()?.join(".pyenv")))?;
+        Some(pyenv_root.join("version"))
+    })?;
+    first_version(&utils::read_file(version_file).ok()?)
+}
+
+/// Get the version of Python pinned for the current directory, if it can be
+/// known without running `python`
+///
+/// The version comes from pyenv when its shims are in `$PATH`, unless a
+/// virtualenv is active, since its own `python` runs instead of pyenv's.
+fn get_pinned_version(context: &Context) -> Option<String> {
+    if context.get_env("VIRTUAL_ENV").is_some() {
+        return None;
+    }
+
+    let uses_pyenv_shims = env::split_paths(&context.get_env("PATH")?)
+        .any(|dir| dir.ends_with("shims") && dir.to_string_lossy().contains("pyenv"));
+    if !uses_pyenv_shims {
+        return None;
+    }
+
+    // The version can be the name of a virtualenv, or "system"
+    get_pyenv_version_from_files(context).filter(|version| is_version(version))
+}
+
+/// Check if a version is a full version number, e.g. "3.8.2", rather than a
+/// name or a range of versions
+fn is_version(version: &str) -> bool {
+    let parts = version.split('.').collect::<Vec<_>>();
+    parts.len() == 3
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn get_python_version(context: &Context) -> Option<String> {
+    let output = context.exec_cmd("python", &["--version"])?;
+    // We have to check both stdout and stderr since for Python versions
+    // < 3.4, Python reports to stderr and for Python version >= 3.5,
+    // Python reports to stdout
+    if output.stdout.trim().is_empty() {
+        Some(output.stderr)
+    } else {
+        Some(output.stdout)
     }
 }
 
@@ -96,8 +147,8 @@ fn format_python_version(python_stdout: &str) -> String {
     format!("v{}", python_stdout.trim_start_matches("Python ").trim())
 }
 
-fn get_python_virtual_env() -> Option<String> {
-    env::var("VIRTUAL_ENV").ok().and_then(|venv| {
+fn get_python_virtual_env(context: &Context) -> Option<String> {
+    context.get_env("VIRTUAL_ENV").and_then(|venv| {
         Path::new(&venv)
             .file_name()
             .map(|filename| String::from(filename.to_str().unwrap_or("")))
@@ -107,10 +158,123 @@ fn get_python_virtual_env() -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const PYENV_PATH: &str = "/home/astronaut/.pyenv/shims:/usr/bin";
 
     #[test]
     fn test_format_python_version() {
         let input = "Python 3.7.2";
         assert_eq!(format_python_version(input), "v3.7.2");
     }
+
+    #[test]
+    fn test_is_version() {
+        assert!(is_version("3.8.2"));
+        assert!(!is_version("3.8"));
+        assert!(!is_version(">=3.8.2"));
+        assert!(!is_version("system"));
+        assert!(!is_version("3.8.2-dev"));
+    }
+
+    #[test]
+    fn version_from_python() {
+        let actual = ModuleRenderer::new("python")
+            .file("main.py", "")
+            .cmd("python --version", "Python 3.7.5\n")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v3.7.5")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn version_from_pyenv_file() {
+        let actual = ModuleRenderer::new("python")
+            .file(".python-version", "3.8.2\n")
+            .env("PATH", PYENV_PATH)
+            .cmd("python --version", "Python 3.7.5\n")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v3.8.2")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn version_from_pyenv_variable() {
+        let actual = ModuleRenderer::new("python")
+            .file(".python-version", "3.8.2\n")
+            .env("PATH", PYENV_PATH)
+            .env("PYENV_VERSION", "3.6.10:2.7.18")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v3.6.10")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pyenv_virtualenv_runs_python() {
+        let actual = ModuleRenderer::new("python")
+            .file(".python-version", "rocket-env\n")
+            .env("PATH", PYENV_PATH)
+            .cmd("python --version", "Python 3.7.5\n")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v3.7.5")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pyenv_file_without_shims_runs_python() {
+        let actual = ModuleRenderer::new("python")
+            .file(".python-version", "3.8.2\n")
+            .cmd("python --version", "Python 3.7.5\n")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v3.7.5")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pyproject_pin_runs_python() {
+        let actual = ModuleRenderer::new("python")
+            .file(
+                "pyproject.toml",
+                "[project]\nrequires-python = \"==3.8.2\"\n",
+            )
+            .cmd("python --version", "Python 3.7.5\n")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Yellow.bold().paint("🐍 v3.7.5")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn virtual_env_runs_python() {
+        let actual = ModuleRenderer::new("python")
+            .file(".python-version", "3.8.2\n")
+            .env("PATH", PYENV_PATH)
+            .env("VIRTUAL_ENV", "/home/astronaut/project/.venv")
+            .cmd("python --version", "Python 3.7.5\n")
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Yellow.bold().paint("🐍 v3.7.5 (.venv)")
+        ));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pyenv_version_name_with_virtual_env() {
+        let actual = ModuleRenderer::new("python")
+            .file(".python-version", "rocket-env\n")
+            .env("VIRTUAL_ENV", "/home/astronaut/.pyenv/versions/rocket-env")
+            .config(toml::toml! {
+                [python]
+                pyenv_version_name = true
+            })
+            .collect();
+        let expected = Some(format!(
+            "via {} ",
+            Color::Yellow
+                .bold()
+                .paint("🐍 pyenv rocket-env (rocket-env)")
+        ));
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,59 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::quarto::QuartoConfig;
+
+/// Creates a module with the current Quarto version
+///
+/// Will display the Quarto version if any of the following criteria are met:
+///     - Current directory contains a `_quarto.yml` file
+///     - Current directory contains a file with the `.qmd` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_quarto_project = context
+        .try_begin_scan()?
+        .set_files(&["_quarto.yml"])
+        .set_extensions(&["qmd"])
+        .is_match();
+
+    if !is_quarto_project {
+        return None;
+    }
+
+    // `quarto --version` only prints the version number, e.g. "1.4.550"
+    let quarto_version = context.exec_cmd("quarto", &["--version"])?.stdout;
+    let formatted_version = format!("v{}", quarto_version.trim());
+
+    let mut module = context.new_module("quarto");
+    let config = QuartoConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn folder_without_quarto_files() {
+        let actual = ModuleRenderer::new("quarto")
+            .cmd("quarto --version", "1.4.550\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_quarto_yml() {
+        let actual = ModuleRenderer::new("quarto")
+            .file("_quarto.yml", "project:\n  type: website\n")
+            .cmd("quarto --version", "1.4.550\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("⨁ v1.4.550")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,119 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::raku::RakuConfig;
+
+/// Creates a module with the current Rakudo version
+///
+/// Will display the Raku version if any of the following criteria are met:
+///     - Current directory contains a `META6.json` file
+///     - Current directory contains a file with the `.raku` or `.rakumod` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_raku_project = context
+        .try_begin_scan()?
+        .set_files(&["META6.json"])
+        .set_extensions(&["raku", "rakumod"])
+        .is_match();
+
+    if !is_raku_project {
+        return None;
+    }
+
+    let raku_version = context.exec_cmd("raku", &["--version"])?.stdout;
+    let formatted_version = format_raku_version(&raku_version)?;
+
+    let mut module = context.new_module("raku");
+    let config = RakuConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_raku_version(raku_stdout: &str) -> Option<String> {
+    // raku --version output looks like this:
+    // Welcome to Rakudo™ v2023.08.
+    // Implementing the Raku® Programming Language v6.d.
+    // Built on MoarVM version 2023.08.
+    //
+    // Older releases print:
+    // This is Rakudo version 2020.01 built on MoarVM version 2020.01.1
+    // implementing Perl 6.d.
+    let version = raku_stdout
+        .lines()
+        .find(|line| line.contains("Rakudo"))?
+        .split_whitespace()
+        .skip_while(|word| !word.starts_with("Rakudo"))
+        .skip(1)
+        .map(|word| word.trim_start_matches('v').trim_end_matches('.'))
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const RAKU_VERSION: &str = "\
+Welcome to Rakudo™ v2023.08.
+Implementing the Raku® Programming Language v6.d.
+Built on MoarVM version 2023.08.
+";
+
+    #[test]
+    fn test_format_raku_version() {
+        assert_eq!(
+            format_raku_version(RAKU_VERSION),
+            Some("v2023.08".to_string())
+        );
+        assert_eq!(
+            format_raku_version(
+                "This is Rakudo version 2020.01 built on MoarVM version 2020.01.1\n\
+                 implementing Perl 6.d.\n"
+            ),
+            Some("v2020.01".to_string())
+        );
+        assert_eq!(format_raku_version("Unknown option"), None);
+    }
+
+    #[test]
+    fn folder_without_raku_files() {
+        let actual = ModuleRenderer::new("raku")
+            .cmd("raku --version", RAKU_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_meta6_json() {
+        let actual = ModuleRenderer::new("raku")
+            .file("META6.json", "{}")
+            .cmd("raku --version", RAKU_VERSION)
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Purple.bold().paint("🦋 v2023.08")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_rakumod_file() {
+        let actual = ModuleRenderer::new("raku")
+            .file("Starship.rakumod", "")
+            .cmd("raku --version", RAKU_VERSION)
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Purple.bold().paint("🦋 v2023.08")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
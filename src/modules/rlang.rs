@@ -0,0 +1,118 @@
+use serde_json as json;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::rlang::RLangConfig;
+use crate::utils;
+
+/// Creates a module with the current R version
+///
+/// Will display the R version if any of the following criteria are met:
+///     - Current directory contains a `.Rprofile` or `renv.lock` file
+///     - Current directory contains a file with the `.R`, `.Rmd`, `.Rproj` or `.Rsx` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_r_project = context
+        .try_begin_scan()?
+        .set_files(&[".Rprofile", "renv.lock"])
+        .set_extensions(&["R", "Rmd", "Rproj", "Rsx"])
+        .is_match();
+
+    if !is_r_project {
+        return None;
+    }
+
+    // Starting R is slow, the version pinned by renv is used when there is one
+    let r_version = match get_renv_version(context) {
+        Some(version) => version,
+        None => {
+            let r_version = context.exec_cmd("R", &["--version"])?.stdout;
+            parse_r_version(&r_version)?
+        }
+    };
+
+    let mut module = context.new_module("rlang");
+    let config = RLangConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment(
+        "version",
+        &config.version.with_value(&format!("v{}", r_version)),
+    );
+
+    Some(module)
+}
+
+/// Get the version of R recorded in the `renv.lock` file of the project
+fn get_renv_version(context: &Context) -> Option<String> {
+    let renv_lock = utils::read_file(context.current_dir.join("renv.lock")).ok()?;
+    let renv_lock: json::Value = json::from_str(&renv_lock).ok()?;
+    let version = renv_lock["R"]["Version"].as_str()?.trim();
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(version.to_string())
+}
+
+fn parse_r_version(r_stdout: &str) -> Option<String> {
+    // R --version output looks like this:
+    // R version 4.1.0 (2021-05-18) -- "Camp Pontanezen"
+    // Copyright (C) 2021 The R Foundation for Statistical Computing
+    let version = r_stdout
+        .trim()
+        .strip_prefix("R version ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const R_VERSION: &str = "R version 4.1.0 (2021-05-18) -- \"Camp Pontanezen\"\nCopyright (C) 2021 The R Foundation for Statistical Computing\nPlatform: x86_64-pc-linux-gnu (64-bit)\n";
+
+    #[test]
+    fn test_parse_r_version() {
+        assert_eq!(parse_r_version(R_VERSION), Some("4.1.0".to_string()));
+        assert_eq!(parse_r_version("not R"), None);
+    }
+
+    #[test]
+    fn folder_without_r_files() {
+        let actual = ModuleRenderer::new("rlang")
+            .cmd("R --version", R_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_r_file() {
+        let actual = ModuleRenderer::new("rlang")
+            .file("analysis.R", "")
+            .cmd("R --version", R_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("📐 v4.1.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_renv_lock() {
+        let actual = ModuleRenderer::new("rlang")
+            .file(
+                "renv.lock",
+                "{\"R\": {\"Version\": \"4.0.5\", \"Repositories\": []}, \"Packages\": {}}",
+            )
+            .cmd("R --version", R_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("📐 v4.0.5")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,5 +1,3 @@
-use std::process::Command;
-
 use super::{Context, Module, RootModuleConfig, SegmentConfig};
 
 use crate::configs::ruby::RubyConfig;
@@ -20,7 +18,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
         return None;
     }
 
-    let ruby_version = get_ruby_version()?;
+    let ruby_version = get_ruby_version(context)?;
     let formatted_version = format_ruby_version(&ruby_version)?;
 
     let mut module = context.new_module("ruby");
@@ -33,11 +31,10 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn get_ruby_version() -> Option<String> {
-    match Command::new("ruby").arg("-v").output() {
-        Ok(output) => Some(String::from_utf8(output.stdout).unwrap()),
-        Err(_) => None,
-    }
+fn get_ruby_version(context: &Context) -> Option<String> {
+    context
+        .exec_cmd("ruby", &["-v"])
+        .map(|output| output.stdout)
 }
 
 fn format_ruby_version(ruby_version: &str) -> Option<String> {
@@ -1,7 +1,7 @@
 use std::ffi::OsStr;
+use std::fs;
 use std::path::Path;
 use std::process::{Command, Output};
-use std::{env, fs};
 
 use super::{Context, Module, RootModuleConfig};
 
@@ -39,7 +39,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     // - `rustup show`
     // - `rustup show active-toolchain`
     // - `rustup which`
-    let module_version = if let Some(toolchain) = env_rustup_toolchain()
+    let module_version = if let Some(toolchain) = env_rustup_toolchain(context)
         .or_else(|| execute_rustup_override_list(&context.current_dir))
         .or_else(|| find_rust_toolchain_file(&context))
     {
@@ -67,8 +67,8 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     Some(module)
 }
 
-fn env_rustup_toolchain() -> Option<String> {
-    let val = env::var("RUSTUP_TOOLCHAIN").ok()?;
+fn env_rustup_toolchain(context: &Context) -> Option<String> {
+    let val = context.get_env("RUSTUP_TOOLCHAIN")?;
     Some(val.trim().to_owned())
 }
 
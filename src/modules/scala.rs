@@ -0,0 +1,129 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::scala::ScalaConfig;
+use crate::utils;
+
+/// Creates a module with the current Scala version
+///
+/// Will display the Scala version if any of the following criteria are met:
+///     - Current directory contains a `build.sbt`, `.scalaenv` or `.sbtenv` file
+///     - Current directory contains a file with the `.scala` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_scala_project = context
+        .try_begin_scan()?
+        .set_files(&["build.sbt", ".scalaenv", ".sbtenv"])
+        .set_extensions(&["scala"])
+        .is_match();
+
+    if !is_scala_project {
+        return None;
+    }
+
+    // Booting the JVM to run `scala` is slow, a version set with scalaenv is
+    // used when there is one
+    let formatted_version = match get_scala_version_from_file(context) {
+        Some(version) => version,
+        None => {
+            // Scala 2 prints its version to stderr
+            let output = context.exec_cmd("scala", &["-version"])?;
+            format_scala_version(&format!("{}{}", output.stdout, output.stderr))?
+        }
+    };
+
+    let mut module = context.new_module("scala");
+    let config = ScalaConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+/// Get the version from the closest `.scala-version` file of scalaenv, which
+/// contains e.g. `scala-2.13.5`
+fn get_scala_version_from_file(context: &Context) -> Option<String> {
+    let version_file = context
+        .current_dir
+        .ancestors()
+        .map(|dir| dir.join(".scala-version"))
+        .find(|file| file.is_file())?;
+    let version = utils::read_file(version_file).ok()?;
+    let version = version.trim();
+    let version = version.strip_prefix("scala-").unwrap_or(version);
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+fn format_scala_version(scala_output: &str) -> Option<String> {
+    // scala -version output looks like this:
+    // Scala code runner version 2.13.5 -- Copyright 2002-2020, LAMP/EPFL and Lightbend, Inc.
+    let version = scala_output
+        .trim()
+        .strip_prefix("Scala code runner version ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use crate::utils::CommandOutput;
+    use ansi_term::Color;
+
+    const SCALA_VERSION: &str =
+        "Scala code runner version 2.13.5 -- Copyright 2002-2020, LAMP/EPFL and Lightbend, Inc.\n";
+
+    #[test]
+    fn test_format_scala_version() {
+        assert_eq!(
+            format_scala_version(SCALA_VERSION),
+            Some("v2.13.5".to_string())
+        );
+        assert_eq!(format_scala_version("not scala"), None);
+    }
+
+    #[test]
+    fn folder_without_scala_files() {
+        let actual = ModuleRenderer::new("scala")
+            .cmd("scala -version", SCALA_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_build_sbt() {
+        let actual = ModuleRenderer::new("scala")
+            .file("build.sbt", "")
+            .cmd_output(
+                "scala -version",
+                Some(CommandOutput {
+                    stdout: String::new(),
+                    stderr: SCALA_VERSION.to_string(),
+                }),
+            )
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Red.dimmed().paint("🆂 v2.13.5")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_scala_version_file() {
+        let actual = ModuleRenderer::new("scala")
+            .file("Main.scala", "")
+            .file(".scala-version", "scala-2.12.13\n")
+            .cmd("scala -version", SCALA_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Red.dimmed().paint("🆂 v2.12.13")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,136 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::shell::ShellConfig;
+
+/// Creates a module with an indicator of the shell running starship
+///
+/// The shell is the one given by `$STARSHIP_SHELL`, which is set by `starship init`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("shell");
+    let config: ShellConfig = ShellConfig::try_load(module.config);
+
+    if config.disabled {
+        log::debug!("Module \"shell\" is disabled by default, set `disabled = false` to enable it");
+        return None;
+    }
+
+    let shell = context.get_env("STARSHIP_SHELL")?;
+    let indicator = match shell.as_str() {
+        "bash" => &config.bash_indicator,
+        "fish" => &config.fish_indicator,
+        "zsh" => &config.zsh_indicator,
+        "elvish" => &config.elvish_indicator,
+        "xonsh" => &config.xonsh_indicator,
+        "tcsh" => &config.tcsh_indicator,
+        "powershell" => &config.powershell_indicator,
+        "cmd" => &config.cmd_indicator,
+        "sh" => &config.sh_indicator,
+        _ => &config.unknown_indicator,
+    };
+    if indicator.value.is_empty() {
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+    module.create_segment("indicator", indicator);
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    fn render(shell: Option<&str>, config: toml::Value) -> Option<String> {
+        let renderer = ModuleRenderer::new("shell").config(config);
+        match shell {
+            Some(shell) => renderer.env("STARSHIP_SHELL", shell),
+            None => renderer,
+        }
+        .collect()
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("shell")
+            .env("STARSHIP_SHELL", "bash")
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn default_indicators() {
+        let config = toml::toml! {
+            [shell]
+            disabled = false
+        };
+        let cases = [
+            ("bash", "bsh"),
+            ("fish", "fsh"),
+            ("zsh", "zsh"),
+            ("elvish", "esh"),
+            ("xonsh", "xsh"),
+            ("tcsh", "tsh"),
+            ("powershell", "psh"),
+            ("cmd", "cmd"),
+            ("sh", "sh"),
+        ];
+        for (shell, indicator) in cases.iter() {
+            let actual = render(Some(shell), config.clone());
+            let expected = Some(format!("{} ", Color::White.bold().paint(*indicator)));
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn unknown_shell() {
+        let actual = render(
+            Some("nushell"),
+            toml::toml! {
+                [shell]
+                disabled = false
+            },
+        );
+        assert_eq!(actual, None);
+
+        let actual = render(
+            Some("nushell"),
+            toml::toml! {
+                [shell]
+                disabled = false
+                unknown_indicator = "?"
+            },
+        );
+        let expected = Some(format!("{} ", Color::White.bold().paint("?")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn no_shell() {
+        let actual = render(
+            None,
+            toml::toml! {
+                [shell]
+                disabled = false
+            },
+        );
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn custom_indicator() {
+        let actual = render(
+            Some("fish"),
+            toml::toml! {
+                [shell]
+                disabled = false
+                fish_indicator = "🐟"
+                style = "bold cyan"
+            },
+        );
+        let expected = Some(format!("{} ", Color::Cyan.bold().paint("🐟")));
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,68 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::singularity::SingularityConfig;
+
+/// Creates a module with the current Singularity or Apptainer image
+///
+/// Will display the image name if the `$SINGULARITY_NAME` or `$APPTAINER_NAME`
+/// environment variable is set, as done by `singularity shell` and `apptainer shell`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let image = context
+        .get_env("SINGULARITY_NAME")
+        .or_else(|| context.get_env("APPTAINER_NAME"))?;
+
+    let mut module = context.new_module("singularity");
+    let config = SingularityConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+
+    module.create_segment("symbol", &config.symbol);
+    let image = format!("{}{}{}", config.prefix, image, config.suffix);
+    module.create_segment("image", &SegmentConfig::new(&image));
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn no_env_set() {
+        let actual = ModuleRenderer::new("singularity").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn singularity_image() {
+        let actual = ModuleRenderer::new("singularity")
+            .env("SINGULARITY_NAME", "centos.sif")
+            .collect();
+
+        let expected = Some(format!(
+            "{} ",
+            Color::Blue.bold().dimmed().paint("[centos.sif]")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn apptainer_image() {
+        let actual = ModuleRenderer::new("singularity")
+            .env("APPTAINER_NAME", "ubuntu.sif")
+            .config(toml::toml! {
+                [singularity]
+                symbol = "📦 "
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "{} ",
+            Color::Blue.bold().dimmed().paint("📦 [ubuntu.sif]")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
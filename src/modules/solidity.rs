@@ -0,0 +1,113 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::solidity::SolidityConfig;
+
+/// Creates a module with the current solc compiler version
+///
+/// Will display the Solidity version if any of the following criteria are met:
+///     - Current directory contains a `foundry.toml` file
+///     - Current directory contains a `hardhat.config.*` file
+///     - Current directory contains a file with the `.sol` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_solidity_project = context
+        .try_begin_scan()?
+        .set_files(&[
+            "foundry.toml",
+            "hardhat.config.js",
+            "hardhat.config.cjs",
+            "hardhat.config.mjs",
+            "hardhat.config.ts",
+        ])
+        .set_extensions(&["sol"])
+        .is_match();
+
+    if !is_solidity_project {
+        return None;
+    }
+
+    let solc_version = context.exec_cmd("solc", &["--version"])?.stdout;
+    let formatted_version = format_solc_version(&solc_version)?;
+
+    let mut module = context.new_module("solidity");
+    let config = SolidityConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_solc_version(solc_stdout: &str) -> Option<String> {
+    // solc --version output looks like this:
+    // solc, the solidity compiler commandline interface
+    // Version: 0.8.24+commit.e11b9ed9.Linux.g++
+    let version = solc_stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("Version: "))?
+        .split('+')
+        .next()?
+        .trim();
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const SOLC_VERSION: &str = "\
+solc, the solidity compiler commandline interface
+Version: 0.8.24+commit.e11b9ed9.Linux.g++
+";
+
+    #[test]
+    fn test_format_solc_version() {
+        assert_eq!(
+            format_solc_version(SOLC_VERSION),
+            Some("v0.8.24".to_string())
+        );
+        assert_eq!(
+            format_solc_version("Version: 0.4.26\n"),
+            Some("v0.4.26".to_string())
+        );
+        assert_eq!(format_solc_version("unknown option"), None);
+    }
+
+    #[test]
+    fn folder_without_solidity_files() {
+        let actual = ModuleRenderer::new("solidity")
+            .cmd("solc --version", SOLC_VERSION)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_sol_file() {
+        let actual = ModuleRenderer::new("solidity")
+            .file("Token.sol", "")
+            .cmd("solc --version", SOLC_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("S v0.8.24")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_hardhat_config() {
+        let actual = ModuleRenderer::new("solidity")
+            .file("hardhat.config.ts", "")
+            .cmd("solc --version", SOLC_VERSION)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("S v0.8.24")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::spack::SpackConfig;
+
+/// Creates a module with the current Spack environment
+///
+/// Will display the Spack environment iff `$SPACK_ENV` is set.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let spack_env = context.get_env("SPACK_ENV")?;
+    let env_name = get_env_name(&spack_env)?;
+
+    let mut module = context.new_module("spack");
+    let config = SpackConfig::try_load(module.config);
+
+    module.set_style(config.style);
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("environment", &config.environment.with_value(env_name));
+
+    Some(module)
+}
+
+/// `$SPACK_ENV` holds the path of the environment's directory, which is named
+/// after the environment for managed environments
+fn get_env_name(spack_env: &str) -> Option<&str> {
+    let spack_env = spack_env.trim();
+    if spack_env.is_empty() {
+        return None;
+    }
+
+    Path::new(spack_env)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .or(Some(spack_env))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_get_env_name() {
+        assert_eq!(
+            get_env_name("/opt/spack/var/spack/environments/astronauts"),
+            Some("astronauts")
+        );
+        assert_eq!(get_env_name("astronauts"), Some("astronauts"));
+        assert_eq!(get_env_name(""), None);
+    }
+
+    #[test]
+    fn not_in_env() {
+        let actual = ModuleRenderer::new("spack").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn env_set() {
+        let actual = ModuleRenderer::new("spack")
+            .env("SPACK_ENV", "/opt/spack/var/spack/environments/astronauts")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🅢 astronauts")));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,195 @@
+use super::{Context, Module, RootModuleConfig, SegmentConfig};
+
+use crate::configs::status::StatusConfig;
+
+/// Creates a module with the exit code of the last command
+///
+/// Will display the exit code if the last command failed. With `pipestatus`
+/// enabled, the exit codes of all the commands of the last pipeline are shown
+/// instead, when the shell provides them with `--pipestatus`.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("status");
+    let config: StatusConfig = StatusConfig::try_load(module.config);
+
+    if config.disabled {
+        log::debug!(
+            "Module \"status\" is disabled by default, set `disabled = false` to enable it"
+        );
+        return None;
+    }
+
+    let props = &context.properties;
+    let status_code = props
+        .get("status_code")
+        .map(|code| code.trim())
+        .unwrap_or("0");
+    let pipestatus: Vec<&str> = match props.get("pipestatus") {
+        Some(pipestatus) if config.pipestatus => pipestatus.split_whitespace().collect(),
+        _ => Vec::new(),
+    };
+
+    // A single command isn't worth showing as a pipeline
+    let codes = if pipestatus.len() > 1 {
+        pipestatus
+    } else {
+        vec![status_code]
+    };
+    if codes.iter().all(|code| *code == "0") {
+        return None;
+    }
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+
+    let symbol = if config.map_symbol {
+        match status_code.parse::<i64>() {
+            Ok(126) => &config.not_executable_symbol,
+            Ok(127) => &config.not_found_symbol,
+            Ok(130) => &config.sigint_symbol,
+            _ if signal_name(status_code).is_some() => &config.signal_symbol,
+            _ => &config.symbol,
+        }
+    } else {
+        &config.symbol
+    };
+    module.create_segment("symbol", symbol);
+
+    for (i, code) in codes.iter().enumerate() {
+        if i > 0 {
+            module.create_segment("pipestatus_separator", &config.pipestatus_separator);
+        }
+        let status = if config.recognize_signal_code {
+            signal_name(code).map_or_else(|| code.to_string(), |name| format!("SIG{}", name))
+        } else {
+            code.to_string()
+        };
+        module.create_segment("status", &SegmentConfig::new(&status));
+    }
+
+    Some(module)
+}
+
+/// Get the name of the signal which killed a command, from its exit code
+///
+/// Shells report a command killed by signal N with the exit code 128 + N.
+fn signal_name(code: &str) -> Option<&'static str> {
+    let signal = code.parse::<i64>().ok()? - 128;
+    let name = match signal {
+        1 => "HUP",
+        2 => "INT",
+        3 => "QUIT",
+        4 => "ILL",
+        5 => "TRAP",
+        6 => "ABRT",
+        7 => "BUS",
+        8 => "FPE",
+        9 => "KILL",
+        10 => "USR1",
+        11 => "SEGV",
+        12 => "USR2",
+        13 => "PIPE",
+        14 => "ALRM",
+        15 => "TERM",
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    fn render(status: &str, pipestatus: Option<&str>, config: toml::Value) -> Option<String> {
+        let renderer = ModuleRenderer::new("status")
+            .property("status_code", status)
+            .config(config);
+        match pipestatus {
+            Some(pipestatus) => renderer.property("pipestatus", pipestatus),
+            None => renderer,
+        }
+        .collect()
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("status")
+            .property("status_code", "1")
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn success_status() {
+        let actual = render("0", None, toml::toml! { [status] disabled = false });
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn failure_status() {
+        let actual = render("1", None, toml::toml! { [status] disabled = false });
+        let expected = Some(format!("{} ", Color::Red.bold().paint("✖ 1")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn mapped_symbols() {
+        let config = toml::toml! { [status] disabled = false };
+        let cases = [
+            ("126", "🚫 126"),
+            ("127", "🔍 127"),
+            ("130", "🧱 SIGINT"),
+            ("137", "⚡ SIGKILL"),
+            ("200", "✖ 200"),
+        ];
+        for (status, expected) in cases.iter() {
+            let actual = render(status, None, config.clone());
+            let expected = Some(format!("{} ", Color::Red.bold().paint(*expected)));
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn unmapped_symbols() {
+        let actual = render(
+            "130",
+            None,
+            toml::toml! {
+                [status]
+                disabled = false
+                map_symbol = false
+                recognize_signal_code = false
+            },
+        );
+        let expected = Some(format!("{} ", Color::Red.bold().paint("✖ 130")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn pipestatus() {
+        let config = toml::toml! {
+            [status]
+            disabled = false
+            pipestatus = true
+        };
+
+        let actual = render("1", Some("0 1"), config.clone());
+        let expected = Some(format!("{} ", Color::Red.bold().paint("✖ 0|1")));
+        assert_eq!(actual, expected);
+
+        // A failure earlier in the pipeline is shown even when the last command succeeded
+        let actual = render("0", Some("127 0"), config.clone());
+        let expected = Some(format!("{} ", Color::Red.bold().paint("✖ 127|0")));
+        assert_eq!(actual, expected);
+
+        let actual = render("0", Some("0 0"), config);
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn pipestatus_disabled() {
+        let actual = render("1", Some("0 1"), toml::toml! { [status] disabled = false });
+        let expected = Some(format!("{} ", Color::Red.bold().paint("✖ 1")));
+        assert_eq!(actual, expected);
+    }
+}
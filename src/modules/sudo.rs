@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::sudo::SudoConfig;
+
+/// Creates a module showing whether sudo credentials are cached
+///
+/// Will display a symbol if `sudo` can currently run commands without asking
+/// for a password, as a reminder that commands can run with elevated privileges.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("sudo");
+    let config: SudoConfig = SudoConfig::try_load(module.config);
+
+    // Running sudo at every prompt isn't something to do without being asked
+    if config.disabled {
+        log::debug!("Module \"sudo\" is disabled by default, set `disabled = false` to enable it");
+        return None;
+    }
+
+    // `-n` makes sudo fail instead of asking for a password
+    context.exec_cmd_with_timeout(
+        "sudo",
+        &["-n", "true"],
+        Duration::from_millis(config.timeout),
+    )?;
+
+    module.set_style(config.style);
+    module.get_prefix().set_value("");
+    module.create_segment("symbol", &config.symbol);
+
+    Some(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("sudo")
+            .cmd("sudo -n true", "")
+            .collect();
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn credentials_cached() {
+        let actual = ModuleRenderer::new("sudo")
+            .cmd("sudo -n true", "")
+            .config(toml::toml! {
+                [sudo]
+                disabled = false
+            })
+            .collect();
+        let expected = Some(format!("{} ", Color::Blue.bold().paint("🧙")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn credentials_not_cached() {
+        let actual = ModuleRenderer::new("sudo")
+            .cmd_output("sudo -n true", None)
+            .config(toml::toml! {
+                [sudo]
+                disabled = false
+            })
+            .collect();
+        assert_eq!(actual, None);
+    }
+}
@@ -0,0 +1,161 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::svn::SvnConfig;
+
+/// Creates a module with the Subversion branch and revision in the current directory
+///
+/// Will display the branch, parsed from the URL of the working copy, if the
+/// current directory is in a working copy, found by its `.svn` directory
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let mut module = context.new_module("svn");
+    let config = SvnConfig::try_load(module.config);
+
+    // Running `svn info` has a cost, so it is only done once the module has
+    // been enabled
+    if config.disabled {
+        log::debug!("Module \"svn\" is disabled by default, set `disabled = false` to enable it");
+        return None;
+    }
+
+    let is_working_copy = context
+        .current_dir
+        .ancestors()
+        .any(|dir| dir.join(".svn").is_dir());
+    if !is_working_copy {
+        return None;
+    }
+
+    // The working copy is described by an SQLite database, which svn reads for us
+    let info = context.exec_cmd("svn", &["info"])?.stdout;
+    let (branch_name, revision) = parse_svn_info(&info)?;
+
+    module.set_style(config.style);
+    module.get_prefix().set_value(context.locale().on);
+
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("name", &config.branch_name.with_value(&branch_name));
+    if config.show_revision {
+        module.create_segment(
+            "revision",
+            &config.revision.with_value(&format!(" (r{})", revision)),
+        );
+    }
+
+    Some(module)
+}
+
+/// Get the branch and revision from the output of `svn info`, e.g.:
+///
+/// ```text
+/// Path: .
+/// URL: https://svn.example.com/repo/branches/feature/src
+/// Relative URL: ^/branches/feature/src
+/// Repository Root: https://svn.example.com/repo
+/// Revision: 1234
+/// ```
+fn parse_svn_info(info: &str) -> Option<(String, String)> {
+    let field = |name: &str| {
+        info.lines()
+            .filter_map(|line| line.split_once(": "))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.trim())
+    };
+
+    let relative_url = field("Relative URL")?;
+    let revision = field("Revision")?;
+
+    Some((get_branch_name(relative_url), revision.to_string()))
+}
+
+/// Get the branch from a URL relative to the repository root, following the
+/// standard `trunk`, `branches` and `tags` layout
+fn get_branch_name(relative_url: &str) -> String {
+    let mut parts = relative_url
+        .trim_start_matches('^')
+        .split('/')
+        .filter(|part| !part.is_empty());
+
+    match parts.next() {
+        Some("trunk") => "trunk".to_string(),
+        Some("branches") | Some("tags") => parts.next().unwrap_or_default().to_string(),
+        Some(part) => part.to_string(),
+        None => "/".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const SVN_INFO: &str = "\
+Path: .
+Working Copy Root Path: /home/user/repo
+URL: https://svn.example.com/repo/branches/feature/src
+Relative URL: ^/branches/feature/src
+Repository Root: https://svn.example.com/repo
+Revision: 1234
+Node Kind: directory
+";
+
+    #[test]
+    fn test_get_branch_name() {
+        assert_eq!(get_branch_name("^/trunk"), "trunk");
+        assert_eq!(get_branch_name("^/trunk/src"), "trunk");
+        assert_eq!(get_branch_name("^/branches/feature/src"), "feature");
+        assert_eq!(get_branch_name("^/tags/v1.0"), "v1.0");
+        assert_eq!(get_branch_name("^/project"), "project");
+        assert_eq!(get_branch_name("^/"), "/");
+    }
+
+    #[test]
+    fn test_parse_svn_info() {
+        assert_eq!(
+            parse_svn_info(SVN_INFO),
+            Some(("feature".to_string(), "1234".to_string()))
+        );
+        assert_eq!(parse_svn_info(""), None);
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let actual = ModuleRenderer::new("svn")
+            .dir(".svn")
+            .cmd("svn info", SVN_INFO)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn not_in_working_copy() {
+        let actual = ModuleRenderer::new("svn")
+            .cmd("svn info", SVN_INFO)
+            .config(toml::toml! {
+                [svn]
+                disabled = false
+            })
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn branch_and_revision() {
+        let actual = ModuleRenderer::new("svn")
+            .dir(".svn")
+            .cmd("svn info", SVN_INFO)
+            .config(toml::toml! {
+                [svn]
+                disabled = false
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "on {} ",
+            Color::Purple.bold().paint(" feature (r1234)")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,163 @@
+use std::path::PathBuf;
+
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::terraform::TerraformConfig;
+use crate::utils;
+
+/// Creates a module with the current Terraform workspace and version
+///
+/// Will display the Terraform workspace if any of the following criteria are met:
+///     - Current directory contains a `.terraform` folder
+///     - Current directory contains a file with the `.tf` extension
+///
+/// The version is only displayed with `show_version = true`, since running
+/// `terraform version` is slow.
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_terraform_project = context
+        .try_begin_scan()?
+        .set_folders(&[".terraform"])
+        .set_extensions(&["tf"])
+        .is_match();
+
+    if !is_terraform_project {
+        return None;
+    }
+
+    let mut module = context.new_module("terraform");
+    let config = TerraformConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+
+    if config.show_version {
+        let terraform_version = context.exec_cmd("terraform", &["version"])?.stdout;
+        let formatted_version = format_terraform_version(&terraform_version)?;
+        module.create_segment(
+            "version",
+            &config
+                .version
+                .with_value(&format!("{} ", formatted_version)),
+        );
+    }
+
+    let workspace = get_terraform_workspace(context);
+    module.create_segment("workspace", &config.workspace.with_value(&workspace));
+
+    Some(module)
+}
+
+/// Get the workspace selected with `TF_WORKSPACE` or `terraform workspace
+/// select`, which is stored in the data directory of the project
+fn get_terraform_workspace(context: &Context) -> String {
+    if let Some(workspace) = context.get_env("TF_WORKSPACE") {
+        return workspace;
+    }
+
+    let data_dir = context
+        .get_env("TF_DATA_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(".terraform"));
+    let environment_file = context.current_dir.join(data_dir).join("environment");
+
+    utils::read_file(environment_file)
+        .ok()
+        .map(|workspace| workspace.trim().to_string())
+        .filter(|workspace| !workspace.is_empty())
+        .unwrap_or_else(|| "default".to_string())
+}
+
+fn format_terraform_version(terraform_stdout: &str) -> Option<String> {
+    // terraform version output looks like this:
+    // Terraform v0.12.14
+    // + provider.aws v2.40.0
+    let version = terraform_stdout
+        .lines()
+        .next()?
+        .trim()
+        .strip_prefix("Terraform ")?;
+
+    Some(version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_terraform_version() {
+        assert_eq!(
+            format_terraform_version("Terraform v0.12.14\n+ provider.aws v2.40.0\n"),
+            Some("v0.12.14".to_string())
+        );
+        assert_eq!(format_terraform_version("not terraform"), None);
+    }
+
+    #[test]
+    fn folder_without_terraform_files() {
+        let actual = ModuleRenderer::new("terraform").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_tf_file() {
+        let actual = ModuleRenderer::new("terraform")
+            .file("main.tf", "")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(105).bold().paint("💠 default")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_selected_workspace() {
+        let actual = ModuleRenderer::new("terraform")
+            .file(".terraform/environment", "development\n")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(105).bold().paint("💠 development")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn workspace_from_env() {
+        let actual = ModuleRenderer::new("terraform")
+            .file("main.tf", "")
+            .file(".terraform/environment", "development\n")
+            .env("TF_WORKSPACE", "production")
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(105).bold().paint("💠 production")
+        ));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn version_shown_when_enabled() {
+        let actual = ModuleRenderer::new("terraform")
+            .file("main.tf", "")
+            .cmd("terraform version", "Terraform v0.12.14\n")
+            .config(toml::toml! {
+                [terraform]
+                show_version = true
+            })
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::Fixed(105).bold().paint("💠 v0.12.14 default")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::format::{Item, StrftimeItems};
 use chrono::{DateTime, FixedOffset, Local, Utc};
 
 use super::{Context, Module};
@@ -7,16 +8,27 @@ use crate::configs::time::TimeConfig;
 
 /// Outputs the current time
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    const TIME_PREFIX: &str = "at ";
-
     let mut module = context.new_module("time");
     let config: TimeConfig = TimeConfig::try_load(module.config);
     if config.disabled {
+        log::debug!("Module \"time\" is disabled by default, set `disabled = false` to enable it");
         return None;
     };
 
     let default_format = if config.use_12hr { "%r" } else { "%T" };
-    let time_format = config.format.unwrap_or(default_format);
+    // `format` is the former name of `time_format`
+    let time_format = match config.time_format.or(config.format) {
+        Some(time_format) if is_valid_format(time_format) => time_format,
+        Some(time_format) => {
+            log::warn!(
+                "Invalid time_format \"{}\" provided! Falling back to \"{}\".",
+                time_format,
+                default_format
+            );
+            default_format
+        }
+        None => default_format,
+    };
 
     log::trace!(
         "Timer module is enabled with format string: {}",
@@ -39,7 +51,7 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
 
     module.set_style(config.style);
 
-    module.get_prefix().set_value(TIME_PREFIX);
+    module.get_prefix().set_value(context.locale().at);
 
     module.create_segment(
         "time",
@@ -77,6 +89,12 @@ fn create_offset_time_string(
     }
 }
 
+/// Check that a format string only has valid specifiers, as chrono panics when
+/// formatting with an invalid one
+fn is_valid_format(time_format: &str) -> bool {
+    StrftimeItems::new(time_format).all(|item| item != Item::Error)
+}
+
 /// Format a given time into the given string. This function should be referentially
 /// transparent, which makes it easy to test (unlike anything involving the actual time)
 fn format_time(time_format: &str, local_time: DateTime<Local>) -> String {
@@ -146,6 +164,14 @@ mod tests {
         assert_eq!(formatted, "[15:36:47]");
     }
 
+    #[test]
+    fn test_is_valid_format() {
+        assert!(is_valid_format("%T"));
+        assert!(is_valid_format("🕙[ %Y-%m-%d %H:%M:%S %z ]"));
+        assert!(!is_valid_format("%Q"));
+        assert!(!is_valid_format("%"));
+    }
+
     #[test]
     fn test_midnight_12hr_fixed_offset() {
         let timezone_offset = FixedOffset::east(0);
@@ -0,0 +1,91 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::typst::TypstConfig;
+
+/// Creates a module with the current Typst compiler version
+///
+/// Will display the Typst version if any of the following criteria are met:
+///     - Current directory contains a `typst.toml` file
+///     - Current directory contains a file with the `.typ` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_typst_project = context
+        .try_begin_scan()?
+        .set_files(&["typst.toml"])
+        .set_extensions(&["typ"])
+        .is_match();
+
+    if !is_typst_project {
+        return None;
+    }
+
+    let typst_version = context.exec_cmd("typst", &["--version"])?.stdout;
+    let formatted_version = format_typst_version(&typst_version)?;
+
+    let mut module = context.new_module("typst");
+    let config = TypstConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_typst_version(typst_stdout: &str) -> Option<String> {
+    // typst --version output looks like this:
+    // typst 0.11.0 (2bf9f95d)
+    let mut words = typst_stdout.split_whitespace();
+    if words.next()? != "typst" {
+        return None;
+    }
+
+    Some(format!("v{}", words.next()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_typst_version() {
+        assert_eq!(
+            format_typst_version("typst 0.11.0 (2bf9f95d)\n"),
+            Some("v0.11.0".to_string())
+        );
+        assert_eq!(format_typst_version("typst\n"), None);
+        assert_eq!(format_typst_version(""), None);
+    }
+
+    #[test]
+    fn folder_without_typst_files() {
+        let actual = ModuleRenderer::new("typst")
+            .cmd("typst --version", "typst 0.11.0 (2bf9f95d)\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_typ_file() {
+        let actual = ModuleRenderer::new("typst")
+            .file("paper.typ", "")
+            .cmd("typst --version", "typst 0.11.0 (2bf9f95d)\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("t v0.11.0")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_typst_toml() {
+        let actual = ModuleRenderer::new("typst")
+            .file("typst.toml", "")
+            .cmd("typst --version", "typst 0.11.0 (2bf9f95d)\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("t v0.11.0")));
+        assert_eq!(expected, actual);
+    }
+}
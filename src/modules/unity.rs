@@ -0,0 +1,81 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::unity::UnityConfig;
+use crate::utils;
+
+const PROJECT_VERSION_FILE: &str = "ProjectSettings/ProjectVersion.txt";
+
+/// Creates a module with the Unity editor version the current project expects
+///
+/// Will display the Unity version if the current directory contains a
+/// `ProjectSettings/ProjectVersion.txt` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let project_version = utils::read_file(context.current_dir.join(PROJECT_VERSION_FILE)).ok()?;
+    let unity_version = parse_editor_version(&project_version)?;
+
+    let mut module = context.new_module("unity");
+    let config = UnityConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&unity_version));
+
+    Some(module)
+}
+
+fn parse_editor_version(project_version: &str) -> Option<String> {
+    // ProjectVersion.txt looks like this:
+    // m_EditorVersion: 2019.2.11f1
+    // m_EditorVersionWithRevision: 2019.2.11f1 (5f859a4cfee5)
+    let version = project_version
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("m_EditorVersion:"))?
+        .trim();
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    const PROJECT_VERSION: &str = "m_EditorVersion: 2019.2.11f1
+m_EditorVersionWithRevision: 2019.2.11f1 (5f859a4cfee5)
+";
+
+    #[test]
+    fn test_parse_editor_version() {
+        assert_eq!(
+            parse_editor_version(PROJECT_VERSION),
+            Some("v2019.2.11f1".to_string())
+        );
+        assert_eq!(parse_editor_version("m_EditorVersion:\n"), None);
+        assert_eq!(parse_editor_version(""), None);
+    }
+
+    #[test]
+    fn folder_without_unity_project() {
+        let actual = ModuleRenderer::new("unity").dir("Assets").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_unity_project() {
+        let actual = ModuleRenderer::new("unity")
+            .file(PROJECT_VERSION_FILE, PROJECT_VERSION)
+            .collect();
+
+        let expected = Some(format!(
+            "via {} ",
+            Color::White.bold().paint("🎮 v2019.2.11f1")
+        ));
+        assert_eq!(expected, actual);
+    }
+}
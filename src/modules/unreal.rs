@@ -0,0 +1,111 @@
+use super::{Context, Module, RootModuleConfig};
+
+use serde_json as json;
+
+use crate::configs::unreal::UnrealConfig;
+use crate::context::has_extension;
+use crate::utils;
+
+/// Creates a module with the Unreal Engine version the current project is associated with
+///
+/// Will display the Unreal Engine version if the current directory contains a
+/// file with the `.uproject` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let project_file = context
+        .get_dir_files()
+        .ok()?
+        .iter()
+        .find(|path| has_extension(path, &["uproject"]))?;
+
+    let mut module = context.new_module("unreal");
+    let config = UnrealConfig::try_load(module.config);
+
+    let project = utils::read_file(project_file).ok()?;
+    let engine_version = match parse_engine_association(&project)? {
+        EngineAssociation::Release(version) => format!("v{}", version),
+        EngineAssociation::SourceBuild => config.source_build.to_string(),
+    };
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&engine_version));
+
+    Some(module)
+}
+
+#[derive(Debug, PartialEq)]
+enum EngineAssociation {
+    /// An installed release of the engine, e.g. "4.24"
+    Release(String),
+    /// An engine built from source, which is identified by a GUID
+    SourceBuild,
+}
+
+fn parse_engine_association(project: &str) -> Option<EngineAssociation> {
+    let project: json::Value = json::from_str(project).ok()?;
+    let association = project.get("EngineAssociation")?.as_str()?.trim();
+
+    if association.is_empty() {
+        None
+    } else if association.starts_with('{') {
+        Some(EngineAssociation::SourceBuild)
+    } else {
+        Some(EngineAssociation::Release(association.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_parse_engine_association() {
+        assert_eq!(
+            parse_engine_association(r#"{"FileVersion": 3, "EngineAssociation": "4.24"}"#),
+            Some(EngineAssociation::Release("4.24".to_string()))
+        );
+        assert_eq!(
+            parse_engine_association(
+                r#"{"EngineAssociation": "{B2A3D1E4-4C1B-9A0C-2D2F-5E6A0C3C1B7D}"}"#
+            ),
+            Some(EngineAssociation::SourceBuild)
+        );
+        assert_eq!(
+            parse_engine_association(r#"{"EngineAssociation": ""}"#),
+            None
+        );
+        assert_eq!(parse_engine_association("not json"), None);
+    }
+
+    #[test]
+    fn folder_without_uproject() {
+        let actual = ModuleRenderer::new("unreal").dir("Content").collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_uproject() {
+        let actual = ModuleRenderer::new("unreal")
+            .file("Rocket.uproject", r#"{"EngineAssociation": "4.24"}"#)
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🕹 v4.24")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_source_build_uproject() {
+        let actual = ModuleRenderer::new("unreal")
+            .file(
+                "Rocket.uproject",
+                r#"{"EngineAssociation": "{B2A3D1E4-4C1B-9A0C-2D2F-5E6A0C3C1B7D}"}"#,
+            )
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("🕹 source")));
+        assert_eq!(expected, actual);
+    }
+}
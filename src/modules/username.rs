@@ -1,6 +1,3 @@
-use std::env;
-use std::process::Command;
-
 use super::{Context, Module, RootModuleConfig, SegmentConfig};
 
 use crate::configs::username::UsernameConfig;
@@ -12,12 +9,12 @@ use crate::configs::username::UsernameConfig;
 ///     - The current user is root (UID = 0)
 ///     - The user is currently connected as an SSH session (`$SSH_CONNECTION`)
 pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
-    let user = env::var("USER").ok();
-    let logname = env::var("LOGNAME").ok();
-    let ssh_connection = env::var("SSH_CONNECTION").ok();
+    let user = context.get_env("USER");
+    let logname = context.get_env("LOGNAME");
+    let ssh_connection = context.get_env("SSH_CONNECTION");
 
     const ROOT_UID: Option<u32> = Some(0);
-    let user_uid = get_uid();
+    let user_uid = get_uid(context);
 
     let mut module = context.new_module("username");
     let config: UsernameConfig = UsernameConfig::try_load(module.config);
@@ -37,11 +34,49 @@ pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
     }
 }
 
-fn get_uid() -> Option<u32> {
-    match Command::new("id").arg("-u").output() {
-        Ok(output) => String::from_utf8(output.stdout)
-            .map(|uid| uid.trim().parse::<u32>().ok())
-            .ok()?,
-        Err(_) => None,
+fn get_uid(context: &Context) -> Option<u32> {
+    let output = context.exec_cmd("id", &["-u"])?;
+    output.stdout.trim().parse::<u32>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn root_user() {
+        let actual = ModuleRenderer::new("username")
+            .env("USER", "root")
+            .env("LOGNAME", "root")
+            .cmd("id -u", "0\n")
+            .collect();
+        let expected = Some(format!("via {} ", Color::Red.bold().paint("root")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn root_user_with_style() {
+        let actual = ModuleRenderer::new("username")
+            .env("USER", "root")
+            .env("LOGNAME", "root")
+            .cmd("id -u", "0\n")
+            .config(toml::toml! {
+                [username]
+                style_root = "bold purple"
+            })
+            .collect();
+        let expected = Some(format!("via {} ", Color::Purple.bold().paint("root")));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn regular_user() {
+        let actual = ModuleRenderer::new("username")
+            .env("USER", "astronaut")
+            .env("LOGNAME", "astronaut")
+            .cmd("id -u", "1000\n")
+            .collect();
+        assert_eq!(actual, None);
     }
 }
@@ -0,0 +1,32 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Truncate a text to `length` graphemes, ending it with the first grapheme of
+/// `truncation_symbol` when it was truncated
+pub fn truncate(text: &str, length: usize, truncation_symbol: &str) -> String {
+    if UnicodeSegmentation::graphemes(text, true).count() <= length {
+        return text.to_string();
+    }
+
+    let truncated: String = UnicodeSegmentation::graphemes(text, true)
+        .take(length)
+        .collect();
+    let symbol = UnicodeSegmentation::graphemes(truncation_symbol, true)
+        .next()
+        .unwrap_or("");
+
+    truncated + symbol
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("master", 10, "…"), "master");
+        assert_eq!(truncate("master", 6, "…"), "master");
+        assert_eq!(truncate("master", 3, "…"), "mas…");
+        assert_eq!(truncate("master", 3, ""), "mas");
+        assert_eq!(truncate("🦀🦀🦀", 2, "..."), "🦀🦀.");
+    }
+}
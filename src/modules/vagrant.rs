@@ -0,0 +1,73 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::vagrant::VagrantConfig;
+
+/// Creates a module with the current Vagrant version
+///
+/// Will display the Vagrant version if the current directory contains a `Vagrantfile` file
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_vagrant_project = context
+        .try_begin_scan()?
+        .set_files(&["Vagrantfile"])
+        .is_match();
+
+    if !is_vagrant_project {
+        return None;
+    }
+
+    let vagrant_version = context.exec_cmd("vagrant", &["--version"])?.stdout;
+    let formatted_version = format_vagrant_version(&vagrant_version)?;
+
+    let mut module = context.new_module("vagrant");
+    let config = VagrantConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_vagrant_version(vagrant_stdout: &str) -> Option<String> {
+    // vagrant --version output looks like this:
+    // Vagrant 2.2.10
+    let version = vagrant_stdout.trim().strip_prefix("Vagrant ")?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_vagrant_version() {
+        assert_eq!(
+            format_vagrant_version("Vagrant 2.2.10\n"),
+            Some("v2.2.10".to_string())
+        );
+        assert_eq!(format_vagrant_version("not vagrant"), None);
+    }
+
+    #[test]
+    fn folder_without_vagrantfile() {
+        let actual = ModuleRenderer::new("vagrant")
+            .cmd("vagrant --version", "Vagrant 2.2.10\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_vagrantfile() {
+        let actual = ModuleRenderer::new("vagrant")
+            .file("Vagrantfile", "Vagrant.configure(\"2\") do |config|\nend\n")
+            .cmd("vagrant --version", "Vagrant 2.2.10\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Cyan.bold().paint("⍱ v2.2.10")));
+        assert_eq!(expected, actual);
+    }
+}
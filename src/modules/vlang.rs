@@ -0,0 +1,90 @@
+use super::{Context, Module, RootModuleConfig};
+
+use crate::configs::vlang::VLangConfig;
+
+/// Creates a module with the current V version
+///
+/// Will display the V version if any of the following criteria are met:
+///     - Current directory contains a `v.mod` or `vpkg.json` file
+///     - Current directory contains a file with the `.v` extension
+pub fn module<'a>(context: &'a Context) -> Option<Module<'a>> {
+    let is_v_project = context
+        .try_begin_scan()?
+        .set_files(&["v.mod", "vpkg.json"])
+        .set_extensions(&["v"])
+        .is_match();
+
+    if !is_v_project {
+        return None;
+    }
+
+    let v_version = context.exec_cmd("v", &["version"])?.stdout;
+    let formatted_version = format_v_version(&v_version)?;
+
+    let mut module = context.new_module("vlang");
+    let config = VLangConfig::try_load(module.config);
+
+    module.set_style(config.style);
+    module.create_segment("symbol", &config.symbol);
+    module.create_segment("version", &config.version.with_value(&formatted_version));
+
+    Some(module)
+}
+
+fn format_v_version(v_stdout: &str) -> Option<String> {
+    // v version output looks like this:
+    // V 0.2.2 45edcef
+    let version = v_stdout
+        .trim()
+        .strip_prefix("V ")?
+        .split_whitespace()
+        .next()?;
+
+    Some(format!("v{}", version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::ModuleRenderer;
+    use ansi_term::Color;
+
+    #[test]
+    fn test_format_v_version() {
+        assert_eq!(
+            format_v_version("V 0.2.2 45edcef\n"),
+            Some("v0.2.2".to_string())
+        );
+        assert_eq!(format_v_version("not v"), None);
+    }
+
+    #[test]
+    fn folder_without_v_files() {
+        let actual = ModuleRenderer::new("vlang")
+            .cmd("v version", "V 0.2.2 45edcef\n")
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+
+    #[test]
+    fn folder_with_v_mod() {
+        let actual = ModuleRenderer::new("vlang")
+            .file("v.mod", "Module {\n\tname: 'rocket'\n}\n")
+            .cmd("v version", "V 0.2.2 45edcef\n")
+            .collect();
+
+        let expected = Some(format!("via {} ", Color::Blue.bold().paint("V v0.2.2")));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn folder_with_v_file_and_no_compiler() {
+        let actual = ModuleRenderer::new("vlang")
+            .file("main.v", "")
+            .cmd_output("v version", None)
+            .collect();
+
+        assert_eq!(None, actual);
+    }
+}
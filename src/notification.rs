@@ -0,0 +1,177 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use notify_rust::Notification;
+
+use crate::config::RootModuleConfig;
+use crate::configs::cmd_duration::CmdDurationConfig;
+use crate::context::Context;
+use crate::modules::cmd_duration::render_time;
+use crate::prompt_cache::remove_expired_session_files;
+
+/// How long to wait for the focused window to be known
+const FOCUS_CHECK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Show a desktop notification if the last command took longer than the
+/// `min_time_to_notify` of the `cmd_duration` module, and the terminal isn't
+/// focused.
+///
+/// Shells draw the prompt again after the same command, e.g. when the vi mode
+/// of fish changes, so a notification is only shown for the first prompt after
+/// a given command, told apart by its status, duration and start time.
+pub fn notify_finished_command(context: &Context) {
+    if context.is_module_disabled_in_config("cmd_duration") {
+        return;
+    }
+
+    let config = CmdDurationConfig::try_load(context.config.get_module_config("cmd_duration"));
+    if !config.show_notifications {
+        return;
+    }
+
+    let elapsed = match context
+        .properties
+        .get("cmd_duration")
+        .and_then(|elapsed| elapsed.parse::<u64>().ok())
+    {
+        Some(elapsed) => elapsed,
+        None => return,
+    };
+
+    let session_key = context.get_env("STARSHIP_SESSION_KEY").unwrap_or_default();
+    if session_key.is_empty() || !session_key.chars().all(|c| c.is_ascii_alphanumeric()) {
+        log::debug!("Not notifying, invalid session key {:?}", session_key);
+        return;
+    }
+
+    let status = context.properties.get("status_code").cloned();
+    let start = context.properties.get("cmd_start").cloned();
+    let last_command = format!(
+        "{} {} {}",
+        status.unwrap_or_default(),
+        elapsed,
+        start.unwrap_or_default()
+    );
+    let path = match dirs::cache_dir() {
+        Some(dir) => dir
+            .join("starship")
+            .join(format!("last_command_{}", session_key)),
+        None => return,
+    };
+    if !is_new_command(&path, &last_command) {
+        return;
+    }
+
+    if elapsed < config.min_time_to_notify.max(0) as u64 || is_terminal_focused(context) {
+        return;
+    }
+
+    let locale = context.locale();
+    let prefix = config.prefix.unwrap_or(locale.took);
+    let body = format!("{}{}", prefix, render_time(elapsed, &locale.duration_units));
+
+    let shown = Notification::new()
+        .appname("starship")
+        .summary("starship")
+        .body(&body)
+        .show();
+    if let Err(e) = shown {
+        log::debug!("Unable to show a notification: {}", e);
+    }
+}
+
+/// Check if the last command of the session, as its status, duration and
+/// start time, differs from the one of the previous prompt, and remember it
+fn is_new_command(path: &Path, last_command: &str) -> bool {
+    match fs::read_to_string(path) {
+        Ok(previous) if previous == last_command => return false,
+        Ok(_) => (),
+        // The first command of a session is a good time to forget the old ones
+        Err(_) => {
+            if let Some(dir) = path.parent() {
+                remove_expired_session_files(dir);
+            }
+        }
+    }
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, last_command));
+    if let Err(e) = result {
+        log::debug!("Unable to remember the last command in {:?}: {}", path, e);
+    }
+    true
+}
+
+/// Check if the frontmost app is the one which started the shell, from the
+/// bundle identifier macOS gives to the processes of an app
+#[cfg(target_os = "macos")]
+fn is_terminal_focused(context: &Context) -> bool {
+    let terminal = match context.get_env("__CFBundleIdentifier") {
+        Some(terminal) => terminal,
+        None => return false,
+    };
+
+    context
+        .exec_cmd_with_timeout(
+            "osascript",
+            &[
+                "-e",
+                "id of application (path to frontmost application as text)",
+            ],
+            FOCUS_CHECK_TIMEOUT,
+        )
+        .is_some_and(|output| output.stdout.trim() == terminal)
+}
+
+/// Check if the console window of the shell is the foreground window
+#[cfg(windows)]
+fn is_terminal_focused(_context: &Context) -> bool {
+    use winapi::um::wincon::GetConsoleWindow;
+    use winapi::um::winuser::GetForegroundWindow;
+
+    // Both functions only return a window handle, which is null if there is
+    // no such window
+    unsafe {
+        let console = GetConsoleWindow();
+        !console.is_null() && console == GetForegroundWindow()
+    }
+}
+
+/// Check if the active X11 window is the one of the terminal, which most
+/// terminals export as `$WINDOWID`
+#[cfg(not(any(target_os = "macos", windows)))]
+fn is_terminal_focused(context: &Context) -> bool {
+    let terminal = match context.get_env("WINDOWID") {
+        Some(terminal) => terminal,
+        None => return false,
+    };
+
+    context
+        .exec_cmd_with_timeout("xdotool", &["getactivewindow"], FOCUS_CHECK_TIMEOUT)
+        .is_some_and(|output| output.stdout.trim() == terminal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn notifies_once_per_command() -> io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("starship").join("last_command_1234");
+
+        assert!(is_new_command(&path, "0 60 1600000000"));
+        // The prompt drawn again after the same command
+        assert!(!is_new_command(&path, "0 60 1600000000"));
+        assert!(is_new_command(&path, "1 60 1600000000"));
+        assert!(is_new_command(&path, "1 90 1600000000"));
+        // Another command with the same status and duration
+        assert!(is_new_command(&path, "1 90 1600000100"));
+
+        dir.close()
+    }
+}
@@ -3,27 +3,125 @@ use rayon::prelude::*;
 use std::fmt::Write as FmtWrite;
 use std::io::{self, Write};
 
+use crate::badge;
 use crate::context::Context;
-use crate::module::Module;
-use crate::module::ALL_MODULES;
+use crate::module::{Module, Priority, ALL_MODULES};
 use crate::modules;
+use crate::notification;
+use crate::profile;
+use crate::prompt_cache::PromptCache;
+use crate::utils;
 
 pub fn prompt(args: ArgMatches) {
+    let profile_output = args.value_of("profile_output").map(str::to_owned);
+    if profile_output.is_some() {
+        profile::enable();
+    }
+
+    let tmux = args.is_present("tmux");
     let context = Context::new(args);
     let stdout = io::stdout();
     let mut handle = stdout.lock();
-    write!(handle, "{}", get_prompt(context)).unwrap();
+
+    let prompt = if tmux {
+        profile::record("prompt", "prompt", || get_tmux_prompt(&context))
+    } else {
+        notification::notify_finished_command(&context);
+
+        let cache = PromptCache::new(&context);
+        match cache.as_ref().and_then(PromptCache::load) {
+            Some(prompt) => prompt,
+            None => {
+                let prompt = profile::record("prompt", "prompt", || get_prompt(context));
+                if let Some(cache) = &cache {
+                    cache.store(&prompt);
+                }
+                prompt
+            }
+        }
+    };
+    write!(handle, "{}", prompt).unwrap();
+
+    if let Some(profile_output) = profile_output {
+        if let Err(e) = profile::write_to_file(&profile_output) {
+            log::warn!("Unable to write profile to {}: {}", profile_output, e);
+        }
+    }
 }
 
 pub fn get_prompt(context: Context) -> String {
     let config = context.config.get_root_config();
     let mut buf = String::new();
 
+    // Let the terminal show the current context outside of the prompt
+    if config.badge && !config.screen_reader {
+        write!(buf, "{}", badge::escapes(&context)).unwrap();
+    }
+
     // Write a new line before the prompt
     if config.add_newline {
         writeln!(buf).unwrap();
     }
 
+    let mut modules = compute_modules(&context);
+
+    if config.screen_reader {
+        write!(buf, "{}", screen_reader_prompt(&modules)).unwrap();
+    } else {
+        if let Some(width) = context.terminal_width() {
+            for line in modules.split_mut(|module| module.get_name() == "line_break") {
+                fill_line(line, width);
+            }
+        }
+
+        let mut print_without_prefix = true;
+        let printable = modules.iter();
+
+        for module in printable {
+            // Skip printing the prefix of a module after the line_break
+            if print_without_prefix {
+                let module_without_prefix = module.to_string_without_prefix();
+                write!(buf, "{}", module_without_prefix).unwrap()
+            } else {
+                write!(buf, "{}", module).unwrap();
+            }
+
+            print_without_prefix = module.get_name() == "line_break"
+        }
+    }
+
+    // tcsh splits the command substitution setting the prompt on every
+    // newline, so they are written as the `\n` it expands in the prompt,
+    // which needs a space before it
+    if context.get_env("STARSHIP_SHELL").as_deref() == Some("tcsh") {
+        buf = buf.replace('\n', " \\n");
+    }
+
+    buf
+}
+
+/// Render the prompt for the tmux status line, styled with tmux's `#[...]`
+/// syntax instead of ANSI escape sequences. Line breaks and the prompt
+/// character don't belong in a status line, so they are left out.
+pub fn get_tmux_prompt(context: &Context) -> String {
+    let modules = compute_modules(context);
+
+    modules
+        .iter()
+        .filter(|module| !["line_break", "character", "fill"].contains(&module.get_name().as_str()))
+        .enumerate()
+        .map(|(i, module)| module.to_tmux_string(i > 0))
+        .collect()
+}
+
+/// Compute the modules of the prompt which are enabled and fit the terminal
+fn compute_modules<'a>(context: &'a Context) -> Vec<Module<'a>> {
+    let config = context.config.get_root_config();
+
+    // Leave out low priority modules when the terminal is too narrow to fit them
+    let is_narrow =
+        matches!(context.terminal_width(), Some(width) if width < config.responsive_width);
+
     let mut prompt_order: Vec<&str> = Vec::new();
 
     // Write out a custom prompt order
@@ -39,38 +137,124 @@ pub fn get_prompt(context: Context) -> String {
         }
     }
 
-    let modules = &prompt_order
+    prompt_order
         .par_iter()
-        .filter(|module| !context.is_module_disabled_in_config(module))
-        .map(|module| modules::handle(module, &context)) // Compute modules
+        .filter(|module| {
+            let disabled = context.is_module_disabled_in_config(module);
+            if disabled {
+                log::debug!("Skipping module \"{}\": disabled in config", module);
+            }
+            !disabled
+        })
+        .filter(|module| {
+            let hidden = is_narrow && context.get_module_priority(module) == Priority::Low;
+            if hidden {
+                log::debug!(
+                    "Skipping module \"{}\": low priority on a narrow terminal",
+                    module
+                );
+            }
+            !hidden
+        })
+        .map(|module| compute_module(module, context)) // Compute modules
         .flatten()
-        .collect::<Vec<Module>>(); // Remove segments set to `None`
+        .collect::<Vec<Module>>() // Remove segments set to `None`
+}
+
+/// Stretch the `fill` modules of a line so that the modules after them are
+/// aligned to the right edge of the terminal. The space left on the line is
+/// shared between the `fill` modules if there are several.
+fn fill_line(line: &mut [Module], terminal_width: usize) {
+    let is_fill = |module: &Module| module.get_name() == "fill";
+    let fill_count = line.iter().filter(|module| is_fill(module)).count();
+    if fill_count == 0 {
+        return;
+    }
 
+    // The first module of a line is printed without its prefix
+    let used_width: usize = line
+        .iter()
+        .enumerate()
+        .filter(|(_, module)| !is_fill(module))
+        .map(|(i, module)| match i {
+            0 => utils::visible_width(&module.to_string_without_prefix()),
+            _ => utils::visible_width(&module.to_string()),
+        })
+        .sum();
+    let free_width = terminal_width.saturating_sub(used_width);
+
+    for (i, module) in line.iter_mut().filter(|module| is_fill(module)).enumerate() {
+        let extra = if i < free_width % fill_count { 1 } else { 0 };
+        module.stretch_to(free_width / fill_count + extra);
+    }
+}
+
+/// Join modules as plain words, separated by single spaces
+fn screen_reader_prompt(modules: &[Module]) -> String {
+    let mut prompt = String::new();
     let mut print_without_prefix = true;
-    let printable = modules.iter();
 
-    for module in printable {
-        // Skip printing the prefix of a module after the line_break
-        if print_without_prefix {
-            let module_without_prefix = module.to_string_without_prefix();
-            write!(buf, "{}", module_without_prefix).unwrap()
-        } else {
-            write!(buf, "{}", module).unwrap();
+    for module in modules {
+        let words = module.to_screen_reader_string(!print_without_prefix);
+        if words.is_empty() {
+            continue;
+        }
+        if !prompt.is_empty() && !prompt.ends_with('\n') && words != "\n" {
+            prompt.push(' ');
         }
+        prompt.push_str(&words);
 
         print_without_prefix = module.get_name() == "line_break"
     }
 
-    buf
+    // Keep what the user types apart from the prompt
+    if !prompt.ends_with('\n') {
+        prompt.push(' ');
+    }
+    prompt
 }
 
 pub fn module(module_name: &str, args: ArgMatches) {
+    let tmux = args.is_present("tmux");
     let context = Context::new(args);
+    let screen_reader = context.config.get_root_config().screen_reader;
 
     // If the module returns `None`, print an empty string
-    let module = modules::handle(module_name, &context)
-        .map(|m| m.to_string())
+    let module = compute_module(module_name, &context)
+        .map(|m| {
+            if tmux {
+                m.to_tmux_string(true)
+            } else if screen_reader {
+                m.to_screen_reader_string(true)
+            } else {
+                m.to_string()
+            }
+        })
         .unwrap_or_default();
 
     print!("{}", module);
 }
+
+/// Compute a single module, logging why it was skipped if it produced nothing to show
+fn compute_module<'a>(module_name: &str, context: &'a Context) -> Option<Module<'a>> {
+    let module = profile::record("module", module_name, || {
+        modules::handle(module_name, context)
+    });
+
+    match &module {
+        None if context.dir_scan_timed_out() => log::debug!(
+            "Skipping module \"{}\": scanning the current directory timed out",
+            module_name
+        ),
+        None => log::debug!(
+            "Skipping module \"{}\": no detection files matched or nothing to show",
+            module_name
+        ),
+        Some(module) if module.is_empty() => {
+            log::debug!("Module \"{}\" has an empty value", module_name)
+        }
+        Some(_) => {}
+    }
+
+    module
+}
@@ -0,0 +1,142 @@
+use once_cell::sync::Lazy;
+use serde_json::json;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The moment profiling was enabled, used as the origin of all event timestamps.
+static EPOCH: Lazy<Instant> = Lazy::new(Instant::now);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static EVENTS: Lazy<Mutex<Vec<TraceEvent>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+static NEXT_THREAD_ID: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    // `std::thread::ThreadId` can't be turned into a number on stable Rust,
+    // so every thread is handed a small sequential id the first time it records.
+    static THREAD_ID: usize = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// A single "complete" event in the Chrome tracing format.
+///
+/// See: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+#[derive(Debug, Clone)]
+struct TraceEvent {
+    name: String,
+    category: &'static str,
+    start: Duration,
+    duration: Duration,
+    thread_id: usize,
+}
+
+/// Start recording profiling events for the rest of this process.
+pub fn enable() {
+    Lazy::force(&EPOCH);
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Whether profiling events are currently being recorded.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Run `f`, recording how long it took under the given category and name.
+///
+/// When profiling is disabled this is just a call to `f`.
+pub fn record<T, F>(category: &'static str, name: &str, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    if !is_enabled() {
+        return f();
+    }
+
+    let start = Instant::now();
+    let result = f();
+    let duration = start.elapsed();
+
+    let event = TraceEvent {
+        name: name.to_string(),
+        category,
+        start: start.duration_since(*EPOCH),
+        duration,
+        thread_id: current_thread_id(),
+    };
+    EVENTS.lock().unwrap().push(event);
+
+    result
+}
+
+/// Write all recorded events as a Chrome tracing JSON file to `path`.
+pub fn write_to_file<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let events = EVENTS.lock().unwrap();
+    let mut file = File::create(path)?;
+    write!(file, "{}", to_trace_json(&events))
+}
+
+fn to_trace_json(events: &[TraceEvent]) -> serde_json::Value {
+    let trace_events: Vec<serde_json::Value> = events
+        .iter()
+        .map(|event| {
+            json!({
+                "name": event.name,
+                "cat": event.category,
+                "ph": "X",
+                "ts": event.start.as_micros() as u64,
+                "dur": event.duration.as_micros() as u64,
+                "pid": std::process::id(),
+                "tid": event.thread_id,
+            })
+        })
+        .collect();
+
+    json!({
+        "traceEvents": trace_events,
+        "displayTimeUnit": "ms",
+    })
+}
+
+fn current_thread_id() -> usize {
+    THREAD_ID.with(|id| *id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_trace_json() {
+        let events = vec![TraceEvent {
+            name: "rust".to_string(),
+            category: "module",
+            start: Duration::from_micros(1500),
+            duration: Duration::from_micros(250),
+            thread_id: 2,
+        }];
+
+        let trace = to_trace_json(&events);
+        let event = &trace["traceEvents"][0];
+
+        assert_eq!(event["name"], "rust");
+        assert_eq!(event["cat"], "module");
+        assert_eq!(event["ph"], "X");
+        assert_eq!(event["ts"], 1500);
+        assert_eq!(event["dur"], 250);
+        assert_eq!(event["tid"], 2);
+    }
+
+    #[test]
+    fn test_thread_ids_are_stable() {
+        let first = current_thread_id();
+        let second = current_thread_id();
+        let other = std::thread::spawn(current_thread_id).join().unwrap();
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+}
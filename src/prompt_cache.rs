@@ -0,0 +1,534 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::context::Context;
+
+/// Modules showing something that changes from one prompt to the next even
+/// when nothing else does, and whether they are enabled by default. A prompt
+/// showing one of them is never cached.
+const VOLATILE_MODULES: &[(&str, bool)] = &[
+    ("battery", cfg!(feature = "battery")),
+    ("localip", false),
+    ("memory_usage", false),
+    ("sudo", false),
+    ("time", false),
+];
+
+/// Environment variables which change between prompts without affecting them
+const IGNORED_ENV_VARS: &[&str] = &["_", "OLDPWD", "STARSHIP_START_TIME", "STARSHIP_END_TIME"];
+
+/// Properties which change between commands without affecting the prompt
+const IGNORED_PROPERTIES: &[&str] = &["cmd_start"];
+
+/// Files which modules look for in the current directory and its parents
+const ANCESTOR_FILES: &[&str] = &[
+    ".envrc",
+    ".fslckout",
+    ".hg/branch",
+    ".python-version",
+    ".scala-version",
+    ".svn/wc.db",
+    "_FOSSIL_",
+    "global.json",
+    "gradle/wrapper/gradle-wrapper.properties",
+    "MODULE.bazel",
+    "Pulumi.yaml",
+    "Pulumi.yml",
+    "rust-toolchain",
+    "WORKSPACE",
+    "WORKSPACE.bazel",
+];
+
+/// Files in the git directory which change along with the branch, the commits
+/// and the state of the repository
+const GIT_FILES: &[&str] = &["HEAD", "index", "logs/HEAD", "FETCH_HEAD"];
+
+/// How long the files of a shell session are kept after they were last
+/// written, since nothing tells when a session ends
+const SESSION_FILE_LIFETIME: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Where the last prompt of a shell session is cached, along with the
+/// fingerprint of the context it was rendered in
+pub struct PromptCache {
+    path: PathBuf,
+    fingerprint: String,
+}
+
+impl PromptCache {
+    /// Get the cache for the shell session of the context, if prompt caching
+    /// is enabled and the session can be identified.
+    ///
+    /// Sessions are identified by `$STARSHIP_SESSION_KEY`, which is set by
+    /// `starship init`.
+    pub fn new(context: &Context) -> Option<PromptCache> {
+        if !context.config.get_root_config().cache_prompt {
+            return None;
+        }
+
+        if let Some((module, _)) = VOLATILE_MODULES
+            .iter()
+            .find(|(module, enabled_by_default)| {
+                is_module_shown(context, module, *enabled_by_default)
+            })
+        {
+            log::debug!("Not caching the prompt, the {} module is enabled", module);
+            return None;
+        }
+
+        let session_key = context.get_env("STARSHIP_SESSION_KEY")?;
+        if session_key.is_empty() || !session_key.chars().all(|c| c.is_ascii_alphanumeric()) {
+            log::debug!(
+                "Not caching the prompt, invalid session key {:?}",
+                session_key
+            );
+            return None;
+        }
+
+        let path = dirs::cache_dir()?
+            .join("starship")
+            .join(format!("prompt_{}", session_key));
+
+        Some(PromptCache {
+            path,
+            fingerprint: format!("{:016x}", fingerprint(context)),
+        })
+    }
+
+    /// Get the cached prompt, if it was rendered in an identical context
+    pub fn load(&self) -> Option<String> {
+        let contents = fs::read_to_string(&self.path).ok()?;
+        let (fingerprint, prompt) = contents.split_once('\n')?;
+
+        if fingerprint == self.fingerprint {
+            log::debug!("Using the cached prompt from {:?}", self.path);
+            Some(prompt.to_string())
+        } else {
+            log::trace!("The context changed since the prompt was cached");
+            None
+        }
+    }
+
+    /// Cache the prompt rendered in the current context
+    pub fn store(&self, prompt: &str) {
+        // The first prompt of a session is a good time to forget the old ones
+        if !self.path.exists() {
+            if let Some(dir) = self.path.parent() {
+                remove_expired_session_files(dir);
+            }
+        }
+
+        let result = self
+            .path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&self.path, format!("{}\n{}", self.fingerprint, prompt)));
+
+        if let Err(e) = result {
+            log::debug!("Unable to cache the prompt in {:?}: {}", self.path, e);
+        }
+    }
+}
+
+/// Check if a module is in the prompt order and isn't disabled
+fn is_module_shown(context: &Context, name: &str, enabled_by_default: bool) -> bool {
+    if !context
+        .config
+        .get_root_config()
+        .prompt_order
+        .contains(&name)
+    {
+        return false;
+    }
+
+    let disabled = context
+        .config
+        .get_module_config(name)
+        .and_then(|table| table.as_table()?.get("disabled")?.as_bool());
+
+    match disabled {
+        Some(disabled) => !disabled,
+        None => enabled_by_default,
+    }
+}
+
+/// The files outside of the current directory which modules read their state
+/// from, e.g. the kubeconfig of the `kubernetes` module
+fn external_files(context: &Context) -> Vec<PathBuf> {
+    let home = dirs::home_dir();
+    let in_home = |path: &str| home.as_ref().map(|home| home.join(path));
+    let env_path = |name: &str, default: &str| {
+        context
+            .get_env(name)
+            .map(PathBuf::from)
+            .or_else(|| in_home(default))
+    };
+    let mut files = Vec::new();
+
+    match context.get_env("KUBECONFIG") {
+        Some(paths) => files.extend(env::split_paths(&paths)),
+        None => files.extend(in_home(".kube/config")),
+    }
+    files.extend(env_path("AWS_CONFIG_FILE", ".aws/config"));
+    if let Some(gcloud) = env_path("CLOUDSDK_CONFIG", ".config/gcloud") {
+        files.push(gcloud.join("active_config"));
+        files.extend(dir_entries(&gcloud.join("configurations")));
+    }
+    if let Some(azure) = env_path("AZURE_CONFIG_DIR", ".azure") {
+        files.push(azure.join("azureProfile.json"));
+    }
+    files.extend(in_home(".config/openstack/clouds.yaml"));
+    files.push(PathBuf::from("/etc/openstack/clouds.yaml"));
+    if let Some(pulumi) = env_path("PULUMI_HOME", ".pulumi") {
+        files.push(pulumi.join("credentials.json"));
+        files.extend(dir_entries(&pulumi.join("workspaces")));
+    }
+    let terraform = context
+        .get_env("TF_DATA_DIR")
+        .unwrap_or_else(|| String::from(".terraform"));
+    files.push(context.current_dir.join(terraform).join("environment"));
+    files.extend(env_path("PYENV_ROOT", ".pyenv").map(|pyenv| pyenv.join("version")));
+    files.push(PathBuf::from("/run/.containerenv"));
+    files.push(PathBuf::from("/.dockerenv"));
+
+    files
+}
+
+fn dir_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    entries.sort();
+    entries
+}
+
+/// Remove the files of the shell sessions which haven't been used for
+/// `SESSION_FILE_LIFETIME`: their cached prompt and their last command
+pub fn remove_expired_session_files(dir: &Path) {
+    let expired = |path: &Path| {
+        modified_time(path)
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > SESSION_FILE_LIFETIME)
+    };
+
+    for path in dir_entries(dir) {
+        let is_session_file = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("prompt_") || name.starts_with("last_command_"));
+        if is_session_file && expired(&path) {
+            if let Err(e) = fs::remove_file(&path) {
+                log::debug!("Unable to remove the expired {:?}: {}", path, e);
+            }
+        }
+    }
+}
+
+/// The files of the working tree which are in the index of the repository,
+/// along with the directories containing them, which change when untracked
+/// files are added
+fn working_tree_files(context: &Context) -> Vec<PathBuf> {
+    let repo = match context.get_repo() {
+        Ok(repo) => repo,
+        Err(_) => return Vec::new(),
+    };
+    let (root, repository) = match (&repo.root, repo.open()) {
+        (Some(root), Some(repository)) => (root, repository),
+        _ => return Vec::new(),
+    };
+    let index = match repository.index() {
+        Ok(index) => index,
+        Err(_) => return Vec::new(),
+    };
+
+    let files: Vec<PathBuf> = index
+        .iter()
+        .filter_map(|entry| Some(root.join(std::str::from_utf8(&entry.path).ok()?)))
+        .collect();
+    let dirs: BTreeSet<&Path> = files
+        .iter()
+        .flat_map(|file| {
+            file.ancestors()
+                .skip(1)
+                .take_while(move |dir| dir.starts_with(root))
+        })
+        .collect();
+
+    dirs.into_iter()
+        .map(Path::to_path_buf)
+        .chain(files.iter().cloned())
+        .collect()
+}
+
+/// Hash everything the prompt depends on: the current directory, its files
+/// and its git repository, the files modules read outside of it, the
+/// arguments of `starship prompt`, the environment and the configuration
+fn fingerprint(context: &Context) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    context.current_dir.hash(&mut hasher);
+    modified_time(&context.current_dir).hash(&mut hasher);
+
+    // Editing a file, e.g. the version of a package.json, leaves the mtime
+    // of its directory as it is
+    if let Ok(files) = context.get_dir_files() {
+        for file in files {
+            modified_time(file).hash(&mut hasher);
+        }
+    }
+    for dir in context.current_dir.ancestors() {
+        for file in ANCESTOR_FILES {
+            modified_time(&dir.join(file)).hash(&mut hasher);
+        }
+    }
+
+    let git_dir = context
+        .get_repo()
+        .ok()
+        .and_then(|repo| Some(repo.open()?.path().to_path_buf()));
+    if let Some(git_dir) = git_dir {
+        for file in GIT_FILES {
+            modified_time(&git_dir.join(file)).hash(&mut hasher);
+        }
+    }
+
+    // Editing a tracked file changes the git status without touching the
+    // index, so the working tree is only skipped when no module shows it
+    if is_module_shown(context, "git_status", true)
+        || is_module_shown(context, "git_metrics", false)
+    {
+        for file in working_tree_files(context) {
+            modified_time(&file).hash(&mut hasher);
+        }
+    }
+
+    for file in external_files(context) {
+        modified_time(&file).hash(&mut hasher);
+    }
+
+    let mut properties: Vec<_> = context
+        .properties
+        .iter()
+        .filter(|(key, _)| !IGNORED_PROPERTIES.contains(key))
+        .collect();
+    properties.sort();
+    properties.hash(&mut hasher);
+
+    context
+        .env
+        .all()
+        .iter()
+        .filter(|(key, _)| !IGNORED_ENV_VARS.contains(&key.as_str()))
+        .for_each(|variable| variable.hash(&mut hasher));
+
+    if let Some(config) = &context.config.config {
+        config.to_string().hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StarshipConfig;
+    use crate::context::Env;
+    use std::collections::HashMap;
+
+    fn context_with_env<'a>(dir: &Path, vars: &[(&str, &str)]) -> Context<'a> {
+        let config = StarshipConfig {
+            config: Some(toml::toml! {
+                cache_prompt = true
+            }),
+        };
+        let mut context = Context::new_with_config(config, HashMap::new(), dir);
+        context.env = Env::isolated();
+        for (key, value) in vars {
+            context.env.insert(*key, *value);
+        }
+        context
+    }
+
+    /// Change the modification time of a file or directory as if it had been
+    /// written `seconds` ago
+    fn backdate(path: &Path, seconds: u64) -> std::io::Result<()> {
+        let modified = SystemTime::now() - Duration::from_secs(seconds);
+        fs::File::open(path)?.set_modified(modified)
+    }
+
+    #[test]
+    fn fingerprint_ignores_volatile_env_vars() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = context_with_env(dir.path(), &[("OLDPWD", "/"), ("HOME", "/root")]);
+        let second = context_with_env(dir.path(), &[("OLDPWD", "/tmp"), ("HOME", "/root")]);
+        let third = context_with_env(dir.path(), &[("OLDPWD", "/"), ("HOME", "/home")]);
+
+        assert_eq!(fingerprint(&first), fingerprint(&second));
+        assert_ne!(fingerprint(&first), fingerprint(&third));
+    }
+
+    #[test]
+    fn fingerprint_depends_on_properties() {
+        let dir = tempfile::tempdir().unwrap();
+        let success = context_with_env(dir.path(), &[]);
+        let mut failure = context_with_env(dir.path(), &[]);
+        failure.properties.insert("status_code", "1".to_string());
+        let mut later = context_with_env(dir.path(), &[]);
+        later
+            .properties
+            .insert("cmd_start", "1600000000".to_string());
+
+        assert_ne!(fingerprint(&success), fingerprint(&failure));
+        assert_eq!(fingerprint(&success), fingerprint(&later));
+    }
+
+    #[test]
+    fn fingerprint_depends_on_external_files() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let kubeconfig = dir.path().join("kubeconfig");
+        fs::write(&kubeconfig, "current-context: a")?;
+        let context = context_with_env(dir.path(), &[("KUBECONFIG", kubeconfig.to_str().unwrap())]);
+        let before = fingerprint(&context);
+
+        backdate(&kubeconfig, 60)?;
+
+        assert_ne!(before, fingerprint(&context));
+        dir.close()
+    }
+
+    #[test]
+    fn fingerprint_depends_on_files_of_the_current_dir() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let project = dir.path().join("project");
+        fs::create_dir(&project)?;
+        let package_json = project.join("package.json");
+        fs::write(&package_json, r#"{"version": "1.0.0"}"#)?;
+        let python_version = dir.path().join(".python-version");
+        fs::write(&python_version, "3.8.0")?;
+        let context = context_with_env(&project, &[]);
+        let before = fingerprint(&context);
+
+        backdate(&package_json, 60)?;
+        let edited = fingerprint(&context);
+        assert_ne!(before, edited);
+
+        backdate(&python_version, 60)?;
+        assert_ne!(edited, fingerprint(&context));
+        dir.close()
+    }
+
+    #[test]
+    fn fingerprint_depends_on_the_working_tree() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir(&src)?;
+        let main = src.join("main.rs");
+        fs::write(&main, "fn main() {}")?;
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("src/main.rs")).unwrap();
+        index.write().unwrap();
+        let context = context_with_env(dir.path(), &[]);
+        let before = fingerprint(&context);
+
+        // The file is edited, not added to the index
+        backdate(&main, 60)?;
+        let edited = fingerprint(&context);
+        assert_ne!(before, edited);
+
+        // A new file in a subdirectory
+        fs::write(src.join("lib.rs"), "")?;
+        backdate(&src, 30)?;
+        assert_ne!(edited, fingerprint(&context));
+        dir.close()
+    }
+
+    #[test]
+    fn expired_session_files_are_removed() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        for file in &["prompt_1", "prompt_2", "last_command_1", "other_1"] {
+            fs::write(dir.path().join(file), "")?;
+        }
+        let eight_days = 8 * 24 * 60 * 60;
+        backdate(&dir.path().join("prompt_1"), eight_days)?;
+        backdate(&dir.path().join("last_command_1"), eight_days)?;
+        backdate(&dir.path().join("other_1"), eight_days)?;
+
+        remove_expired_session_files(dir.path());
+
+        assert!(!dir.path().join("prompt_1").exists());
+        assert!(!dir.path().join("last_command_1").exists());
+        assert!(dir.path().join("prompt_2").exists());
+        assert!(dir.path().join("other_1").exists());
+        dir.close()
+    }
+
+    #[test]
+    fn volatile_modules_disable_the_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut context = context_with_env(dir.path(), &[("STARSHIP_SESSION_KEY", "1234")]);
+        context.config = StarshipConfig {
+            config: Some(toml::toml! {
+                cache_prompt = true
+                [sudo]
+                disabled = false
+            }),
+        };
+        assert!(PromptCache::new(&context).is_none());
+
+        context.config = StarshipConfig {
+            config: Some(toml::toml! {
+                cache_prompt = true
+                prompt_order = ["directory", "character"]
+                [sudo]
+                disabled = false
+            }),
+        };
+        assert!(PromptCache::new(&context).is_some());
+    }
+
+    #[test]
+    fn cache_requires_a_session_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let without_key = context_with_env(dir.path(), &[]);
+        let with_invalid_key = context_with_env(dir.path(), &[("STARSHIP_SESSION_KEY", "../x")]);
+        let with_key = context_with_env(dir.path(), &[("STARSHIP_SESSION_KEY", "1234")]);
+
+        assert!(PromptCache::new(&without_key).is_none());
+        assert!(PromptCache::new(&with_invalid_key).is_none());
+        assert!(PromptCache::new(&with_key).is_some());
+    }
+
+    #[test]
+    fn store_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = PromptCache {
+            path: dir.path().join("prompt_1234"),
+            fingerprint: String::from("0123456789abcdef"),
+        };
+        let stale_cache = PromptCache {
+            path: dir.path().join("prompt_1234"),
+            fingerprint: String::from("fedcba9876543210"),
+        };
+
+        assert_eq!(cache.load(), None);
+        cache.store("\nvia 🦀 v1.40.0\n❯ ");
+        assert_eq!(cache.load(), Some(String::from("\nvia 🦀 v1.40.0\n❯ ")));
+        assert_eq!(stale_cache.load(), None);
+    }
+}
@@ -13,6 +13,9 @@ pub struct Segment {
 
     /// The string value of the current segment.
     value: String,
+
+    /// The URL the segment links to, in terminals supporting hyperlinks.
+    link: Option<String>,
 }
 
 impl Segment {
@@ -22,6 +25,7 @@ impl Segment {
             _name: name.to_string(),
             style: None,
             value: "".to_string(),
+            link: None,
         }
     }
 
@@ -45,6 +49,35 @@ impl Segment {
         self
     }
 
+    /// Make the segment a hyperlink to the given URL.
+    pub fn set_link<T>(&mut self, url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.link = Some(url.into());
+        self
+    }
+
+    /// Get the segment's style
+    pub fn get_style(&self) -> Style {
+        self.style.unwrap_or_default()
+    }
+
+    /// Get the URL the segment links to, if any
+    pub fn get_link(&self) -> Option<&str> {
+        self.link.as_deref()
+    }
+
+    /// Get the segment's name
+    pub fn get_name(&self) -> &str {
+        &self._name
+    }
+
+    /// Get the segment's value, without any styling
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+
     // Returns the ANSIString of the segment value, not including its prefix and suffix
     pub fn ansi_string(&self) -> ANSIString {
         match self.style {
@@ -0,0 +1,129 @@
+// Not every helper is used by the binary's own copy of the tests
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+use crate::config::StarshipConfig;
+use crate::context::{Context, Env};
+use crate::modules;
+
+pub use crate::utils::CommandOutput;
+
+/// Renders a single module against a fake context, to be used in tests.
+///
+/// By default the module is rendered in a fresh, empty temporary directory,
+/// with an empty configuration and an empty environment, so that the output
+/// doesn't depend on the machine running the test.
+///
+/// ```ignore
+/// let actual = ModuleRenderer::new("golang")
+///     .file("main.go", "")
+///     .cmd("go version", "go version go1.12.1 linux/amd64\n")
+///     .collect();
+/// ```
+pub struct ModuleRenderer<'a> {
+    name: &'a str,
+    context: Context<'a>,
+    // Keeps the temporary directory alive until the module has been rendered
+    _temp_dir: Option<TempDir>,
+}
+
+impl<'a> ModuleRenderer<'a> {
+    /// Create a renderer for the module with the given name
+    pub fn new(name: &'a str) -> Self {
+        let temp_dir = tempfile::tempdir().expect("Unable to create a temporary directory");
+        let config = StarshipConfig {
+            config: Some(toml::Value::Table(toml::value::Table::new())),
+        };
+        let mut context = Context::new_with_config(config, HashMap::new(), temp_dir.path());
+        context.env = Env::isolated();
+
+        ModuleRenderer {
+            name,
+            context,
+            _temp_dir: Some(temp_dir),
+        }
+    }
+
+    /// The directory the module is rendered in
+    pub fn root_path(&self) -> &Path {
+        &self.context.current_dir
+    }
+
+    /// Render the module in the given directory instead of a temporary one
+    pub fn path<T: Into<PathBuf>>(mut self, path: T) -> Self {
+        self.context.current_dir = path.into();
+        self._temp_dir = None;
+        self
+    }
+
+    /// Create a file with the given contents, relative to the rendered directory
+    pub fn file<P: AsRef<Path>>(self, path: P, contents: &str) -> Self {
+        self.try_create_file(path, contents)
+            .expect("Unable to create test file");
+        self
+    }
+
+    /// Create a directory, relative to the rendered directory
+    pub fn dir<P: AsRef<Path>>(self, path: P) -> Self {
+        fs::create_dir_all(self.root_path().join(path)).expect("Unable to create test directory");
+        self
+    }
+
+    /// Set an environment variable visible to the module
+    pub fn env<V: Into<String>>(mut self, key: &str, value: V) -> Self {
+        self.context.env.insert(key, value);
+        self
+    }
+
+    /// Stub the output of an external command, given as a full command line
+    /// (e.g. `"node --version"`). `stdout` is returned as the command's output.
+    pub fn cmd(self, command_line: &str, stdout: &str) -> Self {
+        self.cmd_output(
+            command_line,
+            Some(CommandOutput {
+                stdout: stdout.to_string(),
+                stderr: String::new(),
+            }),
+        )
+    }
+
+    /// Stub the full result of an external command. `None` simulates a command
+    /// which is missing or which exited unsuccessfully.
+    pub fn cmd_output(mut self, command_line: &str, output: Option<CommandOutput>) -> Self {
+        self.context.cmd.insert(command_line.to_string(), output);
+        self
+    }
+
+    /// Use the given configuration instead of an empty one
+    pub fn config(mut self, config: toml::Value) -> Self {
+        self.context.config = StarshipConfig {
+            config: Some(config),
+        };
+        self
+    }
+
+    /// Set a property as if it was passed on the command line (e.g. `status_code`)
+    pub fn property(mut self, key: &'a str, value: &str) -> Self {
+        self.context.properties.insert(key, value.to_string());
+        self
+    }
+
+    /// Render the module, returning `None` if it wasn't shown
+    pub fn collect(self) -> Option<String> {
+        modules::handle(self.name, &self.context).map(|module| module.to_string())
+    }
+
+    fn try_create_file<P: AsRef<Path>>(&self, path: P, contents: &str) -> io::Result<()> {
+        let path = self.root_path().join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)
+    }
+}
@@ -0,0 +1,354 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json as json;
+
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/starship/starship/releases/latest";
+
+const DOWNLOAD_URL: &str = "https://github.com/starship/starship/releases/download";
+
+/// Parts of the paths where package managers install binaries. A binary
+/// installed there must be updated by its package manager, which would
+/// otherwise undo the update or fail to verify its files.
+const PACKAGE_MANAGER_PATHS: &[(&str, &str)] = &[
+    ("/nix/store/", "nix"),
+    ("/Cellar/", "Homebrew"),
+    ("/opt/homebrew/", "Homebrew"),
+    ("/snap/", "snap"),
+    ("/.cargo/bin/", "cargo install"),
+    ("\\scoop\\", "Scoop"),
+    ("\\chocolatey\\", "Chocolatey"),
+];
+
+/// Directories only written to by the package manager of the distribution
+const DISTRIBUTION_PATHS: &[&str] = &["/usr/bin/", "/bin/", "/usr/sbin/"];
+
+/// Update the running starship binary to the latest release.
+///
+/// The release archive is downloaded with `curl` or `wget`, checked against
+/// the SHA-256 checksum published with it and extracted with `tar`, like the
+/// install script does. The new binary then atomically replaces the current
+/// one.
+///
+/// The checksum comes from the same server as the archive, so it only catches
+/// corrupted downloads. Releases aren't signed, their authenticity relies on
+/// HTTPS.
+pub fn update(check_only: bool) -> io::Result<()> {
+    let current_exe = env::current_exe()?;
+    let current_version = crate_version!();
+
+    let latest_version = get_latest_version()?;
+    if !is_newer(&latest_version, current_version) {
+        println!("starship v{} is up to date", current_version);
+        return Ok(());
+    }
+
+    println!(
+        "A new version of starship is available: v{} (installed: v{})",
+        latest_version, current_version
+    );
+    if check_only {
+        return Ok(());
+    }
+
+    if let Some(manager) = get_package_manager(&current_exe) {
+        return Err(error(format!(
+            "{} was installed by {}, please update it with {} instead",
+            current_exe.display(),
+            manager,
+            manager
+        )));
+    }
+
+    // Releases for Windows are zip archives, which the tar of Windows can extract
+    let extension = if cfg!(windows) { "zip" } else { "tar.gz" };
+    let archive_name = format!("starship-{}.{}", target_triple(), extension);
+    let archive_url = format!("{}/v{}/{}", DOWNLOAD_URL, latest_version, archive_name);
+
+    let temp_dir = DownloadDir::new()?;
+    let archive_path = temp_dir.path().join(&archive_name);
+    let checksum_path = temp_dir.path().join(format!("{}.sha256", archive_name));
+
+    println!("Downloading {}", archive_url);
+    download(&archive_url, &archive_path)?;
+    download(&format!("{}.sha256", archive_url), &checksum_path)?;
+
+    let expected = parse_checksum(&fs::read_to_string(&checksum_path)?)
+        .ok_or_else(|| error(format!("invalid checksum file for {}", archive_name)))?;
+    let actual = sha256(&archive_path)?;
+    if actual != expected {
+        return Err(error(format!(
+            "checksum mismatch for {}: expected {}, got {}",
+            archive_name, expected, actual
+        )));
+    }
+
+    run(Command::new("tar")
+        .arg("xf")
+        .arg(&archive_path)
+        .arg("-C")
+        .arg(temp_dir.path()))?;
+
+    let binary_name = if cfg!(windows) {
+        "starship.exe"
+    } else {
+        "starship"
+    };
+    replace_binary(&temp_dir.path().join(binary_name), &current_exe)?;
+
+    println!("Updated starship to v{}", latest_version);
+    Ok(())
+}
+
+fn get_latest_version() -> io::Result<String> {
+    let temp_dir = DownloadDir::new()?;
+    let release_path = temp_dir.path().join("release.json");
+    download(LATEST_RELEASE_URL, &release_path)?;
+
+    let release: json::Value = json::from_str(&fs::read_to_string(&release_path)?)?;
+    release["tag_name"]
+        .as_str()
+        .map(|tag| tag.trim_start_matches('v').to_string())
+        .ok_or_else(|| error("unable to find the latest release of starship"))
+}
+
+/// A new directory for the downloads of an update, removed when dropped
+struct DownloadDir(PathBuf);
+
+impl DownloadDir {
+    fn new() -> io::Result<DownloadDir> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.subsec_nanos())
+            .unwrap_or_default();
+        let path = env::temp_dir().join(format!("starship-update-{}-{}", process::id(), nanos));
+
+        // Creating the directory fails if it exists, so nobody else can have
+        // prepared its contents
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        builder.create(&path)?;
+
+        Ok(DownloadDir(path))
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for DownloadDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Download a URL to a file, with curl or with wget if curl isn't installed
+fn download(url: &str, path: &Path) -> io::Result<()> {
+    let curl = Command::new("curl")
+        .args(["--fail", "--silent", "--show-error", "--location"])
+        .arg("--output")
+        .arg(path)
+        .arg(url)
+        .status();
+
+    match curl {
+        Ok(status) if status.success() => Ok(()),
+        Ok(_) => Err(error(format!("unable to download {}", url))),
+        Err(_) => run(Command::new("wget")
+            .arg("--quiet")
+            .arg("--output-document")
+            .arg(path)
+            .arg(url)),
+    }
+}
+
+/// Get the SHA-256 checksum of a file, with sha256sum or with shasum if
+/// sha256sum isn't installed (e.g. on macOS)
+fn sha256(path: &Path) -> io::Result<String> {
+    let output = Command::new("sha256sum").arg(path).output().or_else(|_| {
+        Command::new("shasum")
+            .arg("-a")
+            .arg("256")
+            .arg(path)
+            .output()
+    })?;
+
+    if !output.status.success() {
+        return Err(error(format!(
+            "unable to compute the checksum of {:?}",
+            path
+        )));
+    }
+
+    parse_checksum(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| error(format!("unable to compute the checksum of {:?}", path)))
+}
+
+/// Get the checksum from the output of `sha256sum` (`<checksum>  <file name>`),
+/// which is also the format of the checksum files of the releases
+fn parse_checksum(contents: &str) -> Option<String> {
+    let checksum = contents.split_whitespace().next()?.to_lowercase();
+    if checksum.len() == 64 && checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+        Some(checksum)
+    } else {
+        None
+    }
+}
+
+/// Replace the binary at `current` by the one at `new`, making sure the
+/// current binary is never left half-written
+fn replace_binary(new: &Path, current: &Path) -> io::Result<()> {
+    // Renaming is only atomic within a file system, so the new binary is
+    // first copied next to the current one
+    let staged = current.with_file_name(".starship.new");
+    fs::copy(new, &staged)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))?;
+    }
+
+    // Windows doesn't allow replacing a running executable, but allows renaming it
+    #[cfg(windows)]
+    let old = {
+        let old = current.with_extension("old.exe");
+        let _ = fs::remove_file(&old);
+        fs::rename(current, &old)?;
+        old
+    };
+
+    let result = fs::rename(&staged, current);
+    if result.is_err() {
+        let _ = fs::remove_file(&staged);
+
+        // Put the current binary back, rather than leaving none
+        #[cfg(windows)]
+        let _ = fs::rename(&old, current);
+    }
+    result
+}
+
+fn get_package_manager(exe: &Path) -> Option<&'static str> {
+    let path = exe.to_string_lossy();
+
+    let manager = PACKAGE_MANAGER_PATHS
+        .iter()
+        .find(|(part, _)| path.contains(part))
+        .map(|(_, manager)| *manager);
+
+    manager.or_else(|| {
+        DISTRIBUTION_PATHS
+            .iter()
+            .find(|dir| path.starts_with(*dir))
+            .map(|_| "your distribution's package manager")
+    })
+}
+
+/// The target triple of the release built for this platform
+fn target_triple() -> String {
+    let os = if cfg!(target_os = "macos") {
+        "apple-darwin"
+    } else if cfg!(windows) {
+        "pc-windows-msvc"
+    } else if cfg!(target_env = "musl") {
+        "unknown-linux-musl"
+    } else {
+        "unknown-linux-gnu"
+    };
+
+    format!("{}-{}", env::consts::ARCH, os)
+}
+
+/// Whether version `a` is newer than version `b`, comparing numeric components
+fn is_newer(a: &str, b: &str) -> bool {
+    fn components(version: &str) -> Vec<u64> {
+        version
+            .split(&['.', '-'][..])
+            .map_while(|part| part.parse().ok())
+            .collect()
+    }
+
+    components(a) > components(b)
+}
+
+fn run(command: &mut Command) -> io::Result<()> {
+    let status = command.status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(error(format!("{:?} failed with {}", command, status)))
+    }
+}
+
+fn error<T: Into<String>>(message: T) -> io::Error {
+    io::Error::other(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("0.34.0", "0.33.1"));
+        assert!(is_newer("1.0.0", "0.99.0"));
+        assert!(is_newer("0.33.10", "0.33.9"));
+        assert!(!is_newer("0.33.1", "0.33.1"));
+        assert!(!is_newer("0.33.0", "0.33.1"));
+    }
+
+    #[test]
+    fn test_download_dir_is_removed() -> io::Result<()> {
+        let dir = DownloadDir::new()?;
+        let path = dir.path().to_path_buf();
+        fs::write(path.join("release.json"), "{}")?;
+        assert!(path.is_dir());
+
+        drop(dir);
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_checksum() {
+        let checksum = "a".repeat(64);
+        assert_eq!(
+            parse_checksum(&format!("{}  starship.tar.gz\n", checksum)),
+            Some(checksum.clone())
+        );
+        assert_eq!(parse_checksum(&checksum.to_uppercase()), Some(checksum));
+        assert_eq!(parse_checksum("not a checksum"), None);
+        assert_eq!(parse_checksum(""), None);
+    }
+
+    #[test]
+    fn test_get_package_manager() {
+        let managed = |path: &str| get_package_manager(Path::new(path));
+
+        assert_eq!(managed("/nix/store/abc-starship/bin/starship"), Some("nix"));
+        assert_eq!(
+            managed("/usr/local/Cellar/starship/0.33.1/bin/starship"),
+            Some("Homebrew")
+        );
+        assert_eq!(
+            managed("/home/user/.cargo/bin/starship"),
+            Some("cargo install")
+        );
+        assert_eq!(
+            managed("/usr/bin/starship"),
+            Some("your distribution's package manager")
+        );
+        assert_eq!(managed("/usr/local/bin/starship"), None);
+        assert_eq!(managed("/home/user/.local/bin/starship"), None);
+    }
+}
@@ -1,7 +1,14 @@
 use std::fs::File;
 use std::io::{Read, Result};
 use std::path::Path;
-use std::process::Command;
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use path_slash::PathExt;
+use unicode_width::UnicodeWidthStr;
+
+use crate::profile;
 
 /// Return the string contents of a file
 pub fn read_file<P: AsRef<Path>>(file_name: P) -> Result<String> {
@@ -12,7 +19,7 @@ pub fn read_file<P: AsRef<Path>>(file_name: P) -> Result<String> {
     Ok(data)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CommandOutput {
     pub stdout: String,
     pub stderr: String,
@@ -27,25 +34,127 @@ impl PartialEq for CommandOutput {
 /// Execute a command and return the output on stdout and stderr if sucessful
 pub fn exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
     log::trace!("Executing command '{:?}' with args '{:?}'", cmd, args);
-    match Command::new(cmd).args(args).output() {
-        Ok(output) => {
-            let stdout_string = String::from_utf8(output.stdout).unwrap();
-            let stderr_string = String::from_utf8(output.stderr).unwrap();
-
-            if !output.status.success() {
-                log::trace!("Non-zero exit code '{:?}'", output.status.code());
-                log::trace!("stdout: {}", stdout_string);
-                log::trace!("stderr: {}", stderr_string);
+    let command_line = format!("{} {}", cmd, args.join(" "));
+    let output = profile::record("subprocess", command_line.trim_end(), || {
+        Command::new(cmd).args(args).output()
+    });
+    command_output(output.ok()?)
+}
+
+/// Execute a command like `exec_cmd`, but kill it if it takes longer than `timeout`
+///
+/// The command can't read stdin, and is expected to print little: its output
+/// is only read once it has exited.
+pub fn exec_cmd_with_timeout(cmd: &str, args: &[&str], timeout: Duration) -> Option<CommandOutput> {
+    log::trace!(
+        "Executing command '{:?}' with args '{:?}' and timeout {:?}",
+        cmd,
+        args,
+        timeout
+    );
+    let command_line = format!("{} {}", cmd, args.join(" "));
+    let output = profile::record("subprocess", command_line.trim_end(), || {
+        let mut child = Command::new(cmd)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .ok()?;
+
+        let start = Instant::now();
+        while child.try_wait().ok()?.is_none() {
+            if start.elapsed() >= timeout {
+                log::debug!("Command '{}' timed out", command_line.trim_end());
+                let _ = child.kill();
+                let _ = child.wait();
                 return None;
             }
+            thread::sleep(Duration::from_millis(1));
+        }
+        child.wait_with_output().ok()
+    });
+    command_output(output?)
+}
+
+fn command_output(output: Output) -> Option<CommandOutput> {
+    let stdout_string = String::from_utf8(output.stdout).unwrap();
+    let stderr_string = String::from_utf8(output.stderr).unwrap();
+
+    if !output.status.success() {
+        log::trace!("Non-zero exit code '{:?}'", output.status.code());
+        log::trace!("stdout: {}", stdout_string);
+        log::trace!("stderr: {}", stderr_string);
+        return None;
+    }
+
+    Some(CommandOutput {
+        stdout: stdout_string,
+        stderr: stderr_string,
+    })
+}
+
+/// Get the `file://` URL of a path on this machine, to be used as a hyperlink
+pub fn file_url(path: &Path) -> String {
+    let host = gethostname::gethostname();
+    let path = path
+        .to_slash()
+        .unwrap_or_else(|| path.to_string_lossy().into_owned());
+    let path = if path.starts_with('/') {
+        path
+    } else {
+        // Windows paths start with a drive letter, e.g. "C:/Users"
+        format!("/{}", path)
+    };
+
+    format!("file://{}{}", host.to_string_lossy(), percent_encode(&path))
+}
+
+/// Escape the characters of a URL path which aren't allowed in URLs
+pub fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
 
-            Some(CommandOutput {
-                stdout: stdout_string,
-                stderr: stderr_string,
-            })
+/// The number of terminal columns a rendered string takes up, not counting ANSI
+/// escape sequences or the markers bash and zsh put around them
+pub fn visible_width(text: &str) -> usize {
+    let mut visible = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\u{1b}' => match chars.next() {
+                // Skip a style up to its final character (e.g. "\x1b[1;32m")
+                Some('[') => {
+                    chars.by_ref().find(|c| ('@'..='~').contains(c));
+                }
+                // Skip a hyperlink up to its terminator (e.g. "\x1b]8;;file:///\x1b\\")
+                Some(']') => {
+                    let terminator = chars.by_ref().find(|c| *c == '\u{7}' || *c == '\u{1b}');
+                    if terminator == Some('\u{1b}') {
+                        chars.next_if_eq(&'\\');
+                    }
+                }
+                _ => {}
+            },
+            '\\' if matches!(chars.peek(), Some('[') | Some(']')) => {
+                chars.next();
+            }
+            '%' if matches!(chars.peek(), Some('{') | Some('}')) => {
+                chars.next();
+            }
+            c => visible.push(c),
         }
-        Err(_) => None,
     }
+
+    UnicodeWidthStr::width(visible.as_str())
 }
 
 #[cfg(test)]
@@ -53,6 +162,57 @@ pub fn exec_cmd(cmd: &str, args: &[&str]) -> Option<CommandOutput> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn visible_width_of_styled_text() {
+        use ansi_term::Color;
+
+        assert_eq!(visible_width("on master "), 10);
+        assert_eq!(
+            visible_width(&Color::Green.bold().paint("❯").to_string()),
+            1
+        );
+        assert_eq!(
+            visible_width("\\[\u{1b}[31m\\]🦀 v1.40.0\\[\u{1b}[0m\\]"),
+            10
+        );
+        assert_eq!(visible_width("%{\u{1b}[31m%}abc%{\u{1b}[0m%}"), 3);
+        assert_eq!(
+            visible_width("\u{1b}]8;;file:///tmp\u{1b}\\/tmp\u{1b}]8;;\u{1b}\\"),
+            4
+        );
+    }
+
+    #[test]
+    fn exec_cmd_with_timeout_kills_slow_commands() {
+        let timeout = Duration::from_millis(500);
+        assert_eq!(
+            exec_cmd_with_timeout("echo", &["hello"], timeout).map(|output| output.stdout),
+            Some(String::from("hello\n"))
+        );
+        assert_eq!(exec_cmd_with_timeout("false", &[], timeout), None);
+
+        let start = Instant::now();
+        assert_eq!(
+            exec_cmd_with_timeout("sleep", &["5"], Duration::from_millis(50)),
+            None
+        );
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("/home/user/src"), "/home/user/src");
+        assert_eq!(percent_encode("/tmp/my project"), "/tmp/my%20project");
+        assert_eq!(percent_encode("/tmp/été"), "/tmp/%C3%A9t%C3%A9");
+    }
+
+    #[test]
+    fn test_file_url() {
+        let host = gethostname::gethostname();
+        let expected = format!("file://{}/tmp/a%20b", host.to_string_lossy());
+        assert_eq!(file_url(Path::new("/tmp/a b")), expected);
+    }
+
     #[test]
     fn exec_no_output() {
         let result = exec_cmd("true", &[]);
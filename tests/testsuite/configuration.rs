@@ -1,5 +1,6 @@
 use ansi_term::Color;
-use std::io;
+use std::io::{self, Write};
+use std::process::Stdio;
 
 use crate::common::{self, TestCommand};
 
@@ -39,3 +40,153 @@ fn add_newline_configuration() -> io::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn stdin_config() -> io::Result<()> {
+    let expected = format!("{} ", Color::Green.bold().paint("❯"));
+
+    let mut child = common::render_module("character")
+        .arg("--stdin-config")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"[character]\nsymbol = \"\xe2\x9d\xaf\"\n")?;
+    let output = child.wait_with_output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+fn env_flag_overrides_environment() -> io::Result<()> {
+    let output = common::render_module("env_var")
+        .env("TEST_VAR", "real")
+        .arg("--env")
+        .arg("TEST_VAR=fake")
+        .use_config(toml::toml! {
+            [env_var]
+            variable = "TEST_VAR"
+        })
+        .output()?;
+    let expected = format!("with {} ", Color::Black.bold().dimmed().paint("fake"));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+fn low_priority_modules_hidden_on_narrow_terminal() -> io::Result<()> {
+    let config = toml::toml! {
+        add_newline = false
+        prompt_order = ["env_var", "character"]
+
+        [env_var]
+        variable = "TEST_VAR"
+        priority = "low"
+    };
+
+    let output = common::render_prompt()
+        .env("TEST_VAR", "wide")
+        .arg("--terminal-width=120")
+        .use_config(config.clone())
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains("wide"));
+
+    let output = common::render_prompt()
+        .env("TEST_VAR", "narrow")
+        .arg("--terminal-width=40")
+        .use_config(config)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(!actual.contains("narrow"));
+
+    Ok(())
+}
+
+#[test]
+fn fill_aligns_modules_to_the_right() -> io::Result<()> {
+    let output = common::render_prompt()
+        .env("TEST_VAR", "abc")
+        .arg("--terminal-width=20")
+        .use_config(toml::toml! {
+            add_newline = false
+            prompt_order = ["fill", "env_var", "line_break", "character"]
+
+            [fill]
+            symbol = "."
+
+            [env_var]
+            variable = "TEST_VAR"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let first_line = actual.lines().next().unwrap();
+
+    // "with abc " is 9 columns wide, leaving 11 columns to fill
+    let expected = format!(
+        "{}with {} ",
+        ".".repeat(11),
+        Color::Black.bold().dimmed().paint("abc")
+    );
+    assert_eq!(expected, first_line);
+
+    Ok(())
+}
+
+#[test]
+fn badge_sets_user_vars() -> io::Result<()> {
+    let output = common::render_prompt()
+        .use_config(toml::toml! {
+            badge = true
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(actual.contains("\u{1b}]1337;SetUserVar=starship_branch="));
+    assert!(actual.contains("\u{1b}]1337;SetBadgeFormat="));
+
+    let output = common::render_prompt().output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(!actual.contains("\u{1b}]1337;"));
+
+    Ok(())
+}
+
+#[test]
+fn tmux_prompt() -> io::Result<()> {
+    let output = common::render_prompt()
+        .env("TEST_VAR", "#tmux")
+        .arg("--tmux")
+        .use_config(toml::toml! {
+            prompt_order = ["env_var", "line_break", "character"]
+
+            [env_var]
+            variable = "TEST_VAR"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!("#[fg=black,bold,dim]##tmux#[default] ", actual);
+
+    Ok(())
+}
+
+#[test]
+fn tcsh_prompt_has_no_newlines() -> io::Result<()> {
+    let output = common::render_prompt()
+        .env("STARSHIP_SHELL", "tcsh")
+        .use_config(toml::toml! {
+            prompt_order = ["line_break", "character"]
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert!(!actual.contains('\n'));
+    assert!(actual.starts_with(" \\n \\n"));
+
+    Ok(())
+}
@@ -86,6 +86,11 @@ fn fish_directory_in_home() -> io::Result<()> {
 #[test]
 fn root_directory() -> io::Result<()> {
     let output = common::render_module("directory")
+        .use_config(toml::toml! {
+            // The directory is only read-only for users other than root
+            [directory]
+            read_only = ""
+        })
         .arg("--path=/")
         .output()?;
     let actual = String::from_utf8(output.stdout).unwrap();
@@ -99,6 +104,11 @@ fn root_directory() -> io::Result<()> {
 #[cfg(not(target_os = "windows"))]
 fn directory_in_root() -> io::Result<()> {
     let output = common::render_module("directory")
+        .use_config(toml::toml! {
+            // The directory is only read-only for users other than root
+            [directory]
+            read_only = ""
+        })
         .arg("--path=/etc")
         .output()?;
     let actual = String::from_utf8(output.stdout).unwrap();
@@ -165,6 +175,32 @@ fn truncated_directory_config_large() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[ignore]
+fn substituted_directory_before_truncation() -> io::Result<()> {
+    let dir = Path::new("/tmp/starship/vendor/very/deep/thrusters/rocket");
+    fs::create_dir_all(dir)?;
+
+    let output = common::render_module("directory")
+        .use_config(toml::toml! {
+            [directory]
+            truncation_length = 3
+            [directory.substitutions]
+            "/tmp/starship/vendor/very/deep" = "vendor"
+        })
+        .arg("--path")
+        .arg(dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!(
+        "in {} ",
+        Color::Cyan.bold().paint("vendor/thrusters/rocket")
+    );
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 #[ignore]
 fn fish_style_directory_config_large() -> io::Result<()> {
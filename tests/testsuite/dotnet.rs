@@ -80,6 +80,20 @@ fn shows_pinned_in_deeply_nested_project_within_repository() -> io::Result<()> {
     expect_output(&workspace, "deep/path/to/project", Some("•NET v1.2.3"))
 }
 
+#[test]
+#[ignore]
+fn shows_pinned_and_target_framework_in_project_below_root_with_global_json() -> io::Result<()> {
+    let workspace = create_workspace(false)?;
+    let global_json = make_pinned_sdk_json("1.2.3");
+    touch_path(&workspace, "global.json", Some(&global_json))?;
+    touch_path(
+        &workspace,
+        "src/project/project.csproj",
+        Some("<Project><PropertyGroup><TargetFramework>net8.0</TargetFramework></PropertyGroup></Project>"),
+    )?;
+    expect_output(&workspace, "src/project", Some("•NET v1.2.3 (net8.0)"))
+}
+
 fn create_workspace(is_repo: bool) -> io::Result<TempDir> {
     let repo_dir = tempfile::tempdir()?;
 
@@ -1,4 +1,4 @@
-use ansi_term::{Color, Style};
+use ansi_term::{ANSIStrings, Color, Style};
 use std::io;
 
 use crate::common;
@@ -135,6 +135,79 @@ fn suffix() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn format() -> io::Result<()> {
+    let output = common::render_module("env_var")
+        .env_clear()
+        .use_config(toml::toml! {
+            [env_var]
+            variable = "TEST_VAR"
+            format = "<$value>"
+        })
+        .env("TEST_VAR", TEST_VAR_VALUE)
+        .output()?;
+    let expected = format!("with {} ", style().paint(format!("<{}>", TEST_VAR_VALUE)));
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn multiple_instances() -> io::Result<()> {
+    let output = common::render_module("env_var")
+        .env_clear()
+        .use_config(toml::toml! {
+            [env_var.STAGE]
+            symbol = "🚦 "
+            style = "bold red"
+
+            [env_var.KUBENS]
+            default = "default"
+            format = "ns:$value"
+
+            [env_var.UNSET]
+        })
+        .env("STAGE", "production")
+        .output()?;
+    let expected = format!(
+        "with {} ",
+        ANSIStrings(&[
+            style().paint("ns:default "),
+            Color::Red.bold().paint("🚦 production"),
+        ])
+    );
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn multiple_instances_with_variable() -> io::Result<()> {
+    let output = common::render_module("env_var")
+        .env_clear()
+        .use_config(toml::toml! {
+            [env_var]
+            variable = "TEST_VAR"
+
+            [env_var.project]
+            variable = "PROJECT"
+
+            [env_var.disabled]
+            variable = "TEST_VAR"
+            disabled = true
+        })
+        .env("TEST_VAR", TEST_VAR_VALUE)
+        .env("PROJECT", "rocket")
+        .output()?;
+    let expected = format!(
+        "with {} ",
+        style().paint(format!("{} rocket", TEST_VAR_VALUE))
+    );
+    let actual = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 fn style() -> Style {
     // default style
     Color::Black.bold().dimmed()
@@ -0,0 +1,108 @@
+use ansi_term::Color;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use crate::common::{self, TestCommand};
+
+fn git(args: &[&str], repo_dir: &Path) -> io::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_dir)
+        .output()?;
+    Ok(String::from_utf8(output.stdout).unwrap().trim().to_string())
+}
+
+#[test]
+#[ignore]
+fn hidden_on_branch() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_commit")
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_hash_when_detached() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    git(&["checkout", "--detach", "HEAD"], &repo_dir)?;
+    let hash = git(&["rev-parse", "--short=7", "HEAD"], &repo_dir)?;
+
+    let output = common::render_module("git_commit")
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Green
+        .bold()
+        .paint(format!("({}) ", hash))
+        .to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_hash_on_branch_with_length() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let hash = git(&["rev-parse", "--short=10", "HEAD"], &repo_dir)?;
+
+    let output = common::render_module("git_commit")
+        .use_config(toml::toml! {
+            [git_commit]
+            only_detached = false
+            commit_hash_length = 10
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Green
+        .bold()
+        .paint(format!("({}) ", hash))
+        .to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_nearest_tag() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    git(&["tag", "v1.0.0", "HEAD^"], &repo_dir)?;
+    git(&["checkout", "--detach", "HEAD"], &repo_dir)?;
+    let hash = git(&["rev-parse", "--short=7", "HEAD"], &repo_dir)?;
+
+    let output = common::render_module("git_commit")
+        .use_config(toml::toml! {
+            [git_commit]
+            tag_disabled = false
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Green
+        .bold()
+        .paint(format!("({} 🏷 v1.0.0) ", hash))
+        .to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
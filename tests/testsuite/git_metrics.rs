@@ -0,0 +1,98 @@
+use ansi_term::Color;
+use std::fs;
+use std::io;
+use std::process::Command;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+#[ignore]
+fn hidden_by_default() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    fs::write(repo_dir.join("readme.md"), "# goodbye\n")?;
+
+    let output = common::render_module("git_metrics")
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn hidden_in_clean_repo() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_added_and_deleted_lines() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    fs::write(repo_dir.join("readme.md"), "# goodbye\n\nworld\n")?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!(
+        "{}/{} ",
+        Color::Green.bold().paint("+3"),
+        Color::Red.bold().paint("-1")
+    );
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_staged_added_lines() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    fs::write(repo_dir.join("license"), "MIT\n")?;
+    Command::new("git")
+        .args(["add", "license"])
+        .current_dir(&repo_dir)
+        .output()?;
+
+    let output = common::render_module("git_metrics")
+        .use_config(toml::toml! {
+            [git_metrics]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("{} ", Color::Green.bold().paint("+1"));
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use ansi_term::Color;
+use std::io;
+use std::process::Command;
+
+use crate::common::{self, TestCommand};
+
+#[test]
+#[ignore]
+fn hidden_by_default() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_remote")
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_tracked_remote_branch() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    let output = common::render_module("git_remote")
+        .use_config(toml::toml! {
+            [git_remote]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("{} ", Color::Blue.bold().paint("⇢ origin/master"));
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_other_remote_and_branch() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    Command::new("git")
+        .args(["remote", "rename", "origin", "upstream"])
+        .current_dir(&repo_dir)
+        .output()?;
+    Command::new("git")
+        .args([
+            "checkout",
+            "-b",
+            "feature/login",
+            "--track",
+            "upstream/master",
+        ])
+        .current_dir(&repo_dir)
+        .output()?;
+
+    let output = common::render_module("git_remote")
+        .use_config(toml::toml! {
+            [git_remote]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("{} ", Color::Blue.bold().paint("⇢ upstream/master"));
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn hidden_without_upstream() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    Command::new("git")
+        .args(["checkout", "-b", "local-only"])
+        .current_dir(&repo_dir)
+        .output()?;
+
+    let output = common::render_module("git_remote")
+        .use_config(toml::toml! {
+            [git_remote]
+            disabled = false
+        })
+        .arg("--path")
+        .arg(&repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+
+    Ok(())
+}
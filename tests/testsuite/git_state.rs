@@ -1,4 +1,4 @@
-use super::common;
+use super::common::{self, TestCommand};
 use std::ffi::OsStr;
 use std::fs::OpenOptions;
 use std::io::{self, Error, ErrorKind, Write};
@@ -90,6 +90,48 @@ fn shows_reverting() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[ignore]
+fn shows_rebasing_with_progress_divider() -> io::Result<()> {
+    let repo_dir = create_repo_with_conflict()?;
+    let path = path_str(&repo_dir)?;
+
+    run_git_cmd(["rebase", "other-branch"], Some(path), false)?;
+
+    let output = common::render_module("git_state")
+        .use_config(toml::toml! {
+            [git_state]
+            progress_divider = " of "
+        })
+        .current_dir(path)
+        .output()?;
+    let text = String::from_utf8(output.stdout).unwrap();
+    assert!(text.contains("REBASING 1 of 1"));
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_rebasing_in_worktree() -> io::Result<()> {
+    let repo_dir = create_repo_with_conflict()?;
+    let path = path_str(&repo_dir)?;
+    let worktree_dir = tempfile::tempdir()?;
+    let worktree = worktree_dir.path().join("worktree");
+    let worktree = worktree.to_str().unwrap();
+
+    run_git_cmd(["worktree", "add", worktree, "HEAD"], Some(path), true)?;
+    run_git_cmd(["rebase", "other-branch"], Some(worktree), false)?;
+
+    let output = common::render_module("git_state")
+        .current_dir(worktree)
+        .output()?;
+    let text = String::from_utf8(output.stdout).unwrap();
+    assert!(text.contains("REBASING 1/1"));
+
+    Ok(())
+}
+
 fn run_git_cmd<A, S>(args: A, dir: Option<&str>, expect_ok: bool) -> io::Result<()>
 where
     A: IntoIterator<Item = S>,
@@ -149,6 +149,38 @@ fn shows_diverged_with_count() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[ignore]
+fn shows_diverged_with_styled_counts() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    diverge(&repo_dir)?;
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            ahead_count.enabled = true
+            ahead_count.style = "green"
+            behind_count.enabled = true
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!(
+        "{}",
+        ANSIStrings(&[
+            Color::Red.bold().paint("[⇕⇡"),
+            Color::Green.paint("1"),
+            Color::Red.bold().paint("⇣1] "),
+        ])
+    );
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
 #[test]
 #[ignore]
 fn shows_conflicted() -> io::Result<()> {
@@ -210,6 +242,53 @@ fn shows_untracked_file() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[ignore]
+fn doesnt_show_untracked_file_with_untracked_files_none() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    create_untracked(&repo_dir)?;
+    create_modified(&repo_dir)?;
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            untracked_files = "none"
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Red.bold().paint(format!("[{}] ", "!")).to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
+#[test]
+#[ignore]
+fn shows_untracked_file_within_status_timeout() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+
+    create_untracked(&repo_dir)?;
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            status_timeout = 60000
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Red.bold().paint(format!("[{}] ", "?")).to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
 #[test]
 #[ignore]
 fn shows_untracked_file_with_count() -> io::Result<()> {
@@ -292,6 +371,39 @@ fn shows_stashed() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[ignore]
+fn shows_stashed_with_count() -> io::Result<()> {
+    let repo_dir = common::create_fixture_repo()?;
+    barrier();
+
+    for contents in &["# first", "# second"] {
+        fs::write(repo_dir.join("readme.md"), contents)?;
+        barrier();
+
+        Command::new("git")
+            .args(["stash"])
+            .current_dir(repo_dir.as_path())
+            .output()?;
+        barrier();
+    }
+
+    let output = common::render_module("git_status")
+        .use_config(toml::toml! {
+            [git_status]
+            stashed_count.enabled = true
+        })
+        .arg("--path")
+        .arg(repo_dir)
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = Color::Red.bold().paint(format!("[{}] ", "$2")).to_string();
+
+    assert_eq!(expected, actual);
+
+    Ok(())
+}
+
 #[test]
 #[ignore]
 fn shows_modified() -> io::Result<()> {
@@ -510,6 +622,52 @@ fn suffix() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "gitoxide")]
+fn gitoxide_backend_matches_libgit2() -> io::Result<()> {
+    let changes: &[fn(&PathBuf) -> io::Result<()>] = &[
+        create_untracked,
+        create_modified,
+        create_staged,
+        create_renamed,
+        create_deleted,
+    ];
+
+    for create_change in changes {
+        let repo_dir = common::create_fixture_repo()?;
+
+        create_change(&repo_dir)?;
+
+        let render = |backend: &str| -> io::Result<String> {
+            let mut config = toml::toml! {
+                [git_status]
+                deleted_count.enabled = true
+                renamed_count.enabled = true
+                modified_count.enabled = true
+                staged_count.enabled = true
+                untracked_count.enabled = true
+            };
+            config["git_status"]
+                .as_table_mut()
+                .unwrap()
+                .insert("backend".to_string(), backend.into());
+
+            let output = common::render_module("git_status")
+                .use_config(config)
+                .arg("--path")
+                .arg(&repo_dir)
+                .output()?;
+            Ok(String::from_utf8(output.stdout).unwrap())
+        };
+
+        let expected = render("libgit2")?;
+        assert!(!expected.is_empty());
+        assert_eq!(expected, render("gitoxide")?);
+    }
+
+    Ok(())
+}
+
 fn ahead(repo_dir: &PathBuf) -> io::Result<()> {
     File::create(repo_dir.join("readme.md"))?.sync_all()?;
 
@@ -24,6 +24,49 @@ fn ssh_only_false() -> io::Result<()> {
     Ok(())
 }
 
+#[test]
+fn ssh_with_ssh_style() -> io::Result<()> {
+    let hostname = match get_hostname() {
+        Some(h) => h,
+        None => return hostname_not_tested(),
+    };
+    let output = common::render_module("hostname")
+        .env_clear()
+        .use_config(toml::toml! {
+            [hostname]
+            ssh_only = false
+            trim_at = ""
+            ssh_style = "bold red"
+        })
+        .env("SSH_CONNECTION", "something")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("on {} ", Color::Red.bold().paint(hostname));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn no_ssh_with_ssh_style() -> io::Result<()> {
+    let hostname = match get_hostname() {
+        Some(h) => h,
+        None => return hostname_not_tested(),
+    };
+    let output = common::render_module("hostname")
+        .env_clear()
+        .use_config(toml::toml! {
+            [hostname]
+            ssh_only = false
+            trim_at = ""
+            ssh_style = "bold red"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+    let expected = format!("on {} ", style().paint(hostname));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[test]
 fn no_ssh() -> io::Result<()> {
     let output = common::render_module("hostname")
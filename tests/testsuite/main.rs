@@ -8,6 +8,9 @@ mod directory;
 mod dotnet;
 mod env_var;
 mod git_branch;
+mod git_commit;
+mod git_metrics;
+mod git_remote;
 mod git_state;
 mod git_status;
 mod golang;
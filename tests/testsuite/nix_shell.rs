@@ -1,7 +1,7 @@
 use ansi_term::Color;
 use std::io;
 
-use crate::common;
+use crate::common::{self, TestCommand};
 
 #[test]
 fn no_env_variables() -> io::Result<()> {
@@ -56,3 +56,54 @@ fn lorri_shell() -> io::Result<()> {
     assert_eq!(expected, actual);
     Ok(())
 }
+
+#[test]
+fn nix_store_in_path_without_heuristic() -> io::Result<()> {
+    let output = common::render_module("nix_shell")
+        .env_remove("IN_NIX_SHELL")
+        .env(
+            "PATH",
+            "/nix/store/v5sv61sszx301i0x6xysaqzla09nksnd-hello-2.10/bin:/usr/bin",
+        )
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    Ok(())
+}
+
+#[test]
+fn nix_store_in_path_with_heuristic() -> io::Result<()> {
+    let output = common::render_module("nix_shell")
+        .env_remove("IN_NIX_SHELL")
+        .env(
+            "PATH",
+            "/nix/store/v5sv61sszx301i0x6xysaqzla09nksnd-hello-2.10/bin:/usr/bin",
+        )
+        .use_config(toml::toml! {
+            [nix_shell]
+            heuristic = true
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    let expected = format!("via {} ", Color::Red.bold().paint("nix"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn no_nix_store_in_path_with_heuristic() -> io::Result<()> {
+    let output = common::render_module("nix_shell")
+        .use_config(toml::toml! {
+            [nix_shell]
+            heuristic = true
+        })
+        .env_remove("IN_NIX_SHELL")
+        .env("PATH", "/home/astronaut/.nix-profile/bin:/usr/bin")
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!("", actual);
+    Ok(())
+}
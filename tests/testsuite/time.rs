@@ -56,3 +56,39 @@ fn config_check_prefix_and_suffix() -> io::Result<()> {
     assert!(actual.ends_with(&col_suffix));
     Ok(())
 }
+
+#[test]
+fn config_time_format() -> io::Result<()> {
+    let output = common::render_module("time")
+        .use_config(toml::toml! {
+            [time]
+            disabled = false
+            time_format = "lunch"
+            format = "[%T]"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    // `time_format` takes precedence over its former name, `format`
+    let expected = format!("at {} ", ansi_term::Color::Yellow.bold().paint("lunch"));
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[test]
+fn config_invalid_time_format() -> io::Result<()> {
+    let output = common::render_module("time")
+        .use_config(toml::toml! {
+            [time]
+            disabled = false
+            time_format = "%Q"
+        })
+        .output()?;
+    let actual = String::from_utf8(output.stdout).unwrap();
+
+    // Falls back to the default format instead of panicking
+    assert!(output.status.success());
+    assert!(!actual.contains("%Q"));
+    assert!(!actual.is_empty());
+    Ok(())
+}
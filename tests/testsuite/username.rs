@@ -3,8 +3,8 @@ use std::io;
 
 use crate::common::{self, TestCommand};
 
-// TODO: Add tests for if root user (UID == 0)
-// Requires mocking
+// The root user (UID == 0) is tested in the unit tests of the module, where
+// `id -u` can be stubbed
 
 #[test]
 fn no_env_variables() -> io::Result<()> {